@@ -0,0 +1,339 @@
+//! Convenience API for transforming source text in a single call
+//!
+//! This wraps parsing, semantic analysis, the babel-compatible transformer
+//! and code generation:
+//!
+//! ```rust
+//! use oxc_dom_expressions::{transform, DomExpressionsOptions};
+//! use oxc_span::SourceType;
+//!
+//! let output = transform(
+//!     "const view = <button onClick={() => count++}>Click</button>;",
+//!     SourceType::jsx(),
+//!     DomExpressionsOptions::new("solid-js/web"),
+//! );
+//!
+//! assert!(output.errors.is_empty());
+//! assert_eq!(output.delegated_events, vec!["click"]);
+//! ```
+
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+#[cfg(any(test, feature = "testing"))]
+use oxc_ast::ast::Program;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::{SourceType, SPAN};
+use oxc_traverse::traverse_mut;
+
+use crate::compat::BabelOutputNormalizer;
+use crate::compat2::DomExpressionsCompat2;
+use crate::diagnostics::Diagnostic;
+use crate::options::{DomExpressionsOptions, OutputStyle, PureCommentStyle};
+
+/// The result of [`transform()`]
+#[derive(Debug, Clone, Default)]
+pub struct TransformOutput {
+    /// The generated JavaScript code
+    pub code: String,
+    /// The source map as JSON, when [`DomExpressionsOptions::sourcemap`] is set
+    pub map: Option<String>,
+    /// Events registered with `delegateEvents`, in the order they are emitted
+    pub delegated_events: Vec<String>,
+    /// Parse errors; when non-empty no code is generated
+    pub errors: Vec<String>,
+    /// Constructs that could not be fully transformed
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Transform JSX source text into DOM expressions output
+pub fn transform(
+    source_text: &str,
+    source_type: SourceType,
+    options: DomExpressionsOptions,
+) -> TransformOutput {
+    transform_source(source_text, source_type, Path::new(""), options)
+}
+
+/// Transform the source text of a file, detecting the source type from its extension
+///
+/// The source map, if enabled, names `path` as its source.
+pub fn transform_file(
+    path: impl AsRef<Path>,
+    source_text: &str,
+    options: DomExpressionsOptions,
+) -> TransformOutput {
+    let path = path.as_ref();
+    match SourceType::from_path(path) {
+        Ok(source_type) => transform_source(source_text, source_type, path, options),
+        Err(error) => TransformOutput {
+            errors: vec![error.to_string()],
+            ..Default::default()
+        },
+    }
+}
+
+fn transform_source(
+    source_text: &str,
+    source_type: SourceType,
+    path: &Path,
+    options: DomExpressionsOptions,
+) -> TransformOutput {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+
+    if !ret.errors.is_empty() {
+        return TransformOutput {
+            errors: ret.errors.iter().map(|error| error.to_string()).collect(),
+            ..Default::default()
+        };
+    }
+
+    let mut program = ret.program;
+    let semantic = SemanticBuilder::new().build(&program).semantic;
+    let scoping = semantic.into_scoping();
+
+    let output_style = options.output_style;
+    let pure_comment = options.pure_comment;
+    let sourcemap = options.sourcemap;
+    let mut transformer = DomExpressionsCompat2::new(&allocator, options);
+    traverse_mut(&mut transformer, &allocator, &mut program, scoping, ());
+
+    // Generated nodes have empty spans, so only cloned user code is mapped
+    let ret = Codegen::new()
+        .with_options(CodegenOptions {
+            source_map_path: sourcemap.then(|| path.to_path_buf()),
+            ..CodegenOptions::default()
+        })
+        .build(&program);
+    let mut diagnostics = transformer.get_diagnostics().to_vec();
+    if sourcemap && pure_comment == Some(PureCommentStyle::Babel) {
+        diagnostics.push(Diagnostic::warning(
+            SPAN,
+            "`pure_comment` is ignored when a source map is generated, \
+             oxc's `/* @__PURE__ */` is kept so the mappings stay accurate",
+        ));
+    }
+
+    let code = match (output_style, pure_comment) {
+        _ if sourcemap => ret.code,
+        (OutputStyle::Babel, pure_comment) => BabelOutputNormalizer::normalize_with_pure_comment(
+            &ret.code,
+            pure_comment.unwrap_or(PureCommentStyle::Babel),
+        ),
+        (OutputStyle::Oxc, Some(pure_comment)) => {
+            BabelOutputNormalizer::normalize_pure_comments(&ret.code, pure_comment)
+        }
+        (OutputStyle::Oxc, None) => ret.code,
+    };
+
+    TransformOutput {
+        code,
+        map: ret.map.map(|map| map.to_json_string()),
+        delegated_events: transformer.delegated_events(),
+        errors: Vec::new(),
+        diagnostics,
+    }
+}
+
+/// Transform JSX source text and inspect the resulting AST before code generation
+///
+/// Lets tests assert on the generated statements rather than printed code.
+/// Returns the parse errors when the source does not parse.
+#[cfg(any(test, feature = "testing"))]
+pub fn transform_program<R>(
+    source_text: &str,
+    source_type: SourceType,
+    options: DomExpressionsOptions,
+    inspect: impl FnOnce(&Program<'_>) -> R,
+) -> Result<R, Vec<String>> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+
+    if !ret.errors.is_empty() {
+        return Err(ret.errors.iter().map(|error| error.to_string()).collect());
+    }
+
+    let mut program = ret.program;
+    let semantic = SemanticBuilder::new().build(&program).semantic;
+    let scoping = semantic.into_scoping();
+
+    let mut transformer = DomExpressionsCompat2::new(&allocator, options);
+    traverse_mut(&mut transformer, &allocator, &mut program, scoping, ());
+
+    Ok(inspect(&program))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_ast::ast::{Expression, ModuleDeclaration, Statement};
+    use oxc_span::{GetSpan, Span};
+
+    #[test]
+    fn test_output_style() {
+        let source = "const view = <div>Hello</div>;";
+
+        let babel = transform(
+            source,
+            SourceType::jsx(),
+            DomExpressionsOptions::new("r-dom"),
+        );
+        assert!(babel
+            .code
+            .contains("/*#__PURE__*/ _$template(`<div>Hello`)"));
+
+        let options = DomExpressionsOptions::new("r-dom").with_output_style(OutputStyle::Oxc);
+        let oxc = transform(source, SourceType::jsx(), options);
+        assert!(oxc
+            .code
+            .contains("/* @__PURE__ */ _$template(`<div>Hello`)"));
+    }
+
+    #[test]
+    fn test_pure_comment_style() {
+        let source = "const view = <div>Hello</div>;";
+
+        let options = DomExpressionsOptions::new("r-dom").with_pure_comment(PureCommentStyle::Oxc);
+        let output = transform(source, SourceType::jsx(), options);
+        assert!(output
+            .code
+            .contains("/* @__PURE__ */ _$template(`<div>Hello`)"));
+        // The rest of the babel formatting still applies
+        assert!(!output.code.contains('\t'));
+
+        let options = DomExpressionsOptions::new("r-dom")
+            .with_output_style(OutputStyle::Oxc)
+            .with_pure_comment(PureCommentStyle::Babel);
+        let output = transform(source, SourceType::jsx(), options);
+        assert!(output
+            .code
+            .contains("/*#__PURE__*/ _$template(`<div>Hello`)"));
+    }
+
+    #[test]
+    fn test_sourcemap() {
+        let source = "const view = <div>{count()}</div>;";
+
+        let output = transform(
+            source,
+            SourceType::jsx(),
+            DomExpressionsOptions::new("r-dom"),
+        );
+        assert!(output.map.is_none());
+
+        let options = DomExpressionsOptions::new("r-dom").with_sourcemap(true);
+        let output = transform_file("view.jsx", source, options);
+        let map = output.map.expect("source map");
+        assert!(map.contains(r#""sources":["view.jsx"]"#));
+        assert!(!map.contains(r#""mappings":"""#));
+    }
+
+    #[test]
+    fn test_sourcemap_with_pure_comment() {
+        let source = "const view = <div>{count()}</div>;";
+
+        // The babel spelling would shift the mapped columns, so oxc's is kept
+        let options = DomExpressionsOptions::new("r-dom")
+            .with_sourcemap(true)
+            .with_pure_comment(PureCommentStyle::Babel);
+        let output = transform_file("view.jsx", source, options);
+        assert!(output.map.is_some());
+        assert!(output.code.contains("/* @__PURE__ */"));
+        assert_eq!(output.diagnostics.len(), 1);
+        assert!(output.diagnostics[0].message.contains("pure_comment"));
+
+        // oxc's spelling is what the source map describes, nothing to report
+        let options = DomExpressionsOptions::new("r-dom")
+            .with_sourcemap(true)
+            .with_pure_comment(PureCommentStyle::Oxc);
+        let output = transform_file("view.jsx", source, options);
+        assert!(output.code.contains("/* @__PURE__ */"));
+        assert!(output.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_transform_program_exposes_statements() {
+        let source = "const view = <div>{count()}</div>;";
+        let options = DomExpressionsOptions::new("r-dom");
+
+        let sources = transform_program(source, SourceType::jsx(), options, |program| {
+            let imports: Vec<String> = program
+                .body
+                .iter()
+                .filter_map(|stmt| match stmt.as_module_declaration() {
+                    Some(ModuleDeclaration::ImportDeclaration(import)) => {
+                        Some(import.source.value.to_string())
+                    }
+                    _ => None,
+                })
+                .collect();
+
+            // The element becomes an IIFE call assigned to `view`
+            let Some(Statement::VariableDeclaration(decl)) = program.body.last() else {
+                panic!("expected the view declaration last");
+            };
+            assert!(matches!(
+                decl.declarations[0].init,
+                Some(Expression::CallExpression(_))
+            ));
+
+            imports
+        })
+        .expect("source parses");
+
+        assert!(!sources.is_empty());
+        assert!(sources.iter().all(|source| source == "r-dom"));
+    }
+
+    #[test]
+    fn test_inserted_expression_keeps_span() {
+        let source = "const view = <div>{userExpr}</div>;";
+        let start = source.find("userExpr").unwrap() as u32;
+        let options = DomExpressionsOptions::new("r-dom");
+
+        let span = transform_program(source, SourceType::jsx(), options, |program| {
+            let Some(Statement::VariableDeclaration(decl)) = program.body.last() else {
+                panic!("expected the view declaration last");
+            };
+            let Some(Expression::CallExpression(iife)) = &decl.declarations[0].init else {
+                panic!("expected an IIFE");
+            };
+            let Expression::ArrowFunctionExpression(arrow) = iife.callee.without_parentheses()
+            else {
+                panic!("expected an arrow function callee");
+            };
+
+            // Find `_$insert(_el$, userExpr)` and return the inserted argument's span
+            arrow
+                .body
+                .statements
+                .iter()
+                .find_map(|stmt| match stmt {
+                    Statement::ExpressionStatement(stmt) => match &stmt.expression {
+                        Expression::CallExpression(call)
+                            if call.callee_name() == Some("_$insert") =>
+                        {
+                            Some(call.arguments[1].span())
+                        }
+                        _ => None,
+                    },
+                    _ => None,
+                })
+                .expect("an insert call")
+        })
+        .expect("source parses");
+
+        assert_eq!(span, Span::new(start, start + "userExpr".len() as u32));
+    }
+
+    #[test]
+    fn test_transform_program_reports_parse_errors() {
+        let options = DomExpressionsOptions::new("r-dom");
+        let result = transform_program("const view = <div>;", SourceType::jsx(), options, |_| ());
+
+        assert!(!result.unwrap_err().is_empty());
+    }
+}
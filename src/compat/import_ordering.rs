@@ -32,10 +32,11 @@ pub fn get_import_priority(name: &str) -> usize {
         "setStyleProperty" => 9,
         "setAttribute" => 10,
         "effect" => 11,
-        "classList" => 12,
-        "use" => 13,
-        "spread" => 14,
-        "mergeProps" => 15,
+        "getNextElement" => 12,
+        "classList" => 13,
+        "use" => 14,
+        "spread" => 15,
+        "mergeProps" => 16,
         "For" => 20,
         "Show" => 21,
         "Suspense" => 22,
@@ -50,9 +50,11 @@
 //! let elem_var = element_var_name(1); // "_el$1"
 //!
 //! // Normalize output for babel compatibility
-//! let normalized = BabelOutputNormalizer::normalize(&generated_code);
+//! let generated_code = "var _tmpl$ = /* @__PURE__ */ _$template(`<div>`);";
+//! let normalized = BabelOutputNormalizer::normalize(generated_code);
 //!
 //! // Sort imports by priority
+//! let mut imports = vec!["insert", "template"];
 //! imports.sort_by_key(|name| get_import_priority(name));
 //! ```
 
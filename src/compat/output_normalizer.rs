@@ -4,7 +4,8 @@
 //! to match the exact format produced by babel-plugin-jsx-dom-expressions.
 //! This is necessary for fixture test compatibility.
 
-use super::constants::{BABEL_PURE_COMMENT, OXC_PURE_COMMENT, TEMPLATE_VAR_PREFIX};
+use super::constants::{OXC_PURE_COMMENT, TEMPLATE_VAR_PREFIX};
+use crate::options::PureCommentStyle;
 
 /// Normalizer for babel plugin output compatibility
 pub struct BabelOutputNormalizer;
@@ -25,10 +26,13 @@ impl BabelOutputNormalizer {
     ///
     /// Normalized code string matching babel plugin format
     pub fn normalize(code: &str) -> String {
-        let mut result = code.to_string();
+        Self::normalize_with_pure_comment(code, PureCommentStyle::Babel)
+    }
 
-        // Replace /* @__PURE__ */ with /*#__PURE__*/
-        result = result.replace(OXC_PURE_COMMENT, BABEL_PURE_COMMENT);
+    /// Normalize generated code to babel's formatting, spelling pure
+    /// annotations in the given style
+    pub fn normalize_with_pure_comment(code: &str, pure_comment: PureCommentStyle) -> String {
+        let mut result = Self::normalize_pure_comments(code, pure_comment);
 
         // Replace tabs with double spaces to match babel output
         result = result.replace('\t', "  ");
@@ -41,6 +45,11 @@ impl BabelOutputNormalizer {
 
         result
     }
+
+    /// Rewrite oxc's `/* @__PURE__ */` annotations in the given style
+    pub fn normalize_pure_comments(code: &str, pure_comment: PureCommentStyle) -> String {
+        code.replace(OXC_PURE_COMMENT, pure_comment.comment())
+    }
 }
 
 #[cfg(test)]
@@ -54,6 +63,19 @@ mod tests {
         assert_eq!(BabelOutputNormalizer::normalize(input), expected);
     }
 
+    #[test]
+    fn test_pure_comment_style() {
+        let input = "const x = /* @__PURE__ */ fn();";
+        assert_eq!(
+            BabelOutputNormalizer::normalize_with_pure_comment(input, PureCommentStyle::Oxc),
+            input
+        );
+        assert_eq!(
+            BabelOutputNormalizer::normalize_pure_comments(input, PureCommentStyle::Babel),
+            "const x = /*#__PURE__*/ fn();"
+        );
+    }
+
     #[test]
     fn test_tab_normalization() {
         let input = "function test() {\n\treturn true;\n}";
@@ -9,7 +9,9 @@
 use oxc_allocator::Box;
 use oxc_allocator::Vec as OxcVec;
 use oxc_ast::ast::*;
-use oxc_span::SPAN;
+use oxc_span::{GetSpan, SPAN};
+
+use crate::utils::{contains_await_expression, is_dynamic_expression};
 
 use super::DomExpressionsCompat2;
 
@@ -23,11 +25,11 @@ impl<'a> DomExpressionsCompat2<'a> {
         use oxc_allocator::CloneIn;
         use oxc_ast::ast::*;
 
-        let is_reactive = matches!(value_expr, Expression::CallExpression(_));
+        let is_reactive = is_dynamic_expression(value_expr);
 
         let set_style_prop_fn = IdentifierReference {
             span: SPAN,
-            name: Atom::from("_$setStyleProperty"),
+            name: Atom::from(self.runtime_name("setStyleProperty")),
             reference_id: None.into(),
         };
 
@@ -105,7 +107,7 @@ impl<'a> DomExpressionsCompat2<'a> {
 
             let effect_fn = IdentifierReference {
                 span: SPAN,
-                name: Atom::from("_$effect"),
+                name: Atom::from(self.runtime_name("effect")),
                 reference_id: None.into(),
             };
 
@@ -160,12 +162,13 @@ impl<'a> DomExpressionsCompat2<'a> {
         use oxc_ast::ast::*;
 
         // Check if the expression is a call expression (reactive)
-        let is_reactive = matches!(value_expr, Expression::CallExpression(_));
+        let is_reactive = matches!(value_expr, Expression::CallExpression(_))
+            && !contains_await_expression(value_expr);
 
         // Create: _$setBoolAttribute(element, "attr", value)
         let set_bool_attr_fn = IdentifierReference {
             span: SPAN,
-            name: Atom::from("_$setBoolAttribute"),
+            name: Atom::from(self.runtime_name("setBoolAttribute")),
             reference_id: None.into(),
         };
 
@@ -249,7 +252,7 @@ impl<'a> DomExpressionsCompat2<'a> {
             // Wrap in _$effect call
             let effect_fn = IdentifierReference {
                 span: SPAN,
-                name: Atom::from("_$effect"),
+                name: Atom::from(self.runtime_name("effect")),
                 reference_id: None.into(),
             };
 
@@ -304,11 +307,13 @@ impl<'a> DomExpressionsCompat2<'a> {
         use oxc_ast::ast::*;
 
         // Create: _$effect(() => _$setAttribute(element, "attr", value))
+        // The generated calls map back to the value's location
+        let span = value_expr.span();
 
         // Inner call: _$setAttribute(element, "attr", value)
         let set_attr_fn = IdentifierReference {
             span: SPAN,
-            name: Atom::from("_$setAttribute"),
+            name: Atom::from(self.runtime_name("setAttribute")),
             reference_id: None.into(),
         };
 
@@ -339,7 +344,7 @@ impl<'a> DomExpressionsCompat2<'a> {
         set_attr_args.push(Argument::from(value_expr.clone_in(self.allocator)));
 
         let set_attr_call = CallExpression {
-            span: SPAN,
+            span,
             callee: Expression::Identifier(Box::new_in(set_attr_fn, self.allocator)),
             arguments: set_attr_args,
             optional: false,
@@ -347,6 +352,20 @@ impl<'a> DomExpressionsCompat2<'a> {
             pure: false,
         };
 
+        // Awaited values are set once, the await can't move into the effect
+        if contains_await_expression(value_expr) {
+            return Some(Statement::ExpressionStatement(Box::new_in(
+                ExpressionStatement {
+                    span,
+                    expression: Expression::CallExpression(Box::new_in(
+                        set_attr_call,
+                        self.allocator,
+                    )),
+                },
+                self.allocator,
+            )));
+        }
+
         // Wrap in arrow function: () => _$setAttribute(...)
         // Use expression form (not block) for concise output
         let arrow_body = FunctionBody {
@@ -368,7 +387,7 @@ impl<'a> DomExpressionsCompat2<'a> {
         };
 
         let arrow_fn = ArrowFunctionExpression {
-            span: SPAN,
+            span,
             expression: true, // Use expression form for concise arrow function
             r#async: false,
             params: Box::new_in(
@@ -391,7 +410,7 @@ impl<'a> DomExpressionsCompat2<'a> {
         // Wrap in _$effect call
         let effect_fn = IdentifierReference {
             span: SPAN,
-            name: Atom::from("_$effect"),
+            name: Atom::from(self.runtime_name("effect")),
             reference_id: None.into(),
         };
 
@@ -402,7 +421,7 @@ impl<'a> DomExpressionsCompat2<'a> {
         )));
 
         let effect_call = CallExpression {
-            span: SPAN,
+            span,
             callee: Expression::Identifier(Box::new_in(effect_fn, self.allocator)),
             arguments: effect_args,
             optional: false,
@@ -412,13 +431,84 @@ impl<'a> DomExpressionsCompat2<'a> {
 
         Some(Statement::ExpressionStatement(Box::new_in(
             ExpressionStatement {
-                span: SPAN,
+                span,
                 expression: Expression::CallExpression(Box::new_in(effect_call, self.allocator)),
             },
             self.allocator,
         )))
     }
 
+    /// Create a namespaced attribute statement
+    /// Generates: `_$effect(() => _$setAttributeNS(element, "ns", "name", value));`
+    pub(super) fn create_set_attribute_ns_call(
+        &self,
+        element_var: &str,
+        namespace: &str,
+        attr_name: &str,
+        value_expr: &Expression<'a>,
+    ) -> Statement<'a> {
+        use oxc_allocator::CloneIn;
+        use oxc_ast::ast::*;
+
+        // The generated call maps back to the value's location
+        let span = value_expr.span();
+
+        let string_arg = |value: &str| {
+            Argument::StringLiteral(Box::new_in(
+                StringLiteral {
+                    span: SPAN,
+                    value: Atom::from(self.allocator.alloc_str(value)),
+                    raw: None,
+                    lone_surrogates: false,
+                },
+                self.allocator,
+            ))
+        };
+
+        let mut args = OxcVec::new_in(self.allocator);
+        args.push(Argument::Identifier(Box::new_in(
+            IdentifierReference {
+                span: SPAN,
+                name: Atom::from(self.allocator.alloc_str(element_var)),
+                reference_id: None.into(),
+            },
+            self.allocator,
+        )));
+        args.push(string_arg(namespace));
+        args.push(string_arg(attr_name));
+        args.push(Argument::from(value_expr.clone_in(self.allocator)));
+
+        let set_attr_ns_call = CallExpression {
+            span,
+            callee: Expression::Identifier(Box::new_in(
+                IdentifierReference {
+                    span: SPAN,
+                    name: Atom::from(self.runtime_name("setAttributeNS")),
+                    reference_id: None.into(),
+                },
+                self.allocator,
+            )),
+            arguments: args,
+            optional: false,
+            type_arguments: None,
+            pure: false,
+        };
+
+        let set_attr_ns_call =
+            Expression::CallExpression(Box::new_in(set_attr_ns_call, self.allocator));
+        if contains_await_expression(value_expr) {
+            return Statement::ExpressionStatement(Box::new_in(
+                ExpressionStatement {
+                    span,
+                    expression: set_attr_ns_call,
+                },
+                self.allocator,
+            ));
+        }
+
+        self.create_effect_statement(set_attr_ns_call)
+    }
+
     /// Create a property assignment statement: element.propName = value;
     pub(super) fn create_property_assignment(
         &self,
@@ -457,18 +547,104 @@ impl<'a> DomExpressionsCompat2<'a> {
             right: value_expr.clone_in(self.allocator),
         };
 
+        let assignment = Expression::AssignmentExpression(Box::new_in(assignment, self.allocator));
+
+        // Dynamic values are re-assigned whenever they change
+        if is_dynamic_expression(value_expr) {
+            return Some(self.create_effect_statement(assignment));
+        }
+
         Some(Statement::ExpressionStatement(Box::new_in(
             ExpressionStatement {
                 span: SPAN,
-                expression: Expression::AssignmentExpression(Box::new_in(
-                    assignment,
-                    self.allocator,
-                )),
+                expression: assignment,
             },
             self.allocator,
         )))
     }
 
+    /// Create an effect statement: _$effect(() => body);
+    pub(super) fn create_effect_statement(&self, body: Expression<'a>) -> Statement<'a> {
+        use oxc_ast::ast::*;
+
+        let arrow_fn = self.create_accessor(body);
+
+        let effect_fn = IdentifierReference {
+            span: SPAN,
+            name: Atom::from(self.runtime_name("effect")),
+            reference_id: None.into(),
+        };
+
+        let effect_call = CallExpression {
+            span: SPAN,
+            callee: Expression::Identifier(Box::new_in(effect_fn, self.allocator)),
+            arguments: OxcVec::from_iter_in(
+                [Argument::ArrowFunctionExpression(arrow_fn)],
+                self.allocator,
+            ),
+            optional: false,
+            type_arguments: None,
+            pure: false,
+        };
+
+        Statement::ExpressionStatement(Box::new_in(
+            ExpressionStatement {
+                span: SPAN,
+                expression: Expression::CallExpression(Box::new_in(effect_call, self.allocator)),
+            },
+            self.allocator,
+        ))
+    }
+
+    /// Create an accessor arrow function: () => body
+    pub(super) fn create_accessor(
+        &self,
+        body: Expression<'a>,
+    ) -> Box<'a, ArrowFunctionExpression<'a>> {
+        use oxc_ast::ast::*;
+
+        // The accessor maps back to the wrapped expression's location
+        let span = body.span();
+        let arrow_body = FunctionBody {
+            span: SPAN,
+            directives: OxcVec::new_in(self.allocator),
+            statements: OxcVec::from_iter_in(
+                [Statement::ExpressionStatement(Box::new_in(
+                    ExpressionStatement {
+                        span,
+                        expression: body,
+                    },
+                    self.allocator,
+                ))],
+                self.allocator,
+            ),
+        };
+
+        Box::new_in(
+            ArrowFunctionExpression {
+                span,
+                expression: true,
+                r#async: false,
+                params: Box::new_in(
+                    FormalParameters {
+                        span: SPAN,
+                        kind: FormalParameterKind::ArrowFormalParameters,
+                        items: OxcVec::new_in(self.allocator),
+                        rest: None,
+                    },
+                    self.allocator,
+                ),
+                body: Box::new_in(arrow_body, self.allocator),
+                type_parameters: None,
+                return_type: None,
+                scope_id: None.into(),
+                pure: false,
+                pife: false,
+            },
+            self.allocator,
+        )
+    }
+
     /// Create a static setAttribute call (without effect wrapper)
     pub(super) fn create_static_set_attribute_call(
         &self,
@@ -482,7 +658,7 @@ impl<'a> DomExpressionsCompat2<'a> {
         // Create: _$setAttribute(element, "attr", value)
         let set_attr_fn = IdentifierReference {
             span: SPAN,
-            name: Atom::from("_$setAttribute"),
+            name: Atom::from(self.runtime_name("setAttribute")),
             reference_id: None.into(),
         };
 
@@ -530,69 +706,209 @@ impl<'a> DomExpressionsCompat2<'a> {
         )))
     }
 
-    /// Create a delegated event handler: element.$$eventName = handler;
+    /// Create the statements binding a ref to an element
+    ///
+    /// Like babel, callbacks are passed to `_$use` and assignable targets get
+    /// the element when they don't hold a callback:
+    ///
+    /// ```js
+    /// var _ref$ = target;
+    /// typeof _ref$ === "function" ? _$use(_ref$, _el$) : (target = _el$);
+    /// ```
+    ///
+    /// Other expressions (calls, optional chains) are only used when they
+    /// evaluate to a function.
     pub(super) fn create_ref_call(
-        &self,
+        &mut self,
         element_var: &str,
         ref_expr: &Expression<'a>,
-    ) -> Option<Statement<'a>> {
+    ) -> Vec<Statement<'a>> {
         use oxc_allocator::CloneIn;
         use oxc_ast::ast::*;
 
-        // Need to add _$use import
-        // This will be handled by add_import call from the slot handler
+        // Parentheses and TypeScript wrappers (`r!`, `r as T`) don't change the target
+        let target = ref_expr.get_inner_expression();
 
-        // Create: _$use(ref, element)
-        let fn_name = IdentifierReference {
-            span: SPAN,
-            name: Atom::from("_$use"),
-            reference_id: None.into(),
+        let is_callback = match target {
+            Expression::ArrowFunctionExpression(_) | Expression::FunctionExpression(_) => true,
+            Expression::Identifier(ident) => self.const_bindings.contains(ident.name.as_str()),
+            _ => false,
         };
+        if is_callback {
+            let call = self.create_use_call(ref_expr.clone_in(self.allocator), element_var);
+            return vec![self.expression_statement(call)];
+        }
 
-        let element_ref = IdentifierReference {
-            span: SPAN,
-            name: Atom::from(self.allocator.alloc_str(element_var)),
-            reference_id: None.into(),
+        // Create: var _ref$ = target;
+        let ref_var = self.generate_ref_var();
+        let declaration = self.variable_declaration(&ref_var, ref_expr.clone_in(self.allocator));
+
+        // typeof _ref$ === "function"
+        let is_function = Expression::BinaryExpression(Box::new_in(
+            BinaryExpression {
+                span: SPAN,
+                left: Expression::UnaryExpression(Box::new_in(
+                    UnaryExpression {
+                        span: SPAN,
+                        operator: UnaryOperator::Typeof,
+                        argument: self.identifier_expression(&ref_var),
+                    },
+                    self.allocator,
+                )),
+                operator: BinaryOperator::StrictEquality,
+                right: Expression::StringLiteral(Box::new_in(
+                    StringLiteral {
+                        span: SPAN,
+                        value: Atom::from("function"),
+                        raw: None,
+                        lone_surrogates: false,
+                    },
+                    self.allocator,
+                )),
+            },
+            self.allocator,
+        ));
+        let use_call = self.create_use_call(self.identifier_expression(&ref_var), element_var);
+
+        let assignment_target = match target {
+            Expression::Identifier(ident) => Some(AssignmentTarget::AssignmentTargetIdentifier(
+                ident.clone_in(self.allocator),
+            )),
+            Expression::StaticMemberExpression(member) => Some(
+                AssignmentTarget::StaticMemberExpression(member.clone_in(self.allocator)),
+            ),
+            Expression::ComputedMemberExpression(member) => Some(
+                AssignmentTarget::ComputedMemberExpression(member.clone_in(self.allocator)),
+            ),
+            Expression::PrivateFieldExpression(member) => Some(
+                AssignmentTarget::PrivateFieldExpression(member.clone_in(self.allocator)),
+            ),
+            _ => None,
+        };
+
+        let binding = match assignment_target {
+            // typeof _ref$ === "function" ? _$use(_ref$, _el$) : (target = _el$)
+            Some(left) => Expression::ConditionalExpression(Box::new_in(
+                ConditionalExpression {
+                    span: SPAN,
+                    test: is_function,
+                    consequent: use_call,
+                    alternate: Expression::AssignmentExpression(Box::new_in(
+                        AssignmentExpression {
+                            span: SPAN,
+                            operator: AssignmentOperator::Assign,
+                            left,
+                            right: self.identifier_expression(element_var),
+                        },
+                        self.allocator,
+                    )),
+                },
+                self.allocator,
+            )),
+            // typeof _ref$ === "function" && _$use(_ref$, _el$)
+            None => Expression::LogicalExpression(Box::new_in(
+                LogicalExpression {
+                    span: SPAN,
+                    left: is_function,
+                    operator: LogicalOperator::And,
+                    right: use_call,
+                },
+                self.allocator,
+            )),
         };
 
+        vec![declaration, self.expression_statement(binding)]
+    }
+
+    /// Create: _$use(callback, element)
+    fn create_use_call(&self, callback: Expression<'a>, element_var: &str) -> Expression<'a> {
         let mut args = OxcVec::new_in(self.allocator);
-        args.push(Argument::from(ref_expr.clone_in(self.allocator)));
-        args.push(Argument::Identifier(Box::new_in(
-            element_ref,
+        args.push(Argument::from(callback));
+        args.push(Argument::from(self.identifier_expression(element_var)));
+
+        Expression::CallExpression(Box::new_in(
+            CallExpression {
+                span: SPAN,
+                callee: self.identifier_expression(self.runtime_name("use")),
+                arguments: args,
+                optional: false,
+                type_arguments: None,
+                pure: false,
+            },
             self.allocator,
-        )));
+        ))
+    }
 
-        let call_expr = CallExpression {
+    /// Declare a variable: `var name = init;`
+    pub(super) fn variable_declaration(&self, name: &str, init: Expression<'a>) -> Statement<'a> {
+        let mut declarations = OxcVec::new_in(self.allocator);
+        declarations.push(VariableDeclarator {
             span: SPAN,
-            callee: Expression::Identifier(Box::new_in(fn_name, self.allocator)),
-            arguments: args,
-            optional: false,
-            type_arguments: None,
-            pure: false,
-        };
+            kind: self.template_decl_kind(),
+            id: BindingPattern {
+                kind: BindingPatternKind::BindingIdentifier(Box::new_in(
+                    BindingIdentifier {
+                        span: SPAN,
+                        name: Atom::from(self.allocator.alloc_str(name)),
+                        symbol_id: None.into(),
+                    },
+                    self.allocator,
+                )),
+                type_annotation: None,
+                optional: false,
+            },
+            init: Some(init),
+            definite: false,
+        });
+        Statement::VariableDeclaration(Box::new_in(
+            VariableDeclaration {
+                span: SPAN,
+                kind: self.template_decl_kind(),
+                declarations,
+                declare: false,
+            },
+            self.allocator,
+        ))
+    }
 
-        Some(Statement::ExpressionStatement(Box::new_in(
+    /// Create a reference to a variable
+    pub(super) fn identifier_expression(&self, name: &str) -> Expression<'a> {
+        Expression::Identifier(Box::new_in(
+            IdentifierReference {
+                span: SPAN,
+                name: Atom::from(self.allocator.alloc_str(name)),
+                reference_id: None.into(),
+            },
+            self.allocator,
+        ))
+    }
+
+    /// Wrap an expression in a statement
+    pub(super) fn expression_statement(&self, expression: Expression<'a>) -> Statement<'a> {
+        Statement::ExpressionStatement(Box::new_in(
             ExpressionStatement {
                 span: SPAN,
-                expression: Expression::CallExpression(Box::new_in(call_expr, self.allocator)),
+                expression,
             },
             self.allocator,
-        )))
+        ))
     }
 
-    /// Create a spread call: _$spread(element, props, false, true)
+    /// Create a spread call: _$spread(element, props, isSVG, hasChildren)
     pub(super) fn create_spread_call(
         &self,
         element_var: &str,
         spread_expr: &Expression<'a>,
+        is_svg: bool,
+        has_children: bool,
     ) -> Option<Statement<'a>> {
         use oxc_allocator::CloneIn;
         use oxc_ast::ast::*;
 
-        // Create: _$spread(element, props, false, true)
+        // Create: _$spread(element, props, isSVG, hasChildren)
         let spread_id = IdentifierReference {
             span: SPAN,
-            name: Atom::from("_$spread"),
+            name: Atom::from(self.runtime_name("spread")),
             reference_id: None.into(),
         };
 
@@ -613,20 +929,21 @@ impl<'a> DomExpressionsCompat2<'a> {
         // Arg 2: spread expression
         args.push(Argument::from(spread_expr.clone_in(self.allocator)));
 
-        // Arg 3: false (prevProps)
+        // Arg 3: whether the element is an SVG element
         args.push(Argument::from(Expression::BooleanLiteral(Box::new_in(
             BooleanLiteral {
                 span: SPAN,
-                value: false,
+                value: is_svg,
             },
             self.allocator,
         ))));
 
-        // Arg 4: true (merge)
+        // Arg 4: whether the element has JSX children, which a `children` prop
+        // must not replace
         args.push(Argument::from(Expression::BooleanLiteral(Box::new_in(
             BooleanLiteral {
                 span: SPAN,
-                value: true,
+                value: has_children,
             },
             self.allocator,
         ))));
@@ -661,7 +978,7 @@ impl<'a> DomExpressionsCompat2<'a> {
         // Create: _$classList(element, classListObject)
         let fn_name = IdentifierReference {
             span: SPAN,
-            name: Atom::from("_$classList"),
+            name: Atom::from(self.runtime_name("classList")),
             reference_id: None.into(),
         };
 
@@ -708,7 +1025,7 @@ impl<'a> DomExpressionsCompat2<'a> {
         // Create: _$style(element, styleObject)
         let fn_name = IdentifierReference {
             span: SPAN,
-            name: Atom::from("_$style"),
+            name: Atom::from(self.runtime_name("style")),
             reference_id: None.into(),
         };
 
@@ -743,33 +1060,60 @@ impl<'a> DomExpressionsCompat2<'a> {
         )))
     }
 
-    /// Create a use directive call: _$use(directive, element, value)
+    /// Create a use directive call: _$use(directive, element, () => value)
+    ///
+    /// The value is passed as an accessor; function values are passed as-is.
     pub(super) fn create_use_directive_call(
         &self,
         element_var: &str,
-        _directive_name: &str,
-        directive_expr: &Expression<'a>,
+        directive_name: &str,
+        value_expr: &Expression<'a>,
     ) -> Option<Statement<'a>> {
         use oxc_allocator::CloneIn;
         use oxc_ast::ast::*;
 
-        // Create: directive(element, value) or _$use(directive, element, value)
-        // For now, just call the directive directly
+        let fn_name = IdentifierReference {
+            span: SPAN,
+            name: Atom::from(self.runtime_name("use")),
+            reference_id: None.into(),
+        };
+
+        // The directive is the identifier named after the `use:` prefix
+        let directive_ref = IdentifierReference {
+            span: SPAN,
+            name: Atom::from(self.allocator.alloc_str(directive_name)),
+            reference_id: None.into(),
+        };
+
         let element_ref = IdentifierReference {
             span: SPAN,
             name: Atom::from(self.allocator.alloc_str(element_var)),
             reference_id: None.into(),
         };
 
+        let value_arg = match value_expr {
+            Expression::ArrowFunctionExpression(_) | Expression::FunctionExpression(_) => {
+                Argument::from(value_expr.clone_in(self.allocator))
+            }
+            _ => Argument::ArrowFunctionExpression(
+                self.create_accessor(value_expr.clone_in(self.allocator)),
+            ),
+        };
+
         let mut args = OxcVec::new_in(self.allocator);
+        args.push(Argument::Identifier(Box::new_in(
+            directive_ref,
+            self.allocator,
+        )));
         args.push(Argument::Identifier(Box::new_in(
             element_ref,
             self.allocator,
         )));
+        args.push(value_arg);
 
         let call_expr = CallExpression {
             span: SPAN,
-            callee: directive_expr.clone_in(self.allocator),
+            callee: Expression::Identifier(Box::new_in(fn_name, self.allocator)),
             arguments: args,
             optional: false,
             type_arguments: None,
@@ -798,7 +1142,7 @@ impl<'a> DomExpressionsCompat2<'a> {
         // Create: _$className(element, "className", value)
         let fn_name = IdentifierReference {
             span: SPAN,
-            name: Atom::from("_$className"),
+            name: Atom::from(self.runtime_name("className")),
             reference_id: None.into(),
         };
 
@@ -833,10 +1177,17 @@ impl<'a> DomExpressionsCompat2<'a> {
             pure: false,
         };
 
+        let call_expr = Expression::CallExpression(Box::new_in(call_expr, self.allocator));
+
+        // Reactive values toggle the class whenever they change
+        if is_dynamic_expression(value_expr) {
+            return Some(self.create_effect_statement(call_expr));
+        }
+
         Some(Statement::ExpressionStatement(Box::new_in(
             ExpressionStatement {
                 span: SPAN,
-                expression: Expression::CallExpression(Box::new_in(call_expr, self.allocator)),
+                expression: call_expr,
             },
             self.allocator,
         )))
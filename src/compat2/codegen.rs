@@ -16,10 +16,13 @@
 use oxc_allocator::Box;
 use oxc_allocator::Vec as OxcVec;
 use oxc_ast::ast::*;
-use oxc_span::SPAN;
+use oxc_span::{GetSpan, SPAN};
 
 use crate::compat::get_import_priority;
-use crate::template::{SlotType, Template};
+use crate::template::{
+    attribute_slot_expression, static_child_text, DynamicSlot, SlotType, Template,
+};
+use crate::utils::{contains_await_expression, is_dynamic_expression, is_nullish_jsx_expression};
 
 use super::DomExpressionsCompat2;
 
@@ -47,13 +50,18 @@ impl<'a> DomExpressionsCompat2<'a> {
     ) -> OxcVec<'a, Statement<'a>> {
         let mut stmts = OxcVec::new_in(self.allocator);
         let mut expr_index = 0;
+        // Index of the first statement generated for each slot
+        let mut slot_starts = Vec::with_capacity(template.dynamic_slots.len());
 
         for slot in &template.dynamic_slots {
+            slot_starts.push(stmts.len());
             match &slot.slot_type {
                 SlotType::TextContent => {
                     self.add_import("insert");
 
                     if expr_index < expressions.len() {
+                        let parent_var =
+                            self.element_var_for_slot(&slot.path, root_var, path_to_var);
                         let marker_var = if let Some(marker_path) = &slot.marker_path {
                             path_to_var.get(marker_path).map(|s| s.as_str())
                         } else {
@@ -61,9 +69,10 @@ impl<'a> DomExpressionsCompat2<'a> {
                         };
 
                         if let Some(insert_stmt) = self.create_insert_call_with_marker(
-                            root_var,
+                            parent_var,
                             &expressions[expr_index],
                             marker_var,
+                            slot.only_child,
                         ) {
                             stmts.push(insert_stmt);
                         }
@@ -72,9 +81,11 @@ impl<'a> DomExpressionsCompat2<'a> {
                 }
                 SlotType::Attribute(attr_name) => {
                     self.add_import("setAttribute");
-                    self.add_import("effect");
 
                     if expr_index < expressions.len() {
+                        if !contains_await_expression(&expressions[expr_index]) {
+                            self.add_import("effect");
+                        }
                         let element_var = self.element_var_for_slot(&slot.path, root_var, path_to_var);
 
                         if let Some(attr_stmt) = self.create_set_attribute_call(
@@ -87,11 +98,32 @@ impl<'a> DomExpressionsCompat2<'a> {
                         expr_index += 1;
                     }
                 }
+                SlotType::AttributeNS { ns, name } => {
+                    self.add_import("setAttributeNS");
+
+                    if expr_index < expressions.len() {
+                        if !contains_await_expression(&expressions[expr_index]) {
+                            self.add_import("effect");
+                        }
+                        let element_var =
+                            self.element_var_for_slot(&slot.path, root_var, path_to_var);
+
+                        stmts.push(self.create_set_attribute_ns_call(
+                            element_var,
+                            ns,
+                            name,
+                            &expressions[expr_index],
+                        ));
+                        expr_index += 1;
+                    }
+                }
                 SlotType::BoolAttribute(attr_name) => {
                     self.add_import("setBoolAttribute");
-                    self.add_import("effect");
 
                     if expr_index < expressions.len() {
+                        if !contains_await_expression(&expressions[expr_index]) {
+                            self.add_import("effect");
+                        }
                         let element_var = self.element_var_for_slot(&slot.path, root_var, path_to_var);
 
                         if let Some(stmt) = self.create_set_bool_attribute_call(
@@ -106,6 +138,10 @@ impl<'a> DomExpressionsCompat2<'a> {
                 }
                 SlotType::PropAttribute(attr_name) => {
                     if expr_index < expressions.len() {
+                        if is_dynamic_expression(&expressions[expr_index]) {
+                            self.add_import("effect");
+                        }
+
                         let element_var = self.element_var_for_slot(&slot.path, root_var, path_to_var);
 
                         if let Some(stmt) = self.create_property_assignment(
@@ -138,9 +174,11 @@ impl<'a> DomExpressionsCompat2<'a> {
                     if expr_index < expressions.len() {
                         let element_var = self.element_var_for_slot(&slot.path, root_var, path_to_var);
 
-                        use crate::utils::should_delegate_event;
-                        let should_delegate =
-                            self.options.delegate_events && should_delegate_event(event_name);
+                        use crate::utils::{is_passive_event, should_delegate_event};
+                        let passive = self.options.passive_events && is_passive_event(event_name);
+                        let should_delegate = self.options.delegate_events
+                            && should_delegate_event(event_name)
+                            && !passive;
 
                         let handler_expr = &expressions[expr_index];
                         let is_array = matches!(handler_expr, Expression::ArrayExpression(_));
@@ -192,6 +230,7 @@ impl<'a> DomExpressionsCompat2<'a> {
                                                 event_name,
                                                 &wrapper,
                                                 false,
+                                                passive,
                                             ) {
                                                 stmts.push(stmt);
                                             }
@@ -201,6 +240,7 @@ impl<'a> DomExpressionsCompat2<'a> {
                                                 event_name,
                                                 handler,
                                                 false,
+                                                passive,
                                             ) {
                                                 stmts.push(stmt);
                                             }
@@ -237,12 +277,14 @@ impl<'a> DomExpressionsCompat2<'a> {
                                     }
                                 }
                             } else {
-                                if is_inline_function {
+                                // Passive listeners need the native options argument
+                                if is_inline_function || passive {
                                     if let Some(stmt) = self.create_add_event_listener(
                                         element_var,
                                         event_name,
                                         handler_expr,
                                         false,
+                                        passive,
                                     ) {
                                         stmts.push(stmt);
                                     }
@@ -264,11 +306,21 @@ impl<'a> DomExpressionsCompat2<'a> {
                     }
                 }
                 SlotType::OnEvent(event_name) => {
-                    self.add_import("addEventListener");
-
                     if expr_index < expressions.len() {
                         let element_var = self.element_var_for_slot(&slot.path, root_var, path_to_var);
 
+                        // { handleEvent, ...options } objects bind directly with their options
+                        if let Some(listener_stmts) = self.create_event_listener_with_options(
+                            element_var,
+                            event_name,
+                            &expressions[expr_index],
+                        ) {
+                            stmts.extend(listener_stmts);
+                            expr_index += 1;
+                            continue;
+                        }
+
+                        self.add_import("addEventListener");
                         if let Some(stmt) = self.create_add_event_listener_helper(
                             element_var,
                             event_name,
@@ -296,6 +348,8 @@ impl<'a> DomExpressionsCompat2<'a> {
                     }
                 }
                 SlotType::UseDirective(directive_name) => {
+                    self.add_import("use");
+
                     if expr_index < expressions.len() {
                         let element_var = self.element_var_for_slot(&slot.path, root_var, path_to_var);
 
@@ -313,6 +367,10 @@ impl<'a> DomExpressionsCompat2<'a> {
                     self.add_import("className");
 
                     if expr_index < expressions.len() {
+                        if is_dynamic_expression(&expressions[expr_index]) {
+                            self.add_import("effect");
+                        }
+
                         let element_var = self.element_var_for_slot(&slot.path, root_var, path_to_var);
 
                         if let Some(stmt) = self.create_class_name_call(
@@ -327,9 +385,12 @@ impl<'a> DomExpressionsCompat2<'a> {
                 }
                 SlotType::StyleProperty(property_name) => {
                     self.add_import("setStyleProperty");
-                    self.add_import("effect");
 
                     if expr_index < expressions.len() {
+                        if is_dynamic_expression(&expressions[expr_index]) {
+                            self.add_import("effect");
+                        }
+
                         let element_var = self.element_var_for_slot(&slot.path, root_var, path_to_var);
 
                         if let Some(stmt) = self.create_set_style_property_call(
@@ -348,11 +409,7 @@ impl<'a> DomExpressionsCompat2<'a> {
                     if expr_index < expressions.len() {
                         let element_var = self.element_var_for_slot(&slot.path, root_var, path_to_var);
 
-                        if let Some(stmt) =
-                            self.create_ref_call(element_var, &expressions[expr_index])
-                        {
-                            stmts.push(stmt);
-                        }
+                        stmts.extend(self.create_ref_call(element_var, &expressions[expr_index]));
                         expr_index += 1;
                     }
                 }
@@ -384,14 +441,20 @@ impl<'a> DomExpressionsCompat2<'a> {
                         expr_index += 1;
                     }
                 }
-                SlotType::Spread => {
+                SlotType::Spread {
+                    is_svg,
+                    has_children,
+                } => {
                     self.add_import("spread");
 
+                    // The props of all spreads and the attributes merged with them
                     if expr_index < expressions.len() {
-                        let element_var = self.element_var_for_slot(&slot.path, root_var, path_to_var);
+                        let element_var =
+                            self.element_var_for_slot(&slot.path, root_var, path_to_var);
+                        let props = &expressions[expr_index];
 
                         if let Some(stmt) =
-                            self.create_spread_call(element_var, &expressions[expr_index])
+                            self.create_spread_call(element_var, props, *is_svg, *has_children)
                         {
                             stmts.push(stmt);
                         }
@@ -401,64 +464,112 @@ impl<'a> DomExpressionsCompat2<'a> {
             }
         }
 
-        stmts
+        self.order_element_setup(stmts, &template.dynamic_slots, &slot_starts)
+    }
+
+    /// Reorder the statements binding each element so refs come first and
+    /// event handlers last, like babel, regardless of attribute order
+    ///
+    /// Statements are grouped by the slot that generated them; consecutive
+    /// attribute slots of one element are stably sorted by `setup_priority`.
+    fn order_element_setup(
+        &self,
+        stmts: OxcVec<'a, Statement<'a>>,
+        slots: &[DynamicSlot],
+        slot_starts: &[usize],
+    ) -> OxcVec<'a, Statement<'a>> {
+        let mut segments: Vec<Vec<Statement<'a>>> = slots.iter().map(|_| Vec::new()).collect();
+        let mut slot_index = 0;
+        for (stmt_index, stmt) in stmts.into_iter().enumerate() {
+            while slot_index + 1 < slot_starts.len() && slot_starts[slot_index + 1] <= stmt_index {
+                slot_index += 1;
+            }
+            segments[slot_index].push(stmt);
+        }
+
+        let mut order: Vec<usize> = (0..slots.len()).collect();
+        let mut group_start = 0;
+        while group_start < slots.len() {
+            let is_setup = |slot: &DynamicSlot| !matches!(slot.slot_type, SlotType::TextContent);
+            let mut group_end = group_start + 1;
+            if is_setup(&slots[group_start]) {
+                while group_end < slots.len()
+                    && is_setup(&slots[group_end])
+                    && slots[group_end].path == slots[group_start].path
+                {
+                    group_end += 1;
+                }
+                order[group_start..group_end]
+                    .sort_by_key(|&index| setup_priority(&slots[index].slot_type));
+            }
+            group_start = group_end;
+        }
+
+        let mut ordered = OxcVec::with_capacity_in(slot_starts.len(), self.allocator);
+        for index in order {
+            ordered.extend(std::mem::take(&mut segments[index]));
+        }
+        ordered
     }
 
     /// Extract all dynamic expressions from JSX element in order
     pub(super) fn extract_expressions_from_jsx(
-        &self,
+        &mut self,
         jsx_elem: &JSXElement<'a>,
+        template: &Template,
         expressions: &mut Vec<Expression<'a>>,
     ) {
-        use oxc_allocator::CloneIn;
-
         for attr in &jsx_elem.opening_element.attributes {
-            if let JSXAttributeItem::Attribute(attr) = attr {
-                if let Some(JSXAttributeValue::ExpressionContainer(container)) = &attr.value {
-                    match &container.expression {
-                        JSXExpression::StringLiteral(_)
-                        | JSXExpression::NumericLiteral(_)
-                        | JSXExpression::EmptyExpression(_) => {}
-                        expr => {
-                            if let Some(expr_ref) = expr.as_expression() {
-                                expressions.push(expr_ref.clone_in(self.allocator));
-                            }
-                        }
+            match attr {
+                JSXAttributeItem::Attribute(attr) => {
+                    // Only attributes with a dynamic slot contribute an expression
+                    if template.slot_attributes.contains(&attr.span) {
+                        expressions.push(attribute_slot_expression(attr, self.allocator));
+                    }
+                }
+                JSXAttributeItem::SpreadAttribute(spread) => {
+                    // The first spread's slot takes the props merged for the spread call
+                    if template.slot_attributes.contains(&spread.span) {
+                        let props =
+                            self.create_element_spread_props(&jsx_elem.opening_element.attributes);
+                        expressions.push(props);
                     }
                 }
             }
         }
 
         for child in &jsx_elem.children {
-            self.extract_expressions_from_child(child, expressions);
+            self.extract_expressions_from_child(child, template, expressions);
         }
     }
 
     /// Extract expressions from a JSX child
     pub(super) fn extract_expressions_from_child(
-        &self,
+        &mut self,
         child: &JSXChild<'a>,
+        template: &Template,
         expressions: &mut Vec<Expression<'a>>,
     ) {
         use oxc_allocator::CloneIn;
 
         match child {
             JSXChild::Element(elem) => {
-                self.extract_expressions_from_jsx(elem, expressions);
+                self.extract_expressions_from_jsx(elem, template, expressions);
             }
-            JSXChild::ExpressionContainer(container) => {
-                match &container.expression {
-                    JSXExpression::StringLiteral(_)
-                    | JSXExpression::NumericLiteral(_)
-                    | JSXExpression::EmptyExpression(_) => {}
-                    expr => {
-                        if let Some(expr_ref) = expr.as_expression() {
-                            expressions.push(expr_ref.clone_in(self.allocator));
-                        }
+            JSXChild::ExpressionContainer(container) => match &container.expression {
+                JSXExpression::EmptyExpression(_) => {}
+                expr if is_nullish_jsx_expression(expr) => {}
+                expr if static_child_text(expr).is_some() => {}
+                expr => {
+                    if let Some(expr_ref) = expr.as_expression() {
+                        expressions.push(expr_ref.clone_in(self.allocator));
                     }
                 }
+            },
+            JSXChild::Spread(spread) => {
+                expressions.push(spread.expression.clone_in(self.allocator));
             }
-            JSXChild::Text(_) | JSXChild::Fragment(_) | JSXChild::Spread(_) => {}
+            JSXChild::Text(_) | JSXChild::Fragment(_) => {}
         }
     }
 
@@ -485,19 +596,51 @@ impl<'a> DomExpressionsCompat2<'a> {
     }
 
     /// Create import statements for all required runtime functions
+    ///
+    /// Emits one statement per function, or a single merged statement when
+    /// `merged_imports` is set.
     pub(super) fn create_import_statements(&self) -> Vec<Statement<'a>> {
-        use oxc_ast::ast::*;
-
-        let mut statements = Vec::new();
+        // Scripts cannot contain import declarations; they either load the
+        // runtime with `require` or leave it to the caller
+        if !self.is_module && !self.options.require_in_scripts {
+            return Vec::new();
+        }
 
-        let mut sorted_imports: Vec<_> = self.required_imports.iter().collect();
+        let mut sorted_imports: Vec<&str> = self
+            .required_imports
+            .iter()
+            .map(|name| name.as_str())
+            .collect();
         sorted_imports.sort_by_key(|name| get_import_priority(name));
 
-        for import_name in sorted_imports {
-            let local_name = format!("_${}", import_name);
+        let groups: Vec<&[&str]> = if self.options.merged_imports {
+            vec![&sorted_imports[..]]
+        } else {
+            sorted_imports.chunks(1).collect()
+        };
+
+        groups
+            .into_iter()
+            .map(|names| {
+                if self.is_module {
+                    self.create_import_declaration(names)
+                } else {
+                    self.create_require_statement(names)
+                }
+            })
+            .collect()
+    }
+
+    /// Create a single import declaration for the given runtime functions
+    /// Generates: `import { template as _$template, insert as _$insert } from "solid-js/web";`
+    fn create_import_declaration(&self, import_names: &[&str]) -> Statement<'a> {
+        use oxc_ast::ast::*;
+
+        let mut specifiers = OxcVec::new_in(self.allocator);
+        for import_name in import_names {
             let local = BindingIdentifier {
                 span: SPAN,
-                name: Atom::from(self.allocator.alloc_str(&local_name)),
+                name: Atom::from(self.runtime_name(import_name)),
                 symbol_id: None.into(),
             };
 
@@ -506,7 +649,7 @@ impl<'a> DomExpressionsCompat2<'a> {
                 name: Atom::from(self.allocator.alloc_str(import_name)),
             });
 
-            let specifier = ImportDeclarationSpecifier::ImportSpecifier(Box::new_in(
+            specifiers.push(ImportDeclarationSpecifier::ImportSpecifier(Box::new_in(
                 ImportSpecifier {
                     span: SPAN,
                     imported,
@@ -514,34 +657,125 @@ impl<'a> DomExpressionsCompat2<'a> {
                     import_kind: ImportOrExportKind::Value,
                 },
                 self.allocator,
-            ));
+            )));
+        }
 
-            let mut specifiers = OxcVec::new_in(self.allocator);
-            specifiers.push(specifier);
+        let source = StringLiteral {
+            span: SPAN,
+            value: Atom::from(self.allocator.alloc_str(&self.options.module_name)),
+            raw: None,
+            lone_surrogates: false,
+        };
+
+        let import_decl = ImportDeclaration {
+            span: SPAN,
+            specifiers: Some(specifiers),
+            source,
+            with_clause: None,
+            import_kind: ImportOrExportKind::Value,
+            phase: None,
+        };
+
+        let module_decl =
+            ModuleDeclaration::ImportDeclaration(Box::new_in(import_decl, self.allocator));
+
+        Statement::from(module_decl)
+    }
+
+    /// Create a require statement for the given runtime functions
+    /// Generates: `const { template: _$template, insert: _$insert } = require("solid-js/web");`
+    fn create_require_statement(&self, import_names: &[&str]) -> Statement<'a> {
+        use oxc_ast::ast::*;
+
+        let mut properties = OxcVec::new_in(self.allocator);
+        for import_name in import_names {
+            properties.push(BindingProperty {
+                span: SPAN,
+                key: PropertyKey::StaticIdentifier(Box::new_in(
+                    IdentifierName {
+                        span: SPAN,
+                        name: Atom::from(self.allocator.alloc_str(import_name)),
+                    },
+                    self.allocator,
+                )),
+                value: BindingPattern {
+                    kind: BindingPatternKind::BindingIdentifier(Box::new_in(
+                        BindingIdentifier {
+                            span: SPAN,
+                            name: Atom::from(self.runtime_name(import_name)),
+                            symbol_id: None.into(),
+                        },
+                        self.allocator,
+                    )),
+                    type_annotation: None,
+                    optional: false,
+                },
+                shorthand: false,
+                computed: false,
+            });
+        }
 
-            let source = StringLiteral {
+        let mut args = OxcVec::new_in(self.allocator);
+        args.push(Argument::StringLiteral(Box::new_in(
+            StringLiteral {
                 span: SPAN,
                 value: Atom::from(self.allocator.alloc_str(&self.options.module_name)),
                 raw: None,
                 lone_surrogates: false,
-            };
+            },
+            self.allocator,
+        )));
 
-            let import_decl = ImportDeclaration {
-                span: SPAN,
-                specifiers: Some(specifiers),
-                source,
-                with_clause: None,
-                import_kind: ImportOrExportKind::Value,
-                phase: None,
-            };
+        let require_call = CallExpression {
+            span: SPAN,
+            callee: Expression::Identifier(Box::new_in(
+                IdentifierReference {
+                    span: SPAN,
+                    name: Atom::from("require"),
+                    reference_id: None.into(),
+                },
+                self.allocator,
+            )),
+            arguments: args,
+            optional: false,
+            type_arguments: None,
+            pure: false,
+        };
 
-            let module_decl =
-                ModuleDeclaration::ImportDeclaration(Box::new_in(import_decl, self.allocator));
+        let declarator = VariableDeclarator {
+            span: SPAN,
+            kind: VariableDeclarationKind::Const,
+            id: BindingPattern {
+                kind: BindingPatternKind::ObjectPattern(Box::new_in(
+                    ObjectPattern {
+                        span: SPAN,
+                        properties,
+                        rest: None,
+                    },
+                    self.allocator,
+                )),
+                type_annotation: None,
+                optional: false,
+            },
+            init: Some(Expression::CallExpression(Box::new_in(
+                require_call,
+                self.allocator,
+            ))),
+            definite: false,
+        };
 
-            statements.push(Statement::from(module_decl));
-        }
+        let mut declarations = OxcVec::new_in(self.allocator);
+        declarations.push(declarator);
 
-        statements
+        Statement::VariableDeclaration(Box::new_in(
+            VariableDeclaration {
+                span: SPAN,
+                kind: VariableDeclarationKind::Const,
+                declarations,
+                declare: false,
+            },
+            self.allocator,
+        ))
     }
 
     /// Create template variable declarations
@@ -554,10 +788,8 @@ impl<'a> DomExpressionsCompat2<'a> {
 
         // Create array of event names
         let mut elements = OxcVec::new_in(self.allocator);
-        let mut sorted_events: Vec<_> = self.delegated_events.iter().collect();
-        sorted_events.sort();
 
-        for event in sorted_events {
+        for event in &self.delegated_events {
             let string_lit = StringLiteral {
                 span: SPAN,
                 value: Atom::from(self.allocator.alloc_str(event)),
@@ -578,7 +810,7 @@ impl<'a> DomExpressionsCompat2<'a> {
         // Create call to _$delegateEvents([...])
         let fn_name = IdentifierReference {
             span: SPAN,
-            name: Atom::from("_$delegateEvents"),
+            name: Atom::from(self.runtime_name("delegateEvents")),
             reference_id: None.into(),
         };
 
@@ -619,10 +851,15 @@ impl<'a> DomExpressionsCompat2<'a> {
     /// - Call expressions (except IIFEs, templates, components) -> _$memo(expr)
     /// - Other complex expressions (member access, etc.) -> _$memo(() => expr)
     /// - Simple expressions (identifiers, literals) -> as-is
+    /// - Expressions that await -> as-is, the await has to stay in the async scope
     pub(super) fn maybe_wrap_with_memo(&mut self, expr: Expression<'a>) -> Expression<'a> {
         use oxc_allocator::CloneIn;
         use oxc_ast::ast::*;
 
+        if contains_await_expression(&expr) {
+            return expr;
+        }
+
         match &expr {
             Expression::CallExpression(call_expr) => {
                 // Check if this is an IIFE (immediately invoked function expression)
@@ -638,10 +875,13 @@ impl<'a> DomExpressionsCompat2<'a> {
                     return expr;
                 }
 
-                // Check if this is a template or component call - those shouldn't be wrapped
-                use crate::compat::naming::is_template_var;
+                // Check if this is a template, SSR template or component call - those
+                // shouldn't be wrapped
                 if let Expression::Identifier(ident) = &call_expr.callee {
-                    if is_template_var(&ident.name) || ident.name.starts_with("_$createComponent") {
+                    if self.is_template_var(&ident.name)
+                        || ident.name == self.options.runtime_name("ssr").as_str()
+                        || ident.name == self.options.runtime_name("createComponent").as_str()
+                    {
                         return expr;
                     }
                 }
@@ -672,7 +912,7 @@ impl<'a> DomExpressionsCompat2<'a> {
                 self.add_import("memo");
                 let memo_fn = IdentifierReference {
                     span: SPAN,
-                    name: Atom::from("_$memo"),
+                    name: Atom::from(self.runtime_name("memo")),
                     reference_id: None.into(),
                 };
 
@@ -699,6 +939,13 @@ impl<'a> DomExpressionsCompat2<'a> {
                 // Return as-is
                 expr
             }
+            // Conditions only need memo when something in them can change, such as
+            // calls, member access or JSX branches
+            Expression::ConditionalExpression(_) | Expression::LogicalExpression(_)
+                if !is_dynamic_expression(&expr) =>
+            {
+                expr
+            }
             // Complex expressions (member access, etc.) -> wrap with _$memo(() => expr)
             _ => {
                 // Wrap with _$memo(() => expr)
@@ -745,7 +992,7 @@ impl<'a> DomExpressionsCompat2<'a> {
                 // Create: _$memo(() => expr)
                 let memo_fn = IdentifierReference {
                     span: SPAN,
-                    name: Atom::from("_$memo"),
+                    name: Atom::from(self.runtime_name("memo")),
                     reference_id: None.into(),
                 };
 
@@ -767,19 +1014,61 @@ impl<'a> DomExpressionsCompat2<'a> {
             }
         }
     }
+
+    /// Create the value argument of an `_$insert` call
+    ///
+    /// Dynamic expressions are passed as accessors like babel does: `count()`
+    /// becomes `count` and other dynamic expressions become `() => expr`.
+    /// Elements and components that were already transformed are inserted as-is.
+    fn create_insert_value(&self, expr: &Expression<'a>) -> Expression<'a> {
+        use oxc_allocator::CloneIn;
+
+        if !is_dynamic_expression(expr) || self.is_transformed_jsx(expr) {
+            return expr.clone_in(self.allocator);
+        }
+
+        if let Expression::CallExpression(call) = expr {
+            if call.arguments.is_empty() && matches!(call.callee, Expression::Identifier(_)) {
+                return call.callee.clone_in(self.allocator);
+            }
+        }
+
+        Expression::ArrowFunctionExpression(self.create_accessor(expr.clone_in(self.allocator)))
+    }
+
+    /// Check whether an expression is the output of transforming a JSX element
+    fn is_transformed_jsx(&self, expr: &Expression<'a>) -> bool {
+        let Expression::CallExpression(call) = expr else {
+            return false;
+        };
+        match &call.callee {
+            Expression::Identifier(ident) => {
+                self.is_template_var(&ident.name)
+                    || ident.name == self.runtime_name("createComponent")
+            }
+            // Elements with dynamic parts become IIFEs, user IIFEs stay reactive
+            _ => self.element_iifes.contains(&call.span),
+        }
+    }
+
+    /// Create `_$insert(element, expression, marker)`
+    ///
+    /// The marker argument is left out when the expression is the element's only child.
     pub(super) fn create_insert_call_with_marker(
         &self,
         element_var: &str,
         expr: &Expression<'a>,
         marker_var: Option<&str>,
+        only_child: bool,
     ) -> Option<Statement<'a>> {
-        use oxc_allocator::CloneIn;
         use oxc_ast::ast::*;
 
-        // Create call to _$insert(element, expression, marker)
+        // Create call to _$insert(element, expression, marker), mapped back to the
+        // expression's location
+        let span = expr.span();
         let insert_fn = IdentifierReference {
             span: SPAN,
-            name: Atom::from("_$insert"),
+            name: Atom::from(self.runtime_name("insert")),
             reference_id: None.into(),
         };
 
@@ -793,8 +1082,8 @@ impl<'a> DomExpressionsCompat2<'a> {
             self.allocator,
         ));
 
-        // Second argument: the expression (clone it)
-        let expr_arg = Argument::from(expr.clone_in(self.allocator));
+        // Second argument: the expression, wrapped so it is re-read reactively
+        let expr_arg = Argument::from(self.create_insert_value(expr));
 
         // Third argument: marker position (either a variable reference or null)
         let marker_arg = if let Some(marker) = marker_var {
@@ -813,10 +1102,12 @@ impl<'a> DomExpressionsCompat2<'a> {
         let mut args = OxcVec::new_in(self.allocator);
         args.push(elem_arg);
         args.push(expr_arg);
-        args.push(marker_arg);
+        if !only_child {
+            args.push(marker_arg);
+        }
 
         let call_expr = CallExpression {
-            span: SPAN,
+            span,
             callee: Expression::Identifier(Box::new_in(insert_fn, self.allocator)),
             arguments: args,
             optional: false,
@@ -826,10 +1117,20 @@ impl<'a> DomExpressionsCompat2<'a> {
 
         Some(Statement::ExpressionStatement(Box::new_in(
             ExpressionStatement {
-                span: SPAN,
+                span,
                 expression: Expression::CallExpression(Box::new_in(call_expr, self.allocator)),
             },
             self.allocator,
         )))
     }
 }
+
+/// Order in which the bindings of one element are emitted
+fn setup_priority(slot_type: &SlotType) -> u8 {
+    match slot_type {
+        SlotType::Ref | SlotType::UseDirective(_) => 0,
+        SlotType::PropAttribute(_) => 1,
+        SlotType::EventHandler(_) | SlotType::OnEvent(_) | SlotType::OnCaptureEvent(_) => 3,
+        _ => 2,
+    }
+}
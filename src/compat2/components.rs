@@ -9,9 +9,12 @@
 use oxc_allocator::Box;
 use oxc_allocator::Vec as OxcVec;
 use oxc_ast::ast::*;
-use oxc_span::SPAN;
+use oxc_span::{GetSpan, SPAN};
 
-use crate::utils::is_component;
+use crate::template::{get_attribute_name, spread_prop_attributes};
+use crate::utils::{
+    is_boolean_dom_attribute, is_component, is_dynamic_expression, is_fragment_component,
+};
 
 use super::DomExpressionsCompat2;
 
@@ -25,16 +28,30 @@ impl<'a> DomExpressionsCompat2<'a> {
         // Add the createComponent import
         self.add_import("createComponent");
 
-        // Get the component name
+        // Get the component name, keeping the tag's span for source maps
+        let component_span = jsx_elem.opening_element.name.span();
         let component_name = match &jsx_elem.opening_element.name {
             JSXElementName::Identifier(ident) => ident.name,
             JSXElementName::IdentifierReference(ident) => ident.name,
             _ => Atom::from("Unknown"),
         };
 
+        // Built-in components are imported from the module
+        let component_name = if self
+            .options
+            .built_ins
+            .iter()
+            .any(|b| b == component_name.as_str())
+        {
+            self.add_import(component_name.as_str());
+            Atom::from(self.runtime_name(component_name.as_str()))
+        } else {
+            component_name
+        };
+
         // Create the component identifier for the first argument
         let component_ident = IdentifierReference {
-            span: SPAN,
+            span: component_span,
             name: component_name,
             reference_id: None.into(),
         };
@@ -48,22 +65,19 @@ impl<'a> DomExpressionsCompat2<'a> {
             self.allocator,
         ))));
 
-        // Second argument: props object
-        let props_obj = self.create_component_props(&jsx_elem);
-        arguments.push(Argument::from(Expression::ObjectExpression(Box::new_in(
-            props_obj,
-            self.allocator,
-        ))));
+        // Second argument: props object, merged with any spread props
+        let props = self.create_component_props(&jsx_elem);
+        arguments.push(Argument::from(props));
 
         // Create the call expression: _$createComponent(Component, {...})
         let callee_ident = IdentifierReference {
             span: SPAN,
-            name: Atom::from("_$createComponent"),
+            name: Atom::from(self.runtime_name("createComponent")),
             reference_id: None.into(),
         };
 
         let call_expr = CallExpression {
-            span: SPAN,
+            span: jsx_elem.span,
             callee: Expression::Identifier(Box::new_in(callee_ident, self.allocator)),
             arguments,
             optional: false,
@@ -74,96 +88,72 @@ impl<'a> DomExpressionsCompat2<'a> {
         Expression::CallExpression(Box::new_in(call_expr, self.allocator))
     }
 
-    /// Create props object for a component
-    pub(super) fn create_component_props(
+    /// Create a merge props call: _$mergeProps(source1, source2, ...)
+    pub(super) fn create_merge_props_call(
         &mut self,
-        jsx_elem: &JSXElement<'a>,
-    ) -> ObjectExpression<'a> {
+        sources: Vec<Expression<'a>>,
+    ) -> Expression<'a> {
         use oxc_ast::ast::*;
 
+        self.add_import("mergeProps");
+
+        let callee_ident = IdentifierReference {
+            span: SPAN,
+            name: Atom::from(self.runtime_name("mergeProps")),
+            reference_id: None.into(),
+        };
+
+        let call_expr = CallExpression {
+            span: SPAN,
+            callee: Expression::Identifier(Box::new_in(callee_ident, self.allocator)),
+            arguments: OxcVec::from_iter_in(
+                sources.into_iter().map(Argument::from),
+                self.allocator,
+            ),
+            optional: false,
+            type_arguments: None,
+            pure: false,
+        };
+
+        Expression::CallExpression(Box::new_in(call_expr, self.allocator))
+    }
+
+    /// Create props for a component
+    ///
+    /// Attributes become object literal properties. Spread attributes split the
+    /// props into ordered sources that are combined with `_$mergeProps`, so
+    /// `<Comp a={1} {...b} c={2} />` produces `_$mergeProps({ a: 1 }, b, { c: 2 })`.
+    pub(super) fn create_component_props(&mut self, jsx_elem: &JSXElement<'a>) -> Expression<'a> {
+        use oxc_ast::ast::*;
+
+        let mut sources = Vec::new();
         let mut properties = OxcVec::new_in(self.allocator);
 
         // Add attributes as properties
         for attr in &jsx_elem.opening_element.attributes {
-            if let JSXAttributeItem::Attribute(jsx_attr) = attr {
-                if let JSXAttributeName::Identifier(name_ident) = &jsx_attr.name {
-                    let prop_name = name_ident.name;
-
-                    // Get the value
-                    let prop_value = if let Some(value) = &jsx_attr.value {
-                        match value {
-                            JSXAttributeValue::StringLiteral(str_lit) => {
-                                // Decode HTML entities in JSX string literal attributes for components
-                                let decoded =
-                                    crate::utils::decode_html_entities(str_lit.value.as_str());
-                                Expression::StringLiteral(Box::new_in(
-                                    StringLiteral {
-                                        span: SPAN,
-                                        value: Atom::from(self.allocator.alloc_str(&decoded)),
-                                        raw: None,
-                                        lone_surrogates: false,
-                                    },
-                                    self.allocator,
-                                ))
-                            }
-                            JSXAttributeValue::ExpressionContainer(expr_container) => {
-                                match &expr_container.expression {
-                                    jsx_expr if jsx_expr.is_expression() => {
-                                        // Clone the expression
-                                        self.clone_expression(jsx_expr.as_expression().unwrap())
-                                    }
-                                    _ => {
-                                        // For other cases, use true
-                                        Expression::BooleanLiteral(Box::new_in(
-                                            BooleanLiteral {
-                                                span: SPAN,
-                                                value: true,
-                                            },
-                                            self.allocator,
-                                        ))
-                                    }
-                                }
-                            }
-                            _ => Expression::BooleanLiteral(Box::new_in(
-                                BooleanLiteral {
-                                    span: SPAN,
-                                    value: true,
-                                },
-                                self.allocator,
-                            )),
-                        }
-                    } else {
-                        Expression::BooleanLiteral(Box::new_in(
-                            BooleanLiteral {
-                                span: SPAN,
-                                value: true,
-                            },
-                            self.allocator,
-                        ))
-                    };
-
-                    // Create property
-                    let prop_key = PropertyKey::StaticIdentifier(Box::new_in(
-                        IdentifierName {
-                            span: SPAN,
-                            name: prop_name,
-                        },
-                        self.allocator,
-                    ));
-
-                    properties.push(ObjectPropertyKind::ObjectProperty(Box::new_in(
-                        ObjectProperty {
-                            span: SPAN,
-                            kind: PropertyKind::Init,
-                            key: prop_key,
-                            value: prop_value,
-                            method: false,
-                            shorthand: false,
-                            computed: false,
-                        },
-                        self.allocator,
-                    )));
+            if let JSXAttributeItem::SpreadAttribute(spread) = attr {
+                // Close the current object group and add the spread source after it
+                if !properties.is_empty() {
+                    let group = std::mem::replace(&mut properties, OxcVec::new_in(self.allocator));
+                    sources.push(self.create_props_object(group));
+                }
+                sources.push(self.clone_expression(&spread.argument));
+            } else if let JSXAttributeItem::Attribute(jsx_attr) = attr {
+                // Namespaced names such as `on:custom` are passed through as-is
+                let prop_key = self.create_prop_key(&jsx_attr.name);
+
+                // Attributes without a value are `true`
+                let prop_value = self
+                    .create_prop_value(jsx_attr.value.as_ref())
+                    .unwrap_or_else(|| self.create_boolean_literal(true));
+
+                // Dynamic values are read lazily to stay reactive
+                if is_dynamic_expression(&prop_value) {
+                    properties.push(self.create_getter_property(prop_key, prop_value));
+                    continue;
                 }
+
+                properties.push(self.create_init_property(prop_key, prop_value));
             }
         }
 
@@ -207,61 +197,7 @@ impl<'a> DomExpressionsCompat2<'a> {
 
             if needs_getter {
                 // Create getter: get children() { return [...]; }
-                // Create function body with return statement
-                let return_stmt = Statement::ReturnStatement(Box::new_in(
-                    ReturnStatement {
-                        span: SPAN,
-                        argument: Some(children_value),
-                    },
-                    self.allocator,
-                ));
-
-                let func_body = FunctionBody {
-                    span: SPAN,
-                    directives: OxcVec::new_in(self.allocator),
-                    statements: OxcVec::from_iter_in([return_stmt], self.allocator),
-                };
-
-                let getter_fn = Function {
-                    r#type: FunctionType::FunctionExpression,
-                    span: SPAN,
-                    id: None,
-                    generator: false,
-                    r#async: false,
-                    declare: false,
-                    type_parameters: None,
-                    this_param: None,
-                    params: Box::new_in(
-                        FormalParameters {
-                            span: SPAN,
-                            kind: FormalParameterKind::FormalParameter,
-                            items: OxcVec::new_in(self.allocator),
-                            rest: None,
-                        },
-                        self.allocator,
-                    ),
-                    body: Some(Box::new_in(func_body, self.allocator)),
-                    return_type: None,
-                    scope_id: Default::default(),
-                    pure: false,
-                    pife: false,
-                };
-
-                let getter_value =
-                    Expression::FunctionExpression(Box::new_in(getter_fn, self.allocator));
-
-                properties.push(ObjectPropertyKind::ObjectProperty(Box::new_in(
-                    ObjectProperty {
-                        span: SPAN,
-                        kind: PropertyKind::Get,
-                        key: prop_key,
-                        value: getter_value,
-                        method: false,
-                        shorthand: false,
-                        computed: false,
-                    },
-                    self.allocator,
-                )));
+                properties.push(self.create_getter_property(prop_key, children_value));
             } else {
                 // Regular property
                 properties.push(ObjectPropertyKind::ObjectProperty(Box::new_in(
@@ -279,12 +215,242 @@ impl<'a> DomExpressionsCompat2<'a> {
             }
         }
 
-        ObjectExpression {
-            span: SPAN,
-            properties,
+        if !properties.is_empty() || sources.is_empty() {
+            sources.push(self.create_props_object(properties));
+        }
+
+        if sources.len() == 1 {
+            sources.pop().unwrap()
+        } else {
+            self.create_merge_props_call(sources)
         }
     }
 
+    /// Create the props a host element passes to `_$spread`
+    ///
+    /// Like component props, spreads and the attributes merged with them become
+    /// ordered sources, so `<div {...a} title="x" />` produces
+    /// `_$mergeProps(a, { title: "x" })`.
+    pub(super) fn create_element_spread_props(
+        &mut self,
+        attributes: &[JSXAttributeItem<'a>],
+    ) -> Expression<'a> {
+        use oxc_ast::ast::*;
+
+        let mut sources = Vec::new();
+        let mut properties = OxcVec::new_in(self.allocator);
+
+        for attr in spread_prop_attributes(attributes) {
+            match attr {
+                JSXAttributeItem::SpreadAttribute(spread) => {
+                    if !properties.is_empty() {
+                        let group =
+                            std::mem::replace(&mut properties, OxcVec::new_in(self.allocator));
+                        sources.push(self.create_props_object(group));
+                    }
+                    sources.push(self.clone_expression(&spread.argument));
+                }
+                JSXAttributeItem::Attribute(jsx_attr) => {
+                    let prop_key = self.create_prop_key(&jsx_attr.name);
+
+                    // Attributes without a value set boolean properties, other names
+                    // get an empty attribute
+                    let prop_value = self
+                        .create_prop_value(jsx_attr.value.as_ref())
+                        .unwrap_or_else(|| match get_attribute_name(&jsx_attr.name) {
+                            Some(name) if is_boolean_dom_attribute(&name) => {
+                                self.create_boolean_literal(true)
+                            }
+                            _ => self.create_string_literal(""),
+                        });
+
+                    if is_dynamic_expression(&prop_value) {
+                        properties.push(self.create_getter_property(prop_key, prop_value));
+                    } else {
+                        properties.push(self.create_init_property(prop_key, prop_value));
+                    }
+                }
+            }
+        }
+
+        if !properties.is_empty() {
+            sources.push(self.create_props_object(properties));
+        }
+
+        if sources.len() == 1 {
+            sources.pop().unwrap()
+        } else {
+            self.create_merge_props_call(sources)
+        }
+    }
+
+    /// Create the value of a prop from a JSX attribute value
+    ///
+    /// Returns `None` for attributes without a value or with an empty `{}`.
+    fn create_prop_value(&self, value: Option<&JSXAttributeValue<'a>>) -> Option<Expression<'a>> {
+        match value? {
+            JSXAttributeValue::StringLiteral(str_lit) => {
+                // Decode HTML entities in JSX string literal attributes
+                let decoded = crate::utils::decode_html_entities(str_lit.value.as_str());
+                Some(self.create_string_literal(&decoded))
+            }
+            JSXAttributeValue::ExpressionContainer(expr_container) => expr_container
+                .expression
+                .as_expression()
+                .map(|expr| self.clone_expression(expr)),
+            _ => None,
+        }
+    }
+
+    fn create_string_literal(&self, value: &str) -> Expression<'a> {
+        Expression::StringLiteral(Box::new_in(
+            StringLiteral {
+                span: SPAN,
+                value: Atom::from(self.allocator.alloc_str(value)),
+                raw: None,
+                lone_surrogates: false,
+            },
+            self.allocator,
+        ))
+    }
+
+    fn create_boolean_literal(&self, value: bool) -> Expression<'a> {
+        Expression::BooleanLiteral(Box::new_in(
+            BooleanLiteral { span: SPAN, value },
+            self.allocator,
+        ))
+    }
+
+    /// Create the object key for a prop
+    ///
+    /// Names that aren't JavaScript identifiers, like `data-id` or `on:custom`,
+    /// are quoted.
+    fn create_prop_key(&self, name: &JSXAttributeName<'a>) -> PropertyKey<'a> {
+        match name {
+            JSXAttributeName::Identifier(ident) if !ident.name.contains('-') => {
+                PropertyKey::StaticIdentifier(Box::new_in(
+                    IdentifierName {
+                        span: SPAN,
+                        name: ident.name,
+                    },
+                    self.allocator,
+                ))
+            }
+            _ => {
+                let key = match name {
+                    JSXAttributeName::Identifier(ident) => ident.name.to_string(),
+                    JSXAttributeName::NamespacedName(namespaced) => {
+                        format!("{}:{}", namespaced.namespace.name, namespaced.name.name)
+                    }
+                };
+                PropertyKey::StringLiteral(Box::new_in(
+                    StringLiteral {
+                        span: SPAN,
+                        value: Atom::from(self.allocator.alloc_str(&key)),
+                        raw: None,
+                        lone_surrogates: false,
+                    },
+                    self.allocator,
+                ))
+            }
+        }
+    }
+
+    /// Create a plain property: `key: value`
+    fn create_init_property(
+        &self,
+        key: PropertyKey<'a>,
+        value: Expression<'a>,
+    ) -> ObjectPropertyKind<'a> {
+        ObjectPropertyKind::ObjectProperty(Box::new_in(
+            ObjectProperty {
+                span: SPAN,
+                kind: PropertyKind::Init,
+                key,
+                value,
+                method: false,
+                shorthand: false,
+                computed: false,
+            },
+            self.allocator,
+        ))
+    }
+
+    /// Create a getter property: `get key() { return value; }`
+    fn create_getter_property(
+        &self,
+        key: PropertyKey<'a>,
+        value: Expression<'a>,
+    ) -> ObjectPropertyKind<'a> {
+        use oxc_ast::ast::*;
+
+        let return_stmt = Statement::ReturnStatement(Box::new_in(
+            ReturnStatement {
+                span: SPAN,
+                argument: Some(value),
+            },
+            self.allocator,
+        ));
+
+        let func_body = FunctionBody {
+            span: SPAN,
+            directives: OxcVec::new_in(self.allocator),
+            statements: OxcVec::from_iter_in([return_stmt], self.allocator),
+        };
+
+        let getter_fn = Function {
+            r#type: FunctionType::FunctionExpression,
+            span: SPAN,
+            id: None,
+            generator: false,
+            r#async: false,
+            declare: false,
+            type_parameters: None,
+            this_param: None,
+            params: Box::new_in(
+                FormalParameters {
+                    span: SPAN,
+                    kind: FormalParameterKind::FormalParameter,
+                    items: OxcVec::new_in(self.allocator),
+                    rest: None,
+                },
+                self.allocator,
+            ),
+            body: Some(Box::new_in(func_body, self.allocator)),
+            return_type: None,
+            scope_id: Default::default(),
+            pure: false,
+            pife: false,
+        };
+
+        ObjectPropertyKind::ObjectProperty(Box::new_in(
+            ObjectProperty {
+                span: SPAN,
+                kind: PropertyKind::Get,
+                key,
+                value: Expression::FunctionExpression(Box::new_in(getter_fn, self.allocator)),
+                method: false,
+                shorthand: false,
+                computed: false,
+            },
+            self.allocator,
+        ))
+    }
+
+    /// Create an object literal from a group of props
+    fn create_props_object(
+        &self,
+        properties: OxcVec<'a, ObjectPropertyKind<'a>>,
+    ) -> Expression<'a> {
+        Expression::ObjectExpression(Box::new_in(
+            ObjectExpression {
+                span: SPAN,
+                properties,
+            },
+            self.allocator,
+        ))
+    }
+
     /// Create children value for a component (can be a single value or array)
     pub(super) fn create_component_children(
         &mut self,
@@ -375,15 +541,19 @@ impl<'a> DomExpressionsCompat2<'a> {
                     _ => "",
                 };
 
-                if is_component(tag_name) {
+                if is_fragment_component(tag_name) {
+                    // <Fragment> behaves like <>...</>
+                    self.transform_fragment_children(&elem.children)
+                } else if is_component(tag_name) {
                     // Transform component - clone and box the element
                     let elem_clone = elem.as_ref().clone_in(self.allocator);
                     let boxed_elem = Box::new_in(elem_clone, self.allocator);
                     self.transform_component(boxed_elem)
+                } else if self.options.generate == crate::options::GenerateMode::Ssr {
+                    self.transform_element_ssr(elem)
                 } else {
                     // Build template and transform element
-                    let template =
-                        crate::template::build_template_with_options(elem, Some(&self.options));
+                    let template = self.build_template(elem);
                     let template_var = self.get_template_var(&template.html);
 
                     let has_dynamic_content = !template.dynamic_slots.is_empty();
@@ -391,15 +561,16 @@ impl<'a> DomExpressionsCompat2<'a> {
                     if has_dynamic_content {
                         // Extract expressions from the element
                         let mut expressions = Vec::new();
-                        self.extract_expressions_from_jsx(elem, &mut expressions);
+                        self.extract_expressions_from_jsx(elem, &template, &mut expressions);
 
                         // Generate IIFE with dynamic binding code
                         let iife = self.create_template_iife_from_expressions(
                             expressions,
                             &template,
                             &template_var,
+                            elem.span,
                         );
-                        Expression::CallExpression(iife)
+                        iife
                     } else {
                         // Simple template call for static content
                         let template_var_str = self.allocator.alloc_str(&template_var);
@@ -414,10 +585,7 @@ impl<'a> DomExpressionsCompat2<'a> {
                 let boxed_frag = Box::new_in(frag_clone, self.allocator);
                 self.transform_fragment(boxed_frag)
             }
-            _ => {
-                // For other types (Spread), return null for now
-                Expression::NullLiteral(Box::new_in(NullLiteral { span: SPAN }, self.allocator))
-            }
+            JSXChild::Spread(spread) => self.clone_expression(&spread.expression),
         }
     }
 
@@ -425,13 +593,20 @@ impl<'a> DomExpressionsCompat2<'a> {
     pub(super) fn transform_fragment(
         &mut self,
         jsx_frag: Box<'a, JSXFragment<'a>>,
+    ) -> Expression<'a> {
+        self.transform_fragment_children(&jsx_frag.children)
+    }
+
+    /// Transform fragment children (from `<>...</>` or `<Fragment>`) into an array or string
+    pub(super) fn transform_fragment_children(
+        &mut self,
+        children: &OxcVec<'a, JSXChild<'a>>,
     ) -> Expression<'a> {
         use oxc_ast::ast::*;
 
         // Filter out whitespace-only text nodes that contain newlines
         // Keep single spaces or whitespace without newlines
-        let significant_children: Vec<_> = jsx_frag
-            .children
+        let significant_children: Vec<_> = children
             .iter()
             .filter(|child| match child {
                 JSXChild::Text(text) => {
@@ -66,13 +66,14 @@ impl<'a> DomExpressionsCompat2<'a> {
         )))
     }
 
-    /// Create an addEventListener call
+    /// Create an addEventListener call, with `{ passive: true }` when `passive`
     pub(super) fn create_add_event_listener(
         &self,
         element_var: &str,
         event_name: &str,
         handler_expr: &Expression<'a>,
         _is_capture: bool,
+        passive: bool,
     ) -> Option<Statement<'a>> {
         use oxc_allocator::CloneIn;
         use oxc_ast::ast::*;
@@ -108,6 +109,41 @@ impl<'a> DomExpressionsCompat2<'a> {
 
         args.push(Argument::from(handler_expr.clone_in(self.allocator)));
 
+        if passive {
+            let mut properties = OxcVec::new_in(self.allocator);
+            properties.push(ObjectPropertyKind::ObjectProperty(Box::new_in(
+                ObjectProperty {
+                    span: SPAN,
+                    kind: PropertyKind::Init,
+                    key: PropertyKey::StaticIdentifier(Box::new_in(
+                        IdentifierName {
+                            span: SPAN,
+                            name: Atom::from("passive"),
+                        },
+                        self.allocator,
+                    )),
+                    value: Expression::BooleanLiteral(Box::new_in(
+                        BooleanLiteral {
+                            span: SPAN,
+                            value: true,
+                        },
+                        self.allocator,
+                    )),
+                    method: false,
+                    shorthand: false,
+                    computed: false,
+                },
+                self.allocator,
+            )));
+            args.push(Argument::ObjectExpression(Box::new_in(
+                ObjectExpression {
+                    span: SPAN,
+                    properties,
+                },
+                self.allocator,
+            )));
+        }
+
         let call = CallExpression {
             span: SPAN,
             callee: Expression::from(MemberExpression::StaticMemberExpression(Box::new_in(
@@ -201,6 +237,113 @@ impl<'a> DomExpressionsCompat2<'a> {
         )))
     }
 
+    /// Create element.addEventListener(name, listener, options) for an `on:`
+    /// value written as `{ handleEvent, once, passive, capture }`
+    ///
+    /// The object is passed as written. When it sets listener options, it is
+    /// stored once and passed as the options too, so `handleEvent` keeps its
+    /// `this` and the option keys are read from the same object:
+    ///
+    /// ```js
+    /// var _listener$ = { handleEvent: onScroll, passive: true };
+    /// _el$.addEventListener("scroll", _listener$, _listener$);
+    /// ```
+    ///
+    /// Returns `None` when the value isn't such an object.
+    pub(super) fn create_event_listener_with_options(
+        &mut self,
+        element_var: &str,
+        event_name: &str,
+        handler_expr: &Expression<'a>,
+    ) -> Option<Vec<Statement<'a>>> {
+        use oxc_allocator::CloneIn;
+        use oxc_ast::ast::*;
+
+        let Expression::ObjectExpression(object) = handler_expr.without_parentheses() else {
+            return None;
+        };
+        let static_key = |property: &ObjectPropertyKind<'a>| match property {
+            ObjectPropertyKind::ObjectProperty(prop) if !prop.computed => {
+                prop.key.static_name().map(|name| name.into_owned())
+            }
+            _ => None,
+        };
+        // Spreads could supply or override any key, so leave those to the runtime
+        if object
+            .properties
+            .iter()
+            .any(|property| matches!(property, ObjectPropertyKind::SpreadProperty(_)))
+            || !object
+                .properties
+                .iter()
+                .any(|property| static_key(property).as_deref() == Some("handleEvent"))
+        {
+            return None;
+        }
+        let has_options = object.properties.iter().any(|property| {
+            matches!(
+                static_key(property).as_deref(),
+                Some("once" | "passive" | "capture" | "signal")
+            )
+        });
+
+        let mut statements = Vec::new();
+        let mut args = OxcVec::new_in(self.allocator);
+
+        // First argument: event name as written, like other on: events
+        args.push(Argument::StringLiteral(Box::new_in(
+            StringLiteral {
+                span: SPAN,
+                value: Atom::from(self.allocator.alloc_str(event_name)),
+                raw: None,
+                lone_surrogates: false,
+            },
+            self.allocator,
+        )));
+
+        // Listener object, also passed as the options when it sets any
+        if has_options {
+            let listener_var = self.generate_listener_var();
+            statements.push(
+                self.variable_declaration(&listener_var, handler_expr.clone_in(self.allocator)),
+            );
+            args.push(Argument::from(self.identifier_expression(&listener_var)));
+            args.push(Argument::from(self.identifier_expression(&listener_var)));
+        } else {
+            args.push(Argument::from(handler_expr.clone_in(self.allocator)));
+        }
+
+        let member_expr = StaticMemberExpression {
+            span: SPAN,
+            object: self.identifier_expression(element_var),
+            property: IdentifierName {
+                span: SPAN,
+                name: Atom::from("addEventListener"),
+            },
+            optional: false,
+        };
+
+        let call = CallExpression {
+            span: SPAN,
+            callee: Expression::from(MemberExpression::StaticMemberExpression(Box::new_in(
+                member_expr,
+                self.allocator,
+            ))),
+            arguments: args,
+            optional: false,
+            type_arguments: None,
+            pure: false,
+        };
+        statements.push(
+            self.expression_statement(Expression::CallExpression(Box::new_in(
+                call,
+                self.allocator,
+            ))),
+        );
+
+        Some(statements)
+    }
+
     /// Create element.$$eventNameData = data;
     #[allow(dead_code)]
     pub(super) fn create_delegated_event_data(
@@ -370,7 +513,7 @@ impl<'a> DomExpressionsCompat2<'a> {
         // Create: _$addEventListener(element, "eventName", handler, true_if_delegated);
         let helper_fn = IdentifierReference {
             span: SPAN,
-            name: Atom::from("_$addEventListener"),
+            name: Atom::from(self.runtime_name("addEventListener")),
             reference_id: None.into(),
         };
 
@@ -536,6 +679,7 @@ impl<'a> DomExpressionsCompat2<'a> {
             event_name,
             &Expression::ArrowFunctionExpression(Box::new_in(arrow_fn, self.allocator)),
             false,
+            false,
         )
     }
 }
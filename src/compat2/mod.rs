@@ -65,6 +65,7 @@
 //! - **attributes.rs** - Attribute transformations (style, class, etc.)
 //! - **templates.rs** - Template and IIFE generation
 //! - **components.rs** - Component and fragment transformations  
+//! - **ssr.rs** - Server-side rendering (`_$ssr`) output
 //! - **codegen.rs** - AST-based code generation helpers (imports, declarations, etc.)
 //! - **traverse_impl.rs** - Traverse trait implementation
 //!
@@ -77,18 +78,21 @@
 //! 5. **Output**: Emit optimized JavaScript with template literals and runtime library calls
 
 use oxc_allocator::Allocator;
+use oxc_span::Span;
 use std::collections::{HashMap, HashSet};
 
+use crate::diagnostics::Diagnostic;
 #[cfg(feature = "opt")]
 use crate::opt::{TemplateOptimizer, TemplateStats};
 use crate::options::DomExpressionsOptions;
-use crate::template::Template;
+use crate::template::{JsxComments, Template};
 
 // Sub-modules containing impl blocks for DomExpressions
 mod attributes;
 mod codegen;
 mod components;
 mod events;
+mod ssr;
 mod templates;
 mod traverse_impl;
 
@@ -100,6 +104,8 @@ pub struct DomExpressionsCompat2<'a> {
     pub(super) templates: Vec<Template>,
     /// Map of template HTML to variable name for deduplication
     pub(super) template_map: HashMap<String, String>,
+    /// Templates created while deduplication is disabled, as (HTML, variable name)
+    pub(super) undeduplicated_templates: Vec<(String, String)>,
     /// Counter for generating unique template variable names
     pub(super) template_counter: usize,
     /// Counter for generating unique element variable names
@@ -108,8 +114,26 @@ pub struct DomExpressionsCompat2<'a> {
     pub(super) first_root_generated: bool,
     /// List of required imports (preserves insertion order)
     pub(super) required_imports: Vec<String>,
-    /// Set of events that need delegation
-    pub(super) delegated_events: HashSet<String>,
+    /// Events that need delegation (preserves first-encounter order)
+    pub(super) delegated_events: Vec<String>,
+    /// Whether the program being transformed is an ES module
+    pub(super) is_module: bool,
+    /// Names bound or referenced in the program, avoided by minified names
+    pub(super) reserved_names: HashSet<String>,
+    /// Counter for generating minified variable names
+    pub(super) minified_counter: usize,
+    /// Counter for generating unique `_ref$` variable names
+    pub(super) ref_counter: usize,
+    /// Counter for generating unique `_listener$` variable names
+    pub(super) listener_counter: usize,
+    /// Names whose every binding in the program is `const`
+    pub(super) const_bindings: HashSet<String>,
+    /// Comments kept in templates when `preserve_comments` is set
+    pub(super) jsx_comments: JsxComments,
+    /// Problems found in constructs that could not be fully transformed
+    pub(super) diagnostics: Vec<Diagnostic>,
+    /// Spans of the IIFEs generated for elements with dynamic parts
+    pub(super) element_iifes: HashSet<Span>,
     /// Optimizer for template analysis
     #[cfg(feature = "opt")]
     pub(super) optimizer: TemplateOptimizer,
@@ -123,11 +147,21 @@ impl<'a> DomExpressionsCompat2<'a> {
             options,
             templates: Vec::new(),
             template_map: HashMap::new(),
+            undeduplicated_templates: Vec::new(),
             template_counter: 0,
             element_counter: 0, // Start at 0
             first_root_generated: false,
             required_imports: Vec::new(),
-            delegated_events: HashSet::new(),
+            delegated_events: Vec::new(),
+            is_module: true,
+            reserved_names: HashSet::new(),
+            minified_counter: 0,
+            ref_counter: 0,
+            listener_counter: 0,
+            const_bindings: HashSet::new(),
+            jsx_comments: Vec::new(),
+            diagnostics: Vec::new(),
+            element_iifes: HashSet::new(),
             #[cfg(feature = "opt")]
             optimizer: TemplateOptimizer::new(),
         }
@@ -138,6 +172,26 @@ impl<'a> DomExpressionsCompat2<'a> {
         &self.options
     }
 
+    /// Get the templates collected during transformation
+    pub fn templates(&self) -> &[Template] {
+        &self.templates
+    }
+
+    /// Get the events registered with `delegateEvents`, in emission order
+    pub fn delegated_events(&self) -> Vec<String> {
+        self.delegated_events.clone()
+    }
+
+    /// Get the diagnostics recorded for unsupported constructs, in the order found
+    pub fn get_diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    /// Record a warning for a construct that was dropped or simplified
+    pub(super) fn warn(&mut self, span: Span, message: &str) {
+        self.diagnostics.push(Diagnostic::warning(span, message));
+    }
+
     /// Get template statistics for optimization analysis
     #[cfg(feature = "opt")]
     pub fn get_template_stats(&self) -> TemplateStats {
@@ -154,11 +208,35 @@ impl<'a> DomExpressionsCompat2<'a> {
     pub(super) fn generate_template_var(&mut self) -> String {
         use crate::compat::template_var_name;
         self.template_counter += 1;
+        if self.options.minify_names {
+            return self.generate_minified_var();
+        }
         template_var_name(self.template_counter)
     }
 
+    /// Check whether a name refers to one of the generated template variables
+    pub(super) fn is_template_var(&self, name: &str) -> bool {
+        self.all_templates().any(|(_, var)| var == name)
+    }
+
+    /// All templates to declare, as (HTML, variable name)
+    pub(super) fn all_templates(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.template_map.iter().chain(
+            self.undeduplicated_templates
+                .iter()
+                .map(|(html, var)| (html, var)),
+        )
+    }
+
     /// Get or create a template variable for given HTML
     pub(super) fn get_template_var(&mut self, html: &str) -> String {
+        if !self.options.template_dedup {
+            let var = self.generate_template_var();
+            self.undeduplicated_templates
+                .push((html.to_string(), var.clone()));
+            return var;
+        }
+
         if let Some(var) = self.template_map.get(html) {
             var.clone()
         } else {
@@ -175,23 +253,36 @@ impl<'a> DomExpressionsCompat2<'a> {
         }
     }
 
+    /// Get the local name of a runtime function (e.g. `_$insert`)
+    pub(super) fn runtime_name(&self, name: &str) -> &'a str {
+        self.allocator.alloc_str(&self.options.runtime_name(name))
+    }
+
     /// Add an event that needs delegation
     pub(super) fn add_delegated_event(&mut self, event: &str) {
         // Events should be normalized to lowercase for delegation
         let lowercase_event = event.to_lowercase();
-        self.delegated_events.insert(lowercase_event);
+        if !self.delegated_events.contains(&lowercase_event) {
+            self.delegated_events.push(lowercase_event);
+        }
     }
 
     /// Generate a unique element variable name
     pub(super) fn generate_element_var(&mut self) -> String {
         use crate::compat::element_var_name;
         self.element_counter += 1;
+        if self.options.minify_names {
+            return self.generate_minified_var();
+        }
         element_var_name(self.element_counter)
     }
 
     /// Generate root element variable name
     /// First root in file is "_el$", subsequent are numbered
     pub(super) fn generate_root_element_var(&mut self) -> String {
+        if self.options.minify_names {
+            return self.generate_minified_var();
+        }
         if !self.first_root_generated {
             self.first_root_generated = true;
             "_el$".to_string()
@@ -199,4 +290,84 @@ impl<'a> DomExpressionsCompat2<'a> {
             self.generate_element_var()
         }
     }
+
+    /// Generate a unique variable name for a ref target (`_ref$`, `_ref$2`, ...)
+    pub(super) fn generate_ref_var(&mut self) -> String {
+        if self.options.minify_names {
+            return self.generate_minified_var();
+        }
+        self.ref_counter += 1;
+        if self.ref_counter == 1 {
+            "_ref$".to_string()
+        } else {
+            format!("_ref${}", self.ref_counter)
+        }
+    }
+
+    /// Generate a unique variable name for an `on:` listener object
+    /// (`_listener$`, `_listener$2`, ...)
+    pub(super) fn generate_listener_var(&mut self) -> String {
+        if self.options.minify_names {
+            return self.generate_minified_var();
+        }
+        self.listener_counter += 1;
+        if self.listener_counter == 1 {
+            "_listener$".to_string()
+        } else {
+            format!("_listener${}", self.listener_counter)
+        }
+    }
+
+    /// Generate the next short variable name (`a`, `b`, ..., `aa`, ...)
+    /// that is not used anywhere in the program
+    fn generate_minified_var(&mut self) -> String {
+        loop {
+            let name = short_var_name(self.minified_counter);
+            self.minified_counter += 1;
+            if !self.reserved_names.contains(&name) && !is_short_reserved_word(&name) {
+                return name;
+            }
+        }
+    }
+}
+
+/// Convert an index to a short identifier: 0 -> `a`, 51 -> `Z`, 52 -> `aa`
+fn short_var_name(mut index: usize) -> String {
+    const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+    let mut name = Vec::new();
+    loop {
+        name.push(ALPHABET[index % ALPHABET.len()]);
+        index /= ALPHABET.len();
+        if index == 0 {
+            break;
+        }
+        index -= 1;
+    }
+    name.reverse();
+    String::from_utf8(name).expect("alphabet is ASCII")
+}
+
+/// Keywords and globals short enough to be produced by `short_var_name`
+fn is_short_reserved_word(name: &str) -> bool {
+    matches!(
+        name,
+        "do" | "if" | "in" | "for" | "let" | "new" | "try" | "var" | "NaN"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_var_name() {
+        assert_eq!(short_var_name(0), "a");
+        assert_eq!(short_var_name(25), "z");
+        assert_eq!(short_var_name(26), "A");
+        assert_eq!(short_var_name(51), "Z");
+        assert_eq!(short_var_name(52), "aa");
+        assert_eq!(short_var_name(53), "ab");
+        assert_eq!(short_var_name(52 + 52 * 52), "aaa");
+    }
 }
@@ -0,0 +1,434 @@
+//! SSR code generation
+//!
+//! In SSR mode elements are rendered to strings instead of DOM nodes. The
+//! template is split at every dynamic position and the values are passed to
+//! `_$ssr`, which interleaves them with the static parts:
+//!
+//! ```js
+//! var _tmpl$ = ["<div", "><span>", "</span></div>"];
+//! _$ssr(_tmpl$, _$ssrAttribute("id", _$escape(id(), true), false), _$escape(name()));
+//! ```
+//!
+//! - Text children are escaped with `_$escape(value)`
+//! - `innerHTML` is written as the element's content as-is, `textContent` and
+//!   `innerText` escaped with `_$escape(value)`
+//! - Attributes are rendered with `_$ssrAttribute(name, _$escape(value, true), false)`
+//! - `class` and `style` values are written inside the quoted attribute
+//!   (`_$escape(value, true)`, `_$ssrStyle(value)` and `_$ssrClassList(value)`)
+//! - Client-only bindings (events, refs, `use:`, `prop:`) produce no output
+//!
+//! Spread attributes and `class:`/`style:` bindings are not rendered yet; each
+//! one is reported in [`DomExpressionsCompat2::get_diagnostics`].
+
+use oxc_allocator::{Box, CloneIn, Vec as OxcVec};
+use oxc_ast::ast::*;
+use oxc_span::SPAN;
+
+#[cfg(feature = "opt")]
+use crate::opt::evaluator::{evaluate_expression, EvaluatedValue};
+use crate::template::{
+    escape_attribute_value, escape_html, get_attribute_name, get_element_name,
+    normalize_text_whitespace, static_child_text, static_content_value,
+};
+#[cfg(feature = "opt")]
+use crate::utils::is_enumerated_attribute;
+use crate::utils::{
+    decode_html_entities, get_prefixed_name, get_svg_attribute_name, is_attr_attribute,
+    is_bool_attribute, is_boolean_dom_attribute, is_class_list_binding, is_class_name_binding,
+    is_component, is_content_attribute, is_event_handler, is_fragment_component,
+    is_nullish_jsx_expression, is_on_capture_event, is_on_prefix_event, is_prop_attribute,
+    is_ref_binding, is_style_binding, is_style_property, is_svg_element, is_use_directive,
+    is_void_element, normalize_style_string,
+};
+
+use super::DomExpressionsCompat2;
+
+/// Separator between the static parts of an SSR template in `template_map` keys
+pub(super) const SSR_TEMPLATE_HOLE: char = '\0';
+
+/// A template split at its dynamic positions
+///
+/// There is always one more part than there are values.
+struct SsrTemplate<'a> {
+    parts: Vec<String>,
+    values: Vec<Expression<'a>>,
+}
+
+impl<'a> SsrTemplate<'a> {
+    fn new() -> Self {
+        Self {
+            parts: vec![String::new()],
+            values: Vec::new(),
+        }
+    }
+
+    /// Append static HTML to the current part
+    fn push_str(&mut self, html: &str) {
+        if let Some(last) = self.parts.last_mut() {
+            last.push_str(html);
+        }
+    }
+
+    /// Insert a dynamic value, starting a new static part
+    fn push_value(&mut self, value: Expression<'a>) {
+        self.values.push(value);
+        self.parts.push(String::new());
+    }
+}
+
+impl<'a> DomExpressionsCompat2<'a> {
+    /// Transform a host element into an `_$ssr(_tmpl$, ...values)` call
+    pub(super) fn transform_element_ssr(&mut self, jsx_elem: &JSXElement<'a>) -> Expression<'a> {
+        let mut template = SsrTemplate::new();
+        self.build_ssr_element(jsx_elem, &mut template);
+
+        let key = template.parts.join(&SSR_TEMPLATE_HOLE.to_string());
+        let template_var = self.get_template_var(&key);
+
+        let mut args = OxcVec::new_in(self.allocator);
+        args.push(Argument::Identifier(Box::new_in(
+            IdentifierReference {
+                span: SPAN,
+                name: Atom::from(self.allocator.alloc_str(&template_var)),
+                reference_id: None.into(),
+            },
+            self.allocator,
+        )));
+        for value in template.values {
+            args.push(Argument::from(value));
+        }
+
+        self.create_ssr_runtime_call("ssr", args)
+    }
+
+    /// Render an element (and its host-element descendants) into the template
+    fn build_ssr_element(&mut self, jsx_elem: &JSXElement<'a>, template: &mut SsrTemplate<'a>) {
+        let tag_name = get_element_name(&jsx_elem.opening_element);
+        template.push_str(&format!("<{}", tag_name));
+
+        let mut content_attr = None;
+        for attr in &jsx_elem.opening_element.attributes {
+            match attr {
+                // Content attributes render between the tags, not as attributes
+                JSXAttributeItem::Attribute(attr)
+                    if get_attribute_name(&attr.name)
+                        .is_some_and(|name| is_content_attribute(&name)) =>
+                {
+                    content_attr = Some(attr);
+                }
+                JSXAttributeItem::Attribute(attr) => {
+                    self.build_ssr_attribute(attr, is_svg_element(&tag_name), template);
+                }
+                JSXAttributeItem::SpreadAttribute(spread) => {
+                    self.warn(
+                        spread.span,
+                        "spread attributes are not rendered in SSR mode",
+                    );
+                }
+            }
+        }
+
+        template.push_str(">");
+
+        if is_void_element(&tag_name) {
+            return;
+        }
+
+        if let Some(attr) = content_attr {
+            self.build_ssr_content(attr, template);
+        }
+
+        for child in &jsx_elem.children {
+            self.build_ssr_child(child, template);
+        }
+
+        template.push_str(&format!("</{}>", tag_name));
+    }
+
+    /// Render a single attribute into the template
+    fn build_ssr_attribute(
+        &mut self,
+        attr: &JSXAttribute<'a>,
+        is_svg: bool,
+        template: &mut SsrTemplate<'a>,
+    ) {
+        let Some(name) = get_attribute_name(&attr.name) else {
+            return;
+        };
+        let name = if is_svg {
+            get_svg_attribute_name(&name)
+        } else {
+            name
+        };
+
+        // Client-only bindings have no server representation
+        if is_ref_binding(&name)
+            || is_on_prefix_event(&name)
+            || is_on_capture_event(&name)
+            || is_use_directive(&name)
+            || is_prop_attribute(&name)
+            || is_event_handler(&name)
+        {
+            return;
+        }
+        if is_style_property(&name) || is_class_name_binding(&name) {
+            self.warn(
+                attr.span,
+                "`class:` and `style:` bindings are not rendered in SSR mode",
+            );
+            return;
+        }
+
+        let is_bool = is_bool_attribute(&name) || is_boolean_dom_attribute(&name);
+        let name = if is_bool || is_attr_attribute(&name) {
+            get_prefixed_name(&name).unwrap_or(&name).to_string()
+        } else {
+            name
+        };
+
+        let expr = match &attr.value {
+            None => {
+                template.push_str(&format!(" {}", name));
+                return;
+            }
+            Some(JSXAttributeValue::StringLiteral(lit)) => {
+                let value = decode_html_entities(&lit.value);
+                let value = if is_style_binding(&name) {
+                    normalize_style_string(&value)
+                } else {
+                    value
+                };
+                template.push_str(&format!(" {}=\"{}\"", name, escape_attribute_value(&value)));
+                return;
+            }
+            Some(JSXAttributeValue::ExpressionContainer(container)) => {
+                match container.expression.as_expression() {
+                    Some(expr) => expr,
+                    None => return,
+                }
+            }
+            Some(_) => return,
+        };
+
+        if is_class_list_binding(&name) {
+            self.add_import("ssrClassList");
+            template.push_str(" class=\"");
+            let value = self.create_ssr_runtime_call(
+                "ssrClassList",
+                OxcVec::from_iter_in(
+                    [Argument::from(expr.clone_in(self.allocator))],
+                    self.allocator,
+                ),
+            );
+            template.push_value(value);
+            template.push_str("\"");
+            return;
+        }
+
+        #[cfg(feature = "opt")]
+        {
+            let eval_result = evaluate_expression(expr);
+            if eval_result.confident && is_style_binding(&name) {
+                if let Some(EvaluatedValue::String(css)) = &eval_result.value {
+                    let css = normalize_style_string(css);
+                    template.push_str(&format!(" style=\"{}\"", escape_attribute_value(&css)));
+                    return;
+                }
+            } else if eval_result.confident {
+                match &eval_result.value {
+                    Some(EvaluatedValue::Null) | Some(EvaluatedValue::Undefined) => return,
+                    Some(EvaluatedValue::Boolean(b)) if is_enumerated_attribute(&name) => {
+                        template.push_str(&format!(" {}=\"{}\"", name, b));
+                        return;
+                    }
+                    Some(EvaluatedValue::Boolean(false)) => return,
+                    Some(EvaluatedValue::Boolean(true)) => {
+                        template.push_str(&format!(" {}", name));
+                        return;
+                    }
+                    Some(value @ EvaluatedValue::String(_))
+                    | Some(value @ EvaluatedValue::Number(_)) => {
+                        let value = escape_attribute_value(&value.to_string());
+                        template.push_str(&format!(" {}=\"{}\"", name, value));
+                        return;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        if is_style_binding(&name) {
+            // style="..." with the serialized style object or string
+            self.add_import("ssrStyle");
+            template.push_str(" style=\"");
+            let value = self.create_ssr_runtime_call(
+                "ssrStyle",
+                OxcVec::from_iter_in(
+                    [Argument::from(expr.clone_in(self.allocator))],
+                    self.allocator,
+                ),
+            );
+            template.push_value(value);
+            template.push_str("\"");
+        } else if name == "class" {
+            // class="..." with the escaped value
+            template.push_str(" class=\"");
+            let value = self.create_ssr_escape(expr.clone_in(self.allocator), true);
+            template.push_value(value);
+            template.push_str("\"");
+        } else {
+            // _$ssrAttribute renders the leading space, or nothing when the value is nullish
+            self.add_import("ssrAttribute");
+            let value_arg = if is_bool {
+                expr.clone_in(self.allocator)
+            } else {
+                self.create_ssr_escape(expr.clone_in(self.allocator), true)
+            };
+            let mut args = OxcVec::new_in(self.allocator);
+            args.push(Argument::StringLiteral(Box::new_in(
+                StringLiteral {
+                    span: SPAN,
+                    value: Atom::from(self.allocator.alloc_str(&name)),
+                    raw: None,
+                    lone_surrogates: false,
+                },
+                self.allocator,
+            )));
+            args.push(Argument::from(value_arg));
+            args.push(Argument::BooleanLiteral(Box::new_in(
+                BooleanLiteral {
+                    span: SPAN,
+                    value: is_bool,
+                },
+                self.allocator,
+            )));
+            let value = self.create_ssr_runtime_call("ssrAttribute", args);
+            template.push_value(value);
+        }
+    }
+
+    /// Render an `innerHTML`, `textContent` or `innerText` attribute as the element's content
+    ///
+    /// Known values are written into the template; `innerHTML` is raw markup,
+    /// the text properties are escaped.
+    fn build_ssr_content(&mut self, attr: &JSXAttribute<'a>, template: &mut SsrTemplate<'a>) {
+        let is_inner_html =
+            matches!(&attr.name, JSXAttributeName::Identifier(ident) if ident.name == "innerHTML");
+
+        if let Some(content) = static_content_value(attr) {
+            if is_inner_html {
+                template.push_str(&content);
+            } else {
+                template.push_str(&escape_html(&content));
+            }
+            return;
+        }
+
+        let Some(JSXAttributeValue::ExpressionContainer(container)) = &attr.value else {
+            return;
+        };
+        let Some(expr) = container.expression.as_expression() else {
+            return;
+        };
+
+        let value = expr.clone_in(self.allocator);
+        if is_inner_html {
+            template.push_value(value);
+        } else {
+            let value = self.create_ssr_escape(value, false);
+            template.push_value(value);
+        }
+    }
+
+    /// Render a child node into the template
+    fn build_ssr_child(&mut self, child: &JSXChild<'a>, template: &mut SsrTemplate<'a>) {
+        match child {
+            JSXChild::Text(text) => {
+                let normalized = normalize_text_whitespace(text.value.as_str());
+                template.push_str(&escape_html(&decode_html_entities(&normalized)));
+            }
+            JSXChild::ExpressionContainer(container) => match &container.expression {
+                JSXExpression::EmptyExpression(_) => {}
+                jsx_expr if is_nullish_jsx_expression(jsx_expr) => {}
+                jsx_expr => {
+                    if let Some(text) = static_child_text(jsx_expr) {
+                        template.push_str(&escape_html(&text));
+                    } else if let Some(expr) = jsx_expr.as_expression() {
+                        let value = self.create_ssr_escape(expr.clone_in(self.allocator), false);
+                        template.push_value(value);
+                    }
+                }
+            },
+            JSXChild::Element(elem) => {
+                let tag_name = get_element_name(&elem.opening_element);
+                if is_fragment_component(&tag_name) {
+                    let value = self.transform_fragment_children(&elem.children);
+                    let value = self.create_ssr_escape(value, false);
+                    template.push_value(value);
+                } else if is_component(&tag_name) {
+                    let elem_clone =
+                        Box::new_in(elem.as_ref().clone_in(self.allocator), self.allocator);
+                    let value = self.transform_component(elem_clone);
+                    let value = self.create_ssr_escape(value, false);
+                    template.push_value(value);
+                } else {
+                    self.build_ssr_element(elem, template);
+                }
+            }
+            JSXChild::Fragment(frag) => {
+                let value = self.transform_fragment_children(&frag.children);
+                let value = self.create_ssr_escape(value, false);
+                template.push_value(value);
+            }
+            JSXChild::Spread(spread) => {
+                let value =
+                    self.create_ssr_escape(spread.expression.clone_in(self.allocator), false);
+                template.push_value(value);
+            }
+        }
+    }
+
+    /// Create `_$escape(value)` or `_$escape(value, true)` for attribute values
+    fn create_ssr_escape(&mut self, value: Expression<'a>, is_attribute: bool) -> Expression<'a> {
+        self.add_import("escape");
+
+        let mut args = OxcVec::new_in(self.allocator);
+        args.push(Argument::from(value));
+        if is_attribute {
+            args.push(Argument::BooleanLiteral(Box::new_in(
+                BooleanLiteral {
+                    span: SPAN,
+                    value: true,
+                },
+                self.allocator,
+            )));
+        }
+
+        self.create_ssr_runtime_call("escape", args)
+    }
+
+    /// Create a call to an SSR runtime helper: `_$name(...args)`
+    fn create_ssr_runtime_call(
+        &self,
+        name: &str,
+        arguments: OxcVec<'a, Argument<'a>>,
+    ) -> Expression<'a> {
+        let callee = IdentifierReference {
+            span: SPAN,
+            name: Atom::from(self.runtime_name(name)),
+            reference_id: None.into(),
+        };
+
+        Expression::CallExpression(Box::new_in(
+            CallExpression {
+                span: SPAN,
+                callee: Expression::Identifier(Box::new_in(callee, self.allocator)),
+                arguments,
+                optional: false,
+                type_arguments: None,
+                pure: false,
+            },
+            self.allocator,
+        ))
+    }
+}
@@ -8,14 +8,31 @@
 use oxc_allocator::Box;
 use oxc_allocator::Vec as OxcVec;
 use oxc_ast::ast::*;
-use oxc_span::SPAN;
+use oxc_span::{Span, SPAN};
 
+use crate::options::VarKind;
 use crate::template::{SlotType, Template};
+use crate::utils::{contains_await_expression, escape_template_literal, is_svg_element};
 
+use super::ssr::SSR_TEMPLATE_HOLE;
 use super::DomExpressionsCompat2;
 
 impl<'a> DomExpressionsCompat2<'a> {
+    /// Build the template for a host element with the transformer's options
+    pub(super) fn build_template(&self, element: &JSXElement<'a>) -> Template {
+        crate::template::build_template_with_comments(
+            element,
+            Some(&self.options),
+            Some(&self.jsx_comments),
+        )
+    }
+
     /// Create a template call expression
+    ///
+    /// - DOM: `_tmpl$()` clones the template
+    /// - SSR: `_$ssr(_tmpl$)` renders the template string
+    /// - Hydratable: `_$getNextElement(_tmpl$)` claims the server-rendered node,
+    ///   falling back to cloning the template when there is nothing to hydrate
     pub(super) fn create_template_call(
         &self,
         template_var: &'a str,
@@ -23,18 +40,24 @@ impl<'a> DomExpressionsCompat2<'a> {
         use crate::options::GenerateMode;
         use oxc_ast::ast::*;
 
-        let is_ssr = self.options.generate == GenerateMode::Ssr;
+        let template_ident = IdentifierReference {
+            span: SPAN,
+            name: oxc_span::Atom::from(template_var),
+            reference_id: None.into(),
+        };
 
-        if is_ssr {
-            let ssr_fn = IdentifierReference {
-                span: SPAN,
-                name: oxc_span::Atom::from("_$ssr"),
-                reference_id: None.into(),
-            };
+        let wrapper_fn = if self.options.generate == GenerateMode::Ssr {
+            Some(self.runtime_name("ssr"))
+        } else if self.options.is_hydratable() {
+            Some(self.runtime_name("getNextElement"))
+        } else {
+            None
+        };
 
-            let template_ident = IdentifierReference {
+        let call_expr = if let Some(wrapper_fn) = wrapper_fn {
+            let wrapper_ident = IdentifierReference {
                 span: SPAN,
-                name: oxc_span::Atom::from(template_var),
+                name: oxc_span::Atom::from(wrapper_fn),
                 reference_id: None.into(),
             };
 
@@ -44,46 +67,46 @@ impl<'a> DomExpressionsCompat2<'a> {
                 self.allocator,
             )));
 
-            let call_expr = CallExpression {
+            CallExpression {
                 span: SPAN,
                 arguments: args,
-                callee: Expression::Identifier(Box::new_in(ssr_fn, self.allocator)),
+                callee: Expression::Identifier(Box::new_in(wrapper_ident, self.allocator)),
                 optional: false,
                 type_arguments: None,
                 pure: false,
-            };
-
-            Box::new_in(call_expr, self.allocator)
+            }
         } else {
-            let callee_ident = IdentifierReference {
-                span: SPAN,
-                name: oxc_span::Atom::from(template_var),
-                reference_id: None.into(),
-            };
-            let callee = Expression::Identifier(Box::new_in(callee_ident, self.allocator));
-
-            let call_expr = CallExpression {
+            CallExpression {
                 span: SPAN,
                 arguments: OxcVec::new_in(self.allocator),
-                callee,
+                callee: Expression::Identifier(Box::new_in(template_ident, self.allocator)),
                 optional: false,
                 type_arguments: None,
                 pure: false,
-            };
+            }
+        };
 
-            Box::new_in(call_expr, self.allocator)
-        }
+        Box::new_in(call_expr, self.allocator)
     }
 
     /// Create an IIFE that clones template and applies dynamic bindings
+    ///
+    /// When a binding awaits, the IIFE is made async and awaited so the
+    /// `await` stays valid and the element is still created in place.
+    ///
+    /// The call takes the element's span and is recorded in `element_iifes`,
+    /// so it can be told apart from IIFEs written by the user.
     pub(super) fn create_template_iife_from_expressions(
         &mut self,
         expressions: Vec<Expression<'a>>,
         template: &Template,
         template_var: &str,
-    ) -> Box<'a, CallExpression<'a>> {
+        span: Span,
+    ) -> Expression<'a> {
         use oxc_ast::ast::*;
 
+        let is_async = expressions.iter().any(contains_await_expression);
+
         let mut body_stmts = OxcVec::new_in(self.allocator);
 
         let (root_var, elem_decls, path_to_var) =
@@ -110,7 +133,7 @@ impl<'a> DomExpressionsCompat2<'a> {
         let arrow_fn = ArrowFunctionExpression {
             span: SPAN,
             expression: false,
-            r#async: false,
+            r#async: is_async,
             params: Box::new_in(
                 FormalParameters {
                     span: SPAN,
@@ -128,16 +151,28 @@ impl<'a> DomExpressionsCompat2<'a> {
             pife: false,
         };
 
+        self.element_iifes.insert(span);
         let call_expr = CallExpression {
-            span: SPAN,
+            span,
             callee: Expression::ArrowFunctionExpression(Box::new_in(arrow_fn, self.allocator)),
             arguments: OxcVec::new_in(self.allocator),
             optional: false,
             type_arguments: None,
             pure: false,
         };
+        let call_expr = Expression::CallExpression(Box::new_in(call_expr, self.allocator));
 
-        Box::new_in(call_expr, self.allocator)
+        if is_async {
+            Expression::AwaitExpression(Box::new_in(
+                AwaitExpression {
+                    span: SPAN,
+                    argument: call_expr,
+                },
+                self.allocator,
+            ))
+        } else {
+            call_expr
+        }
     }
 
     /// Create element reference declarations
@@ -165,11 +200,12 @@ impl<'a> DomExpressionsCompat2<'a> {
         let mut all_paths = std::collections::HashSet::new();
 
         // Check if we have any TextContent slots - if so, always create firstChild reference
-        // This matches babel plugin behavior for consistency
+        // This matches babel plugin behavior for consistency. An only child is inserted
+        // into an empty element, so there is no firstChild to reference.
         let has_text_content = template
             .dynamic_slots
             .iter()
-            .any(|slot| matches!(slot.slot_type, SlotType::TextContent));
+            .any(|slot| matches!(slot.slot_type, SlotType::TextContent) && !slot.only_child);
 
         if has_text_content {
             // Always create firstChild reference for text content templates
@@ -194,9 +230,10 @@ impl<'a> DomExpressionsCompat2<'a> {
             }
         }
 
-        // Sort paths by length to ensure we create parent references before children
+        // Sort paths by length to ensure we create parent references before children,
+        // then in document order ("firstChild" sorts before "nextSibling")
         let mut sorted_paths: Vec<_> = all_paths.into_iter().collect();
-        sorted_paths.sort_by_key(|path| path.len());
+        sorted_paths.sort_by(|a, b| (a.len(), a).cmp(&(b.len(), b)));
 
         // Generate element references for each path
         for path in sorted_paths {
@@ -223,7 +260,7 @@ impl<'a> DomExpressionsCompat2<'a> {
 
         let var_decl = VariableDeclaration {
             span: SPAN,
-            kind: VariableDeclarationKind::Var,
+            kind: self.template_decl_kind(),
             declarations: declarators,
             declare: false,
         };
@@ -260,7 +297,7 @@ impl<'a> DomExpressionsCompat2<'a> {
 
         VariableDeclarator {
             span: SPAN,
-            kind: VariableDeclarationKind::Var,
+            kind: self.template_decl_kind(),
             id: root_id,
             init: Some(Expression::CallExpression(template_call)),
             definite: false,
@@ -316,27 +353,33 @@ impl<'a> DomExpressionsCompat2<'a> {
 
         VariableDeclarator {
             span: SPAN,
-            kind: VariableDeclarationKind::Var,
+            kind: self.template_decl_kind(),
             id: elem_id,
             init: Some(expr),
             definite: false,
         }
     }
 
+    /// The declaration keyword for templates and element references
+    pub(super) fn template_decl_kind(&self) -> VariableDeclarationKind {
+        match self.options.template_decl_kind {
+            VarKind::Var => VariableDeclarationKind::Var,
+            VarKind::Const => VariableDeclarationKind::Const,
+        }
+    }
+
     /// Generate unique element variable name
     pub(super) fn create_template_declarations(&self) -> Option<Statement<'a>> {
         use crate::options::GenerateMode;
         use oxc_ast::ast::*;
 
-        if self.template_map.is_empty() {
-            return None;
-        }
+        self.all_templates().next()?;
 
         // Create variable declarators for all templates
         let mut declarators = OxcVec::new_in(self.allocator);
 
         // Sort template map by variable name to get consistent order (numerically)
-        let mut sorted_templates: Vec<_> = self.template_map.iter().collect();
+        let mut sorted_templates: Vec<_> = self.all_templates().collect();
         sorted_templates.sort_by(|a, b| {
             // Extract the numeric part from variable names using compat naming module
             use crate::compat::naming::extract_template_counter;
@@ -362,24 +405,55 @@ impl<'a> DomExpressionsCompat2<'a> {
             };
 
             // For SSR mode, just assign string literals
-            // For DOM mode, wrap in _$template() call
+            // For DOM and hydratable modes, wrap in _$template() call
+            // (hydration keys are attached at runtime by _$getNextElement)
             let init_expr = if is_ssr {
-                // SSR: just a string literal
-                let string_lit = StringLiteral {
-                    span: SPAN,
-                    value: Atom::from(self.allocator.alloc_str(html)),
-                    raw: None,
-                    lone_surrogates: false,
-                };
-                Expression::StringLiteral(Box::new_in(string_lit, self.allocator))
+                // SSR: a string literal, or an array of strings split at dynamic positions
+                let mut parts = html.split(SSR_TEMPLATE_HOLE).map(|part| {
+                    Box::new_in(
+                        StringLiteral {
+                            span: SPAN,
+                            value: Atom::from(self.allocator.alloc_str(part)),
+                            raw: None,
+                            lone_surrogates: false,
+                        },
+                        self.allocator,
+                    )
+                });
+
+                if html.contains(SSR_TEMPLATE_HOLE) {
+                    let elements = OxcVec::from_iter_in(
+                        parts.map(ArrayExpressionElement::StringLiteral),
+                        self.allocator,
+                    );
+                    Expression::ArrayExpression(Box::new_in(
+                        ArrayExpression {
+                            span: SPAN,
+                            elements,
+                        },
+                        self.allocator,
+                    ))
+                } else {
+                    Expression::StringLiteral(parts.next().unwrap())
+                }
             } else {
+                // Other SVG roots are parsed inside an <svg> wrapper that the runtime
+                // strips again when `isSVG` is set; an <svg> root needs neither
+                let root_tag = template_root_tag(html);
+                let is_svg = root_tag != "svg" && is_svg_element(root_tag);
+                let html = if is_svg {
+                    format!("<svg>{}</svg>", html)
+                } else {
+                    html.clone()
+                };
+
                 // DOM: call _$template with template literal
                 let template_element = TemplateElement {
                     span: SPAN,
                     tail: true,
                     value: TemplateElementValue {
-                        raw: Atom::from(self.allocator.alloc_str(html)),
-                        cooked: Some(Atom::from(self.allocator.alloc_str(html))),
+                        raw: Atom::from(self.allocator.alloc_str(&escape_template_literal(&html))),
+                        cooked: Some(Atom::from(self.allocator.alloc_str(&html))),
                     },
                     lone_surrogates: false,
                 };
@@ -396,7 +470,7 @@ impl<'a> DomExpressionsCompat2<'a> {
                 // Create call to _$template(...)
                 let template_fn = IdentifierReference {
                     span: SPAN,
-                    name: Atom::from("_$template"),
+                    name: Atom::from(self.runtime_name("template")),
                     reference_id: None.into(),
                 };
 
@@ -406,6 +480,16 @@ impl<'a> DomExpressionsCompat2<'a> {
                     self.allocator,
                 )));
 
+                // template(html, isImportNode, isSVG, isMathML)
+                if is_svg {
+                    for value in [false, true, false] {
+                        args.push(Argument::BooleanLiteral(Box::new_in(
+                            BooleanLiteral { span: SPAN, value },
+                            self.allocator,
+                        )));
+                    }
+                }
+
                 let call_expr = CallExpression {
                     span: SPAN,
                     callee: Expression::Identifier(Box::new_in(template_fn, self.allocator)),
@@ -421,7 +505,7 @@ impl<'a> DomExpressionsCompat2<'a> {
             // Create variable declarator
             let declarator = VariableDeclarator {
                 span: SPAN,
-                kind: VariableDeclarationKind::Var,
+                kind: self.template_decl_kind(),
                 id,
                 init: Some(init_expr),
                 definite: false,
@@ -433,7 +517,7 @@ impl<'a> DomExpressionsCompat2<'a> {
         // Create variable declaration
         let var_decl = VariableDeclaration {
             span: SPAN,
-            kind: VariableDeclarationKind::Var,
+            kind: self.template_decl_kind(),
             declarations: declarators,
             declare: false,
         };
@@ -444,3 +528,12 @@ impl<'a> DomExpressionsCompat2<'a> {
         )))
     }
 }
+
+/// Get the tag name of the root element of a template's HTML
+fn template_root_tag(html: &str) -> &str {
+    let tag = html.strip_prefix('<').unwrap_or_default();
+    let end = tag
+        .find(|c: char| !c.is_ascii_alphanumeric() && c != '-')
+        .unwrap_or(tag.len());
+    &tag[..end]
+}
@@ -5,27 +5,73 @@
 
 use oxc_allocator::{Box, Vec as OxcVec};
 use oxc_ast::ast::*;
-use oxc_span::SPAN;
+use oxc_span::{GetSpan, SPAN};
 use oxc_traverse::{Traverse, TraverseCtx};
+use std::collections::HashSet;
 
+use crate::diagnostics::Diagnostic;
+use crate::options::GenerateMode;
 use crate::template::SlotType;
-use crate::utils::{is_component, should_delegate_event};
+use crate::utils::{is_component, is_fragment_component, is_passive_event, should_delegate_event};
 
 use super::DomExpressionsCompat2;
 
 impl<'a> Traverse<'a, ()> for DomExpressionsCompat2<'a> {
-    fn enter_program(&mut self, _program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a, ()>) {
-        use crate::options::GenerateMode;
-
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a, ()>) {
         // Entry point for the transformation
         // Initialize state for collecting templates and imports
         self.templates.clear();
         self.template_map.clear();
+        self.undeduplicated_templates.clear();
         self.template_counter = 0;
         self.element_counter = 0; // Reset to 0
         self.first_root_generated = false;
         self.required_imports.clear();
         self.delegated_events.clear();
+        self.is_module = program.source_type.is_module();
+        self.minified_counter = 0;
+        self.ref_counter = 0;
+        self.listener_counter = 0;
+        self.reserved_names.clear();
+        self.const_bindings.clear();
+        self.jsx_comments.clear();
+        self.diagnostics.clear();
+
+        // Refs bound to a const can only be callbacks, never assignment targets
+        let scoping = ctx.scoping();
+        let mut reassignable = HashSet::new();
+        for symbol_id in scoping.symbol_ids() {
+            let name = scoping.symbol_name(symbol_id);
+            if scoping.symbol_flags(symbol_id).is_const_variable() {
+                self.const_bindings.insert(name.to_string());
+            } else {
+                reassignable.insert(name);
+            }
+        }
+        self.const_bindings
+            .retain(|name| !reassignable.contains(name.as_str()));
+
+        // Comment text for `{/* ... */}` children kept in templates
+        if self.options.preserve_comments {
+            self.jsx_comments
+                .extend(program.comments.iter().map(|comment| {
+                    let text = comment.content_span().source_text(program.source_text);
+                    (comment.span, text.trim().to_string())
+                }));
+        }
+
+        // Minified names must not shadow or collide with any name in the program
+        if self.options.minify_names {
+            let scoping = ctx.scoping();
+            self.reserved_names
+                .extend(scoping.symbol_names().map(str::to_string));
+            self.reserved_names.extend(
+                scoping
+                    .root_unresolved_references()
+                    .keys()
+                    .map(|name| name.to_string()),
+            );
+        }
 
         // Add the template import (will be needed for any JSX)
         // Use "ssr" for SSR mode, "template" for DOM mode
@@ -35,10 +81,22 @@ impl<'a> Traverse<'a, ()> for DomExpressionsCompat2<'a> {
             "template"
         };
         self.add_import(import_name);
+
+        // Hydratable output claims server-rendered nodes instead of cloning
+        if self.options.is_hydratable() {
+            self.add_import("getNextElement");
+        }
     }
 
     fn exit_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a, ()>) {
         // Exit point for the transformation
+        // The template imports were added first to keep their position; without
+        // any templates (no host elements, or no JSX at all) they're unused
+        if self.all_templates().next().is_none() {
+            self.required_imports
+                .retain(|name| !matches!(name.as_str(), "template" | "ssr" | "getNextElement"));
+        }
+
         // Add delegate events import if needed
         if self.options.delegate_events && !self.delegated_events.is_empty() {
             self.add_import("delegateEvents");
@@ -54,7 +112,7 @@ impl<'a> Traverse<'a, ()> for DomExpressionsCompat2<'a> {
         }
 
         // 2. Add template declarations
-        if !self.template_map.is_empty() {
+        if self.all_templates().next().is_some() {
             if let Some(template_decl) = self.create_template_declarations() {
                 new_stmts.push(template_decl);
             }
@@ -87,9 +145,39 @@ impl<'a> Traverse<'a, ()> for DomExpressionsCompat2<'a> {
         let tag_name = match &elem.opening_element.name {
             JSXElementName::Identifier(ident) => ident.name.as_str(),
             JSXElementName::IdentifierReference(ident) => ident.name.as_str(),
-            _ => return, // Skip complex element names for now
+            name => {
+                let message = "member expression element names are not supported";
+                self.diagnostics
+                    .push(Diagnostic::error(name.span(), message));
+                return;
+            }
         };
 
+        // Server rendering handles these children; templates do not yet
+        if !is_component(tag_name) && self.options.generate != GenerateMode::Ssr {
+            for child in &elem.children {
+                match child {
+                    JSXChild::Fragment(fragment) => self.warn(
+                        fragment.span,
+                        "fragments inside host elements are not supported and are dropped",
+                    ),
+                    JSXChild::Element(child) => {
+                        let name = &child.opening_element.name;
+                        if let JSXElementName::IdentifierReference(ident) = name {
+                            if is_component(&ident.name) {
+                                self.warn(
+                                    child.span,
+                                    "components inside host elements are not supported \
+                                     and are written into the template as tags",
+                                );
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
         // Components are handled differently
         if is_component(tag_name) {
             // Component handling - track that we need component imports
@@ -99,7 +187,7 @@ impl<'a> Traverse<'a, ()> for DomExpressionsCompat2<'a> {
 
         // Handle JSX elements
         // Build a template from the JSX element
-        let template = crate::template::build_template_with_options(elem, Some(&self.options));
+        let template = self.build_template(elem);
 
         // Record template for optimization analysis (if opt feature is enabled)
         #[cfg(feature = "opt")]
@@ -107,7 +195,10 @@ impl<'a> Traverse<'a, ()> for DomExpressionsCompat2<'a> {
 
         // Get effect wrapper name before borrowing self mutably
         let _effect_wrapper = self.options.effect_wrapper.clone(); // TODO: Use when implementing full dynamic binding
-        let delegate_events = self.options.delegate_events;
+
+        // Server output has no event listeners to delegate
+        let delegate_events =
+            self.options.delegate_events && self.options.generate != GenerateMode::Ssr;
 
         // Track which imports are needed based on dynamic slots
         // NOTE: Currently we only generate simple template calls without dynamic binding code,
@@ -124,7 +215,8 @@ impl<'a> Traverse<'a, ()> for DomExpressionsCompat2<'a> {
                     // self.add_import(&effect_wrapper);
                 }
                 SlotType::EventHandler(event_name) => {
-                    if delegate_events && should_delegate_event(event_name) {
+                    let passive = self.options.passive_events && is_passive_event(event_name);
+                    if delegate_events && should_delegate_event(event_name) && !passive {
                         self.add_delegated_event(event_name);
                     }
                 }
@@ -142,13 +234,14 @@ impl<'a> Traverse<'a, ()> for DomExpressionsCompat2<'a> {
                 SlotType::OnEvent(_) | SlotType::OnCaptureEvent(_) => {
                     // These use direct addEventListener, no imports needed
                 }
-                SlotType::BoolAttribute(_)
+                SlotType::AttributeNS { .. }
+                | SlotType::BoolAttribute(_)
                 | SlotType::PropAttribute(_)
                 | SlotType::AttrAttribute(_)
                 | SlotType::UseDirective(_)
                 | SlotType::StyleProperty(_)
                 | SlotType::ClassName(_)
-                | SlotType::Spread => {
+                | SlotType::Spread { .. } => {
                     // These slot types don't need special import handling here
                 }
             }
@@ -254,6 +347,12 @@ impl<'a> Traverse<'a, ()> for DomExpressionsCompat2<'a> {
                 }
             };
 
+            if is_fragment_component(tag_name) {
+                // <Fragment> behaves like <>...</>
+                *expr = self.transform_fragment_children(&jsx_elem.children);
+                return;
+            }
+
             if is_component(tag_name) {
                 // Transform component
                 let component_call = self.transform_component(jsx_elem);
@@ -261,9 +360,14 @@ impl<'a> Traverse<'a, ()> for DomExpressionsCompat2<'a> {
                 return;
             }
 
+            // SSR renders elements to strings instead of DOM nodes
+            if self.options.generate == GenerateMode::Ssr {
+                *expr = self.transform_element_ssr(&jsx_elem);
+                return;
+            }
+
             // Build template and get the template variable
-            let template =
-                crate::template::build_template_with_options(&jsx_elem, Some(&self.options));
+            let template = self.build_template(&jsx_elem);
             let template_var = self.get_template_var(&template.html);
 
             // Check if this template has dynamic content
@@ -272,15 +376,16 @@ impl<'a> Traverse<'a, ()> for DomExpressionsCompat2<'a> {
             if has_dynamic_content {
                 // Extract expressions before we lose the JSX element
                 let mut expressions = Vec::new();
-                self.extract_expressions_from_jsx(&jsx_elem, &mut expressions);
+                self.extract_expressions_from_jsx(&jsx_elem, &template, &mut expressions);
 
                 // Generate an IIFE with dynamic binding code
                 let iife = self.create_template_iife_from_expressions(
                     expressions,
                     &template,
                     &template_var,
+                    jsx_elem.span,
                 );
-                *expr = Expression::CallExpression(iife);
+                *expr = iife;
             } else {
                 // Simple template call for static content
                 let template_var_str = self.allocator.alloc_str(&template_var);
@@ -0,0 +1,47 @@
+//! Diagnostics for JSX the transformer cannot represent
+//!
+//! Some constructs are not supported yet and are dropped or rendered in a
+//! simplified form. The transformer records a [`Diagnostic`] for each one so
+//! callers can surface it instead of shipping silently wrong output.
+
+use oxc_span::Span;
+
+/// How serious a diagnostic is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// The output is generated but does not fully represent the source
+    Warning,
+    /// The construct could not be transformed at all
+    Error,
+}
+
+/// A problem found while transforming, located in the source
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Source span of the offending construct
+    pub span: Span,
+    /// Human readable description
+    pub message: String,
+    /// How serious the problem is
+    pub severity: Severity,
+}
+
+impl Diagnostic {
+    /// Create a warning for a construct that was dropped or simplified
+    pub fn warning(span: Span, message: impl Into<String>) -> Self {
+        Self {
+            span,
+            message: message.into(),
+            severity: Severity::Warning,
+        }
+    }
+
+    /// Create an error for a construct that was left untransformed
+    pub fn error(span: Span, message: impl Into<String>) -> Self {
+        Self {
+            span,
+            message: message.into(),
+            severity: Severity::Error,
+        }
+    }
+}
@@ -2,8 +2,6 @@
 //!
 //! This parser is specifically designed to parse the well-formed HTML generated
 //! by template.rs. It does NOT handle:
-//! - Comments
-//! - CDATA sections
 //! - Doctype declarations
 //! - Malformed HTML
 //! - HTML entities (other than what's in the source)
@@ -13,6 +11,7 @@
 //! - Text nodes
 //! - Proper nesting and closing tag matching
 //! - Quoted and unquoted attribute values
+//! - Comments and CDATA sections, which are kept verbatim
 
 /// HTML node types in our AST
 #[derive(Debug, Clone, PartialEq)]
@@ -26,6 +25,10 @@ pub enum HtmlNode {
     Text(String),
     /// Marker node for dynamic content (<!>)
     Marker,
+    /// Comment node (`<!-- ... -->`), holding the text between the delimiters
+    Comment(String),
+    /// CDATA section (`<![CDATA[ ... ]]>`), holding the raw section content
+    Cdata(String),
 }
 
 /// Parse HTML into an AST
@@ -33,7 +36,7 @@ pub enum HtmlNode {
 /// This parser expects well-formed HTML as generated by template.rs:
 /// - All tags are properly closed (except void elements)
 /// - Attributes are properly quoted or unquoted
-/// - No HTML comments or special constructs
+/// - Comments and CDATA sections are closed (an unterminated one runs to the end)
 pub fn parse(html: &str) -> Vec<HtmlNode> {
     let mut chars = html.chars().peekable();
     let mut nodes = Vec::new();
@@ -54,13 +57,19 @@ fn parse_node(chars: &mut std::iter::Peekable<std::str::Chars>) -> Option<HtmlNo
     if chars.peek() == Some(&'<') {
         chars.next(); // consume '<'
 
-        // Check if it's a marker node <!>
+        // Check if it's a marker node <!>, a comment or a CDATA section
         if chars.peek() == Some(&'!') {
             chars.next(); // consume '!'
             if chars.peek() == Some(&'>') {
                 chars.next(); // consume '>'
                 return Some(HtmlNode::Marker);
             }
+            if consume_prefix(chars, "--") {
+                return Some(HtmlNode::Comment(read_until(chars, "-->")));
+            }
+            if consume_prefix(chars, "[CDATA[") {
+                return Some(HtmlNode::Cdata(read_until(chars, "]]>")));
+            }
             // If not <!>, this is unexpected, treat as text
             return Some(HtmlNode::Text("<!".to_string()));
         }
@@ -267,6 +276,33 @@ fn parse_node(chars: &mut std::iter::Peekable<std::str::Chars>) -> Option<HtmlNo
     }
 }
 
+/// Consume `prefix` if the input starts with it, leaving the input untouched otherwise
+fn consume_prefix(chars: &mut std::iter::Peekable<std::str::Chars>, prefix: &str) -> bool {
+    let mut lookahead = chars.clone();
+    for expected in prefix.chars() {
+        if lookahead.next() != Some(expected) {
+            return false;
+        }
+    }
+    *chars = lookahead;
+    true
+}
+
+/// Read up to and including `terminator`, returning the text before it
+///
+/// If the terminator never appears, the rest of the input is returned.
+fn read_until(chars: &mut std::iter::Peekable<std::str::Chars>, terminator: &str) -> String {
+    let mut text = String::new();
+    for ch in chars.by_ref() {
+        text.push(ch);
+        if text.ends_with(terminator) {
+            text.truncate(text.len() - terminator.len());
+            break;
+        }
+    }
+    text
+}
+
 /// Check if a tag is a void element (self-closing in HTML)
 fn is_void_tag(tag: &str) -> bool {
     matches!(
@@ -439,4 +475,54 @@ mod tests {
             panic!("Expected element node");
         }
     }
+
+    #[test]
+    fn test_parse_comment() {
+        let nodes = parse("<div><!-- a <b> c --><span></span></div>");
+
+        assert_eq!(nodes.len(), 1);
+        if let HtmlNode::Element { children, .. } = &nodes[0] {
+            assert_eq!(children.len(), 2);
+            assert_eq!(children[0], HtmlNode::Comment(" a <b> c ".to_string()));
+            assert!(matches!(&children[1], HtmlNode::Element { tag, .. } if tag == "span"));
+        } else {
+            panic!("Expected element node");
+        }
+    }
+
+    #[test]
+    fn test_parse_cdata() {
+        let nodes = parse("<svg><![CDATA[x < y && </svg>]]><g></g></svg>");
+
+        assert_eq!(nodes.len(), 1);
+        if let HtmlNode::Element { children, .. } = &nodes[0] {
+            assert_eq!(children.len(), 2);
+            assert_eq!(children[0], HtmlNode::Cdata("x < y && </svg>".to_string()));
+            assert!(matches!(&children[1], HtmlNode::Element { tag, .. } if tag == "g"));
+        } else {
+            panic!("Expected element node");
+        }
+    }
+
+    #[test]
+    fn test_parse_marker_next_to_comment() {
+        let nodes = parse("<!><!---->text");
+        assert_eq!(
+            nodes,
+            vec![
+                HtmlNode::Marker,
+                HtmlNode::Comment(String::new()),
+                HtmlNode::Text("text".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_unterminated_comment() {
+        let nodes = parse("<!-- never closed <div>");
+        assert_eq!(
+            nodes,
+            vec![HtmlNode::Comment(" never closed <div>".to_string())]
+        );
+    }
 }
@@ -85,6 +85,8 @@
 //! ### Configuration
 //!
 //! - [`options`]: Configuration options (re-exported as [`DomExpressionsOptions`])
+//! - [`api`]: One-call [`transform()`] returning a [`TransformOutput`]
+//! - `wasm` (feature `wasm`): `transform_sync` taking options as JSON, for WASM/N-API wrappers
 //!
 //! ### Compatibility
 //!
@@ -103,8 +105,10 @@
 //!
 //! - [`html_subset_parser`]: HTML parsing for template generation
 
+pub mod api;
 pub mod compat;
 pub mod compat2;
+mod diagnostics;
 pub mod html_subset_parser;
 #[cfg(feature = "opt")]
 pub mod opt;
@@ -112,12 +116,19 @@ mod options;
 pub mod template;
 mod transform;
 pub mod utils;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 #[cfg(feature = "opt")]
 pub use opt::{Optimization, OptimizationKind, TemplateOptimizer, TemplateStats};
-pub use options::{DomExpressionsOptions, GenerateMode};
-pub use transform::DomExpressions;
+pub use api::{transform, transform_file, TransformOutput};
+pub use diagnostics::{Diagnostic, Severity};
+pub use options::{DomExpressionsOptions, GenerateMode, OutputStyle, PureCommentStyle, VarKind};
+pub use template::{DynamicSlot, SlotType, Template};
+pub use transform::{DomExpressions, TemplateRegistry};
 pub use compat2::DomExpressionsCompat2;
+#[cfg(feature = "wasm")]
+pub use wasm::transform_sync;
 
 #[cfg(test)]
 mod tests;
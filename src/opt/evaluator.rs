@@ -24,25 +24,27 @@ pub enum EvaluatedValue {
     Object(HashMap<String, EvaluatedValue>),
 }
 
-impl EvaluatedValue {
-    /// Convert to a string representation
-    pub fn to_string(&self) -> String {
+impl std::fmt::Display for EvaluatedValue {
+    /// Format using JavaScript's string conversion rules
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            EvaluatedValue::Boolean(b) => b.to_string(),
-            EvaluatedValue::String(s) => s.clone(),
+            EvaluatedValue::Boolean(b) => write!(f, "{}", b),
+            EvaluatedValue::String(s) => f.write_str(s),
             EvaluatedValue::Number(n) => {
                 if n.fract() == 0.0 && n.is_finite() {
-                    format!("{}", *n as i64)
+                    write!(f, "{}", *n as i64)
                 } else {
-                    n.to_string()
+                    write!(f, "{}", n)
                 }
             }
-            EvaluatedValue::Null => "null".to_string(),
-            EvaluatedValue::Undefined => "undefined".to_string(),
-            EvaluatedValue::Object(_) => "[object Object]".to_string(),
+            EvaluatedValue::Null => f.write_str("null"),
+            EvaluatedValue::Undefined => f.write_str("undefined"),
+            EvaluatedValue::Object(_) => f.write_str("[object Object]"),
         }
     }
+}
 
+impl EvaluatedValue {
     /// Check if this value is truthy
     pub fn is_truthy(&self) -> bool {
         match self {
@@ -192,11 +194,11 @@ fn evaluate_binary_expression(binary: &BinaryExpression) -> EvaluationResult {
             match (&left_value, &right_value) {
                 (EvaluatedValue::String(l), _) => EvaluationResult {
                     confident: true,
-                    value: Some(EvaluatedValue::String(format!("{}{}", l, right_value.to_string()))),
+                    value: Some(EvaluatedValue::String(format!("{}{}", l, right_value))),
                 },
                 (_, EvaluatedValue::String(r)) => EvaluationResult {
                     confident: true,
-                    value: Some(EvaluatedValue::String(format!("{}{}", left_value.to_string(), r))),
+                    value: Some(EvaluatedValue::String(format!("{}{}", left_value, r))),
                 },
                 (EvaluatedValue::Number(l), EvaluatedValue::Number(r)) => EvaluationResult {
                     confident: true,
@@ -6,6 +6,8 @@
 //! - Omitting quotes from attribute values when safe
 //! - Omitting closing tags for elements on the last-child path
 //! - Precisely handling the last-child path logic
+//! - Optionally omitting end tags that HTML5 makes optional (`</li>`, `</td>`, `</p>`)
+//! - Optionally collapsing runs of spaces and tabs in text outside `<pre>`-like elements
 
 use crate::html_subset_parser::{parse as parse_html, HtmlNode};
 use crate::options::DomExpressionsOptions;
@@ -19,28 +21,48 @@ pub fn minimize_template(html: &str, options: &DomExpressionsOptions) -> String
     serialize_html(&nodes, options, true)
 }
 
+/// Elements whose text content is whitespace-sensitive or not HTML
+const WHITESPACE_SENSITIVE_TAGS: &[&str] = &["pre", "textarea", "script", "style"];
+
 /// Serialize HTML nodes back to string with minimization
 fn serialize_html(nodes: &[HtmlNode], options: &DomExpressionsOptions, is_root: bool) -> String {
     let mut result = String::new();
 
     for (index, node) in nodes.iter().enumerate() {
         let is_last = index == nodes.len() - 1;
-        result.push_str(&serialize_node(node, options, is_root && is_last, is_last));
+        result.push_str(&serialize_node(
+            node,
+            options,
+            is_root && is_last,
+            nodes.get(index + 1),
+            None,
+            false,
+        ));
     }
 
     result
 }
 
 /// Serialize a single node
+///
+/// `next_sibling` and `parent_tag` decide whether an optional end tag can be left out.
+/// `preserve_whitespace` is set inside `<pre>`-like elements.
 fn serialize_node(
     node: &HtmlNode,
     options: &DomExpressionsOptions,
     on_last_path: bool,
-    _is_last_sibling: bool,
+    next_sibling: Option<&HtmlNode>,
+    parent_tag: Option<&str>,
+    preserve_whitespace: bool,
 ) -> String {
     match node {
+        HtmlNode::Text(text) if options.collapse_whitespace && !preserve_whitespace => {
+            collapse_whitespace(text)
+        }
         HtmlNode::Text(text) => text.clone(),
         HtmlNode::Marker => "<!>".to_string(),
+        HtmlNode::Comment(text) => format!("<!--{}-->", text),
+        HtmlNode::Cdata(text) => format!("<![CDATA[{}]]>", text),
         HtmlNode::Element {
             tag,
             attributes,
@@ -74,23 +96,14 @@ fn serialize_node(
 
             // Children and closing tag
             if !is_void {
-                // Determine if we should render children
-                // The last-path optimization: we continue rendering down the last-child path
-                // BUT we stop if we encounter mixed content (text + elements at same level)
-
-                let has_element_children = children
-                    .iter()
-                    .any(|c| matches!(c, HtmlNode::Element { .. }));
-                let has_text_children = children.iter().any(|c| matches!(c, HtmlNode::Text(_)));
-
-                // Stop rendering children only if:
-                // 1. We're on the last path, AND
-                // 2. We have both text and element children (mixed content)
-                // This handles the noscript case where it has "No JS!!" text + style element
-                let should_stop_here = on_last_path && has_element_children && has_text_children;
+                // <noscript> content never runs on the client, so babel drops it
+                // from DOM templates
+                let should_stop_here = tag.eq_ignore_ascii_case("noscript");
 
                 if !should_stop_here {
                     // Serialize children
+                    let preserve_whitespace = preserve_whitespace
+                        || WHITESPACE_SENSITIVE_TAGS.contains(&tag.to_ascii_lowercase().as_str());
                     for (idx, child) in children.iter().enumerate() {
                         let child_is_last = idx == children.len() - 1;
                         let child_is_element = matches!(child, HtmlNode::Element { .. });
@@ -99,13 +112,17 @@ fn serialize_node(
                             child,
                             options,
                             child_on_last_path,
-                            child_is_last,
+                            children.get(idx + 1),
+                            Some(tag),
+                            preserve_whitespace,
                         ));
                     }
                 }
 
                 // Closing tag - omit if on last path and option is set
-                let should_omit_closing = options.omit_last_closing_tag && on_last_path;
+                let should_omit_closing = (options.omit_last_closing_tag && on_last_path)
+                    || (options.omit_optional_tags
+                        && can_omit_end_tag(tag, next_sibling, parent_tag));
 
                 if !should_omit_closing {
                     result.push_str("</");
@@ -119,6 +136,103 @@ fn serialize_node(
     }
 }
 
+/// Elements whose start tag implicitly closes an open `<p>`
+const PARAGRAPH_CLOSING_TAGS: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "details",
+    "dialog",
+    "div",
+    "dl",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hgroup",
+    "hr",
+    "main",
+    "menu",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "search",
+    "section",
+    "table",
+    "ul",
+];
+
+/// Check whether the HTML5 optional end tag rules allow leaving out `</tag>`
+///
+/// The parser closes these elements implicitly when the next sibling starts
+/// one of the listed elements, or when the parent ends. Text or comments in
+/// between keep the end tag.
+fn can_omit_end_tag(tag: &str, next_sibling: Option<&HtmlNode>, parent_tag: Option<&str>) -> bool {
+    let next_tag = match next_sibling {
+        None => None,
+        Some(HtmlNode::Element { tag, .. }) => Some(tag.to_ascii_lowercase()),
+        Some(_) => return false,
+    };
+    let next_is = |tags: &[&str]| next_tag.as_deref().is_some_and(|next| tags.contains(&next));
+    let at_parent_end = next_tag.is_none();
+
+    match tag.to_ascii_lowercase().as_str() {
+        "li" => at_parent_end || next_is(&["li"]),
+        "dt" => next_is(&["dt", "dd"]),
+        "dd" => at_parent_end || next_is(&["dd", "dt"]),
+        "rt" | "rp" => at_parent_end || next_is(&["rt", "rp"]),
+        "optgroup" => at_parent_end || next_is(&["optgroup", "hr"]),
+        "option" => at_parent_end || next_is(&["option", "optgroup", "hr"]),
+        "thead" => next_is(&["tbody", "tfoot"]),
+        "tbody" => at_parent_end || next_is(&["tbody", "tfoot"]),
+        "tfoot" => at_parent_end,
+        "tr" => at_parent_end || next_is(&["tr"]),
+        "td" | "th" => at_parent_end || next_is(&["td", "th"]),
+        "p" => {
+            if at_parent_end {
+                // Content models that are transparent to a trailing paragraph
+                !parent_tag.is_some_and(|parent| {
+                    matches!(
+                        parent.to_ascii_lowercase().as_str(),
+                        "a" | "audio" | "del" | "ins" | "map" | "noscript" | "video"
+                    ) || parent.contains('-')
+                })
+            } else {
+                next_is(PARAGRAPH_CLOSING_TAGS)
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Replace each run of spaces and tabs with a single space
+fn collapse_whitespace(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut in_run = false;
+    for c in text.chars() {
+        if c == ' ' || c == '\t' {
+            if !in_run {
+                result.push(' ');
+            }
+            in_run = true;
+        } else {
+            result.push(c);
+            in_run = false;
+        }
+    }
+    result
+}
+
 /// Check if attribute value can be written without quotes
 fn can_omit_quotes(value: &str) -> bool {
     !value.is_empty()
@@ -193,7 +307,7 @@ mod tests {
 
     #[test]
     fn test_minimalize_noscript_mixed_content() {
-        // noscript with mixed content (text + element) should stop at noscript when on last path
+        // noscript content is dropped from DOM templates
         let html = r#"<div><noscript>No JS!!<style>div</style></noscript></div>"#;
         let options = DomExpressionsOptions {
             omit_quotes: false,
@@ -202,7 +316,102 @@ mod tests {
         };
 
         let result = minimize_template(html, &options);
-        // Should stop at noscript because it has mixed content (text + element)
         assert_eq!(result, r#"<div><noscript>"#);
     }
+
+    #[test]
+    fn test_minimalize_keeps_mixed_content() {
+        let html = r#"<label>Name: <input></label>"#;
+        let options = DomExpressionsOptions {
+            omit_quotes: false,
+            omit_last_closing_tag: true,
+            ..Default::default()
+        };
+
+        let result = minimize_template(html, &options);
+        assert_eq!(result, r#"<label>Name: <input>"#);
+    }
+
+    #[test]
+    fn test_minimalize_omits_optional_end_tags() {
+        let options = DomExpressionsOptions {
+            omit_last_closing_tag: false,
+            omit_optional_tags: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            minimize_template("<ul><li>a</li><li>b</li></ul>", &options),
+            "<ul><li>a<li>b</ul>"
+        );
+        assert_eq!(
+            minimize_template(
+                "<table><tbody><tr><td>a</td><td>b</td></tr></tbody></table>",
+                &options
+            ),
+            "<table><tbody><tr><td>a<td>b</table>"
+        );
+        assert_eq!(
+            minimize_template("<div><p>a</p><p>b</p></div>", &options),
+            "<div><p>a<p>b</div>"
+        );
+    }
+
+    #[test]
+    fn test_minimalize_keeps_required_end_tags() {
+        let options = DomExpressionsOptions {
+            omit_last_closing_tag: false,
+            omit_optional_tags: true,
+            ..Default::default()
+        };
+
+        // Text after the element, a following <span>, and a paragraph ending an <a>
+        assert_eq!(
+            minimize_template("<ul><li>a</li> </ul>", &options),
+            "<ul><li>a</li> </ul>"
+        );
+        assert_eq!(
+            minimize_template("<div><p>a</p><span>b</span></div>", &options),
+            "<div><p>a</p><span>b</span></div>"
+        );
+        assert_eq!(
+            minimize_template("<a><p>a</p></a>", &options),
+            "<a><p>a</p></a>"
+        );
+        assert_eq!(
+            minimize_template("<dl><dt>a</dt></dl>", &options),
+            "<dl><dt>a</dt></dl>"
+        );
+    }
+
+    #[test]
+    fn test_minimalize_collapses_whitespace() {
+        let options = DomExpressionsOptions {
+            omit_last_closing_tag: false,
+            collapse_whitespace: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            minimize_template("<div>a  \t b<pre>x    y</pre></div>", &options),
+            "<div>a b<pre>x    y</pre></div>"
+        );
+        assert_eq!(
+            minimize_template("<pre><b>x    y</b></pre><p>x    y</p>", &options),
+            "<pre><b>x    y</b></pre><p>x y</p>"
+        );
+    }
+
+    #[test]
+    fn test_minimalize_keeps_comments_and_cdata() {
+        let html = r#"<div><!--note--><svg><![CDATA[a<b]]></svg></div>"#;
+        let options = DomExpressionsOptions {
+            omit_quotes: false,
+            omit_last_closing_tag: true,
+            ..Default::default()
+        };
+
+        let result = minimize_template(html, &options);
+        assert_eq!(result, r#"<div><!--note--><svg><![CDATA[a<b]]>"#);
+    }
 }
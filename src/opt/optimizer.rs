@@ -45,6 +45,10 @@ pub struct TemplateStats {
     pub static_templates: usize,
     /// Number of dynamic templates
     pub dynamic_templates: usize,
+    /// Bytes of template HTML emitted, each unique template counted once
+    pub total_html_bytes: usize,
+    /// Bytes of template HTML not emitted because identical templates were shared
+    pub bytes_saved_by_dedup: usize,
 }
 
 impl TemplateStats {
@@ -118,6 +122,9 @@ impl TemplateOptimizer {
             // Count deduplicated size (template only stored once)
             stats.deduplicated_html_size += html.len();
 
+            stats.total_html_bytes += html.len();
+            stats.bytes_saved_by_dedup += html.len() * usage_count.saturating_sub(1);
+
             // Count static vs dynamic
             if template.dynamic_slots.is_empty() {
                 stats.static_templates += 1;
@@ -226,6 +233,7 @@ mod tests {
             deduplicated_html_size: 500,
             static_templates: 2,
             dynamic_templates: 3,
+            ..Default::default()
         };
 
         assert_eq!(stats.space_saved(), 500);
@@ -240,11 +248,13 @@ mod tests {
         let template1 = Template {
             html: "<div>Hello</div>".to_string(),
             dynamic_slots: vec![],
+            slot_attributes: vec![],
         };
 
         let template2 = Template {
             html: "<div>Hello</div>".to_string(),
             dynamic_slots: vec![],
+            slot_attributes: vec![],
         };
 
         optimizer.record_template(template1);
@@ -256,6 +266,26 @@ mod tests {
         assert_eq!(stats.reused_templates, 1);
     }
 
+    #[test]
+    fn test_optimizer_bytes_saved_by_dedup() {
+        let mut optimizer = TemplateOptimizer::new();
+
+        for html in ["<div>Hello</div>", "<div>Hello</div>", "<p>Other</p>"] {
+            optimizer.record_template(Template {
+                html: html.to_string(),
+                dynamic_slots: vec![],
+                slot_attributes: vec![],
+            });
+        }
+
+        let stats = optimizer.get_stats();
+        assert_eq!(stats.bytes_saved_by_dedup, "<div>Hello</div>".len());
+        assert_eq!(
+            stats.total_html_bytes,
+            "<div>Hello</div>".len() + "<p>Other</p>".len()
+        );
+    }
+
     #[test]
     fn test_optimizer_static_vs_dynamic() {
         let mut optimizer = TemplateOptimizer::new();
@@ -263,6 +293,7 @@ mod tests {
         let static_template = Template {
             html: "<div>Static</div>".to_string(),
             dynamic_slots: vec![],
+            slot_attributes: vec![],
         };
 
         let dynamic_template = Template {
@@ -271,7 +302,9 @@ mod tests {
                 path: vec![],
                 slot_type: SlotType::TextContent,
                 marker_path: None,
+                only_child: false,
             }],
+            slot_attributes: vec![],
         };
 
         optimizer.record_template(static_template);
@@ -294,8 +327,10 @@ mod tests {
                     path: vec![],
                     slot_type: SlotType::TextContent,
                     marker_path: None,
+                    only_child: false,
                 })
                 .collect(),
+            slot_attributes: vec![],
         };
 
         optimizer.record_template(large_template);
@@ -316,8 +351,10 @@ mod tests {
                     path: vec![],
                     slot_type: SlotType::TextContent,
                     marker_path: None,
+                    only_child: false,
                 })
                 .collect(),
+            slot_attributes: vec![],
         };
 
         optimizer.record_template(template);
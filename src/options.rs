@@ -23,7 +23,7 @@
 //! # Key Options
 //!
 //! - **module_name**: Runtime module to import from (e.g., "solid-js/web", "r-dom")
-//! - **generate**: Output mode (Dom or Ssr)
+//! - **generate**: Output mode (Dom, Ssr or Hydratable)
 //! - **delegate_events**: Enable event delegation for better performance
 //! - **hydratable**: Generate hydration markers for SSR
 //! - **wrap_conditionals**: Wrap conditionals in functions for reactivity
@@ -31,27 +31,73 @@
 //! - **builtin_components**: Map of built-in component transformers
 //! - **effect_wrapper**: Custom effect wrapper function name
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Output mode for the compiler
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum GenerateMode {
     /// Standard DOM output
+    #[default]
     Dom,
     /// Server-side rendering output
     Ssr,
+    /// DOM output that claims server-rendered nodes during hydration
+    Hydratable,
 }
 
-impl Default for GenerateMode {
-    fn default() -> Self {
-        Self::Dom
+/// Formatting of the code returned by [`crate::transform()`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum OutputStyle {
+    /// Normalized to babel's formatting (`/*#__PURE__*/`, two-space indents)
+    #[default]
+    Babel,
+    /// oxc's codegen formatting as-is
+    Oxc,
+}
+
+/// Spelling of the pure annotations on generated template calls
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum PureCommentStyle {
+    /// `/*#__PURE__*/`, as emitted by babel
+    Babel,
+    /// `/* @__PURE__ */`, as emitted by oxc's codegen
+    Oxc,
+}
+
+impl PureCommentStyle {
+    /// The comment text for this style
+    pub fn comment(self) -> &'static str {
+        use crate::compat::constants::{BABEL_PURE_COMMENT, OXC_PURE_COMMENT};
+        match self {
+            PureCommentStyle::Babel => BABEL_PURE_COMMENT,
+            PureCommentStyle::Oxc => OXC_PURE_COMMENT,
+        }
     }
 }
 
+/// Declaration keyword for template variables and element references
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum VarKind {
+    /// `var`, as emitted by the babel plugin
+    #[default]
+    Var,
+    /// `const`
+    Const,
+}
+
 /// Configuration options for the DOM expressions transformer
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase", default)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 pub struct DomExpressionsOptions {
     /// The name of the runtime module to import methods from
     pub module_name: String,
@@ -65,6 +111,12 @@ pub struct DomExpressionsOptions {
     /// Whether to enable automatic event delegation on camelCase
     pub delegate_events: bool,
 
+    /// Whether scroll, touch and wheel handlers are bound as passive listeners
+    ///
+    /// Passive listeners can't be delegated, so these events are then bound
+    /// directly on the element with `{ passive: true }`.
+    pub passive_events: bool,
+
     /// Whether smart conditional detection should be used
     pub wrap_conditionals: bool,
 
@@ -74,6 +126,10 @@ pub struct DomExpressionsOptions {
     /// Array of Component exports from module that aren't included by default
     pub built_ins: Vec<String>,
 
+    /// Properties assigned directly (el.prop = ...) on custom elements instead of
+    /// set as attributes
+    pub custom_element_properties: Vec<String>,
+
     /// The reactive wrapper function name
     pub effect_wrapper: String,
 
@@ -92,11 +148,64 @@ pub struct DomExpressionsOptions {
     /// Whether to remove tags if they are the last element
     pub omit_last_closing_tag: bool,
 
+    /// Whether to remove end tags that HTML5 makes optional (`</li>`, `</td>`, `</p>`)
+    pub omit_optional_tags: bool,
+
+    /// Whether to collapse runs of spaces and tabs in template text
+    ///
+    /// Text inside `<pre>`, `<textarea>`, `<script>` and `<style>` is kept as is.
+    pub collapse_whitespace: bool,
+
+    /// Whether `{/* ... */}` comments are kept in templates as HTML comments
+    pub preserve_comments: bool,
+
     /// Whether to remove quotes for HTML attributes when possible
     pub omit_quotes: bool,
 
     /// When set, restricts JSX transformation to files with specific import source pragma
     pub require_import_source: Option<String>,
+
+    /// Prefix for the local names of imported runtime functions (`_$insert`)
+    pub import_prefix: String,
+
+    /// Whether script (non-module) sources load the runtime with `require`
+    ///
+    /// When disabled, no imports are emitted for scripts and the runtime
+    /// functions are expected to be in scope already.
+    pub require_in_scripts: bool,
+
+    /// Whether to emit all runtime imports as a single statement
+    ///
+    /// Defaults to one statement per function, matching the babel plugin's output.
+    pub merged_imports: bool,
+
+    /// Whether to use short generated variable names (`a`, `b`) instead of
+    /// `_tmpl$` and `_el$`, avoiding every name already used in the program
+    pub minify_names: bool,
+
+    /// Formatting of the code returned by [`crate::transform()`]
+    pub output_style: OutputStyle,
+
+    /// Spelling of pure annotations in the code returned by [`crate::transform()`]
+    ///
+    /// `None` follows [`Self::output_style`]. A source map keeps oxc's formatting,
+    /// so with [`Self::sourcemap`] set a babel-style comment is not applied and a
+    /// warning is reported instead.
+    pub pure_comment: Option<PureCommentStyle>,
+
+    /// Whether identical template HTML shares one template variable
+    ///
+    /// When disabled every JSX element gets its own `_tmpl$N`.
+    pub template_dedup: bool,
+
+    /// Whether [`crate::transform()`] also returns a source map
+    ///
+    /// The code then keeps oxc's formatting so the mappings stay accurate.
+    pub sourcemap: bool,
+
+    /// Keyword used to declare `_tmpl$` templates and the `_el$` element
+    /// references inside generated functions
+    pub template_decl_kind: VarKind,
 }
 
 impl Default for DomExpressionsOptions {
@@ -106,17 +215,31 @@ impl Default for DomExpressionsOptions {
             generate: GenerateMode::Dom,
             hydratable: false,
             delegate_events: true,
+            passive_events: false,
             wrap_conditionals: true,
             context_to_custom_elements: false,
             built_ins: Vec::new(),
+            custom_element_properties: Vec::new(),
             effect_wrapper: String::from("effect"),
             static_marker: String::from("@once"),
             memo_wrapper: String::from("memo"),
             validate: true,
             omit_nested_closing_tags: false,
             omit_last_closing_tag: true,
+            omit_optional_tags: false,
+            collapse_whitespace: false,
+            preserve_comments: false,
             omit_quotes: true,
             require_import_source: None,
+            import_prefix: String::from("_$"),
+            require_in_scripts: true,
+            merged_imports: false,
+            minify_names: false,
+            output_style: OutputStyle::Babel,
+            pure_comment: None,
+            template_dedup: true,
+            sourcemap: false,
+            template_decl_kind: VarKind::Var,
         }
     }
 }
@@ -141,4 +264,175 @@ impl DomExpressionsOptions {
         self.delegate_events = delegate;
         self
     }
+
+    /// Set whether scroll, touch and wheel handlers are bound as passive listeners
+    pub fn with_passive_events(mut self, passive: bool) -> Self {
+        self.passive_events = passive;
+        self
+    }
+
+    /// Set whether the output should contain hydratable markers
+    ///
+    /// In `GenerateMode::Dom` this switches element creation to `_$getNextElement`,
+    /// see [`is_hydratable`](Self::is_hydratable).
+    pub fn with_hydratable(mut self, hydratable: bool) -> Self {
+        self.hydratable = hydratable;
+        self
+    }
+
+    /// Set the prefix for the local names of imported runtime functions
+    pub fn with_import_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.import_prefix = prefix.into();
+        self
+    }
+
+    /// Set whether script sources load the runtime with `require`
+    pub fn with_require_in_scripts(mut self, require: bool) -> Self {
+        self.require_in_scripts = require;
+        self
+    }
+
+    /// Set whether runtime imports are merged into a single statement
+    pub fn with_merged_imports(mut self, merged: bool) -> Self {
+        self.merged_imports = merged;
+        self
+    }
+
+    /// Set whether generated variable names are minified
+    pub fn with_minify_names(mut self, minify: bool) -> Self {
+        self.minify_names = minify;
+        self
+    }
+
+    /// Set whether end tags that HTML5 makes optional are removed from templates
+    pub fn with_omit_optional_tags(mut self, omit: bool) -> Self {
+        self.omit_optional_tags = omit;
+        self
+    }
+
+    /// Set whether runs of spaces and tabs in template text are collapsed
+    pub fn with_collapse_whitespace(mut self, collapse: bool) -> Self {
+        self.collapse_whitespace = collapse;
+        self
+    }
+
+    /// Set whether `{/* ... */}` comments are kept in templates as HTML comments
+    pub fn with_preserve_comments(mut self, preserve: bool) -> Self {
+        self.preserve_comments = preserve;
+        self
+    }
+
+    /// Set the formatting of the code returned by [`crate::transform()`]
+    pub fn with_output_style(mut self, style: OutputStyle) -> Self {
+        self.output_style = style;
+        self
+    }
+
+    /// Set the spelling of pure annotations, independent of the output style
+    ///
+    /// Rewriting the annotations would shift the source map's columns, so when
+    /// a source map is generated oxc's `/* @__PURE__ */` is kept and choosing
+    /// [`PureCommentStyle::Babel`] reports a warning diagnostic.
+    pub fn with_pure_comment(mut self, style: PureCommentStyle) -> Self {
+        self.pure_comment = Some(style);
+        self
+    }
+
+    /// Set whether identical templates share one template variable
+    pub fn with_template_dedup(mut self, dedup: bool) -> Self {
+        self.template_dedup = dedup;
+        self
+    }
+
+    /// Set whether a source map is generated alongside the code
+    pub fn with_sourcemap(mut self, sourcemap: bool) -> Self {
+        self.sourcemap = sourcemap;
+        self
+    }
+
+    /// Set the keyword used to declare templates and element references
+    pub fn with_template_decl_kind(mut self, kind: VarKind) -> Self {
+        self.template_decl_kind = kind;
+        self
+    }
+
+    /// Set the component exports imported from the module when used as JSX tags
+    pub fn with_built_ins(mut self, built_ins: Vec<String>) -> Self {
+        self.built_ins = built_ins;
+        self
+    }
+
+    /// Set the custom element props that are assigned as properties
+    pub fn with_custom_element_properties(mut self, properties: Vec<String>) -> Self {
+        self.custom_element_properties = properties;
+        self
+    }
+
+    /// Get the local name a runtime function is imported as (e.g. `_$insert`)
+    pub fn runtime_name(&self, name: &str) -> String {
+        format!("{}{}", self.import_prefix, name)
+    }
+
+    /// Whether DOM output should claim existing nodes instead of cloning templates
+    ///
+    /// This is the case for `GenerateMode::Hydratable`, and for `GenerateMode::Dom`
+    /// when the `hydratable` flag is set (matching the babel plugin's configuration).
+    pub fn is_hydratable(&self) -> bool {
+        match self.generate {
+            GenerateMode::Hydratable => true,
+            GenerateMode::Dom => self.hydratable,
+            GenerateMode::Ssr => false,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_babel_config_round_trip() {
+        // Shaped like the options object passed to babel-plugin-jsx-dom-expressions
+        let json = r#"{
+            "moduleName": "r-dom",
+            "generate": "ssr",
+            "hydratable": true,
+            "delegateEvents": false,
+            "wrapConditionals": false,
+            "contextToCustomElements": true,
+            "builtIns": ["For", "Show"],
+            "effectWrapper": "effect2",
+            "staticMarker": "@static",
+            "memoWrapper": "memo2",
+            "validate": false,
+            "omitNestedClosingTags": true,
+            "omitLastClosingTag": false,
+            "omitQuotes": false,
+            "requireImportSource": "solid-js"
+        }"#;
+        let options: DomExpressionsOptions = serde_json::from_str(json).unwrap();
+
+        let mut expected = DomExpressionsOptions::new("r-dom")
+            .with_generate(GenerateMode::Ssr)
+            .with_hydratable(true)
+            .with_delegate_events(false)
+            .with_built_ins(vec!["For".to_string(), "Show".to_string()]);
+        expected.wrap_conditionals = false;
+        expected.context_to_custom_elements = true;
+        expected.effect_wrapper = "effect2".to_string();
+        expected.static_marker = "@static".to_string();
+        expected.memo_wrapper = "memo2".to_string();
+        expected.validate = false;
+        expected.omit_nested_closing_tags = true;
+        expected.omit_last_closing_tag = false;
+        expected.omit_quotes = false;
+        expected.require_import_source = Some("solid-js".to_string());
+        assert_eq!(format!("{:?}", options), format!("{:?}", expected));
+
+        let serialized = serde_json::to_string(&options).unwrap();
+        assert!(serialized.contains(r#""moduleName":"r-dom""#));
+        assert!(serialized.contains(r#""generate":"ssr""#));
+        let round_trip: DomExpressionsOptions = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(format!("{:?}", round_trip), format!("{:?}", options));
+    }
 }
@@ -19,7 +19,7 @@
 //! ```
 //!
 //! Output Template:
-//! ```rust
+//! ```text
 //! Template {
 //!     html: "<div class=\"container\"><span>",
 //!     dynamic_slots: [
@@ -27,8 +27,10 @@
 //!             path: vec!["firstChild"],  // Path to <span>
 //!             slot_type: SlotType::TextContent,
 //!             marker_path: None,
+//!             only_child: true,
 //!         }
-//!     ]
+//!     ],
+//!     slot_attributes: [],
 //! }
 //! ```
 //!
@@ -43,16 +45,22 @@
 //! - **OnEvent**: Custom events (on: prefix)
 //! - **OnCaptureEvent**: Capture phase events (oncapture: prefix)
 
+use oxc_allocator::{Allocator, Box, CloneIn};
 use oxc_ast::ast::*;
+use oxc_span::{GetSpan, Span, SPAN};
 use std::fmt::Write;
 
 #[cfg(feature = "opt")]
 use crate::opt::evaluator::{evaluate_expression, EvaluatedValue};
+#[cfg(feature = "opt")]
+use crate::utils::is_enumerated_attribute;
 use crate::utils::{
-    get_event_name, get_prefix_event_name, get_prefixed_name, is_attr_attribute, is_bool_attribute,
-    is_class_list_binding, is_class_name_binding, is_event_handler, is_on_capture_event,
-    is_on_prefix_event, is_prop_attribute, is_ref_binding, is_style_binding, is_style_property,
-    is_use_directive, is_void_element,
+    can_native_spread, decode_html_entities, get_attribute_namespace, get_dom_property_name,
+    get_event_name, get_prefix_event_name, get_prefixed_name, get_svg_attribute_name,
+    is_attr_attribute, is_bool_attribute, is_class_list_binding, is_class_name_binding,
+    is_event_handler, is_nullish_jsx_expression, is_on_capture_event, is_on_prefix_event,
+    is_prop_attribute, is_ref_binding, is_style_binding, is_style_property, is_svg_element,
+    is_use_directive, is_void_element, normalize_style_string,
 };
 
 /// Represents a template with its HTML string and dynamic expression positions
@@ -62,6 +70,12 @@ pub struct Template {
     pub html: String,
     /// Positions where dynamic content should be inserted
     pub dynamic_slots: Vec<DynamicSlot>,
+    /// Spans of the attributes that were given a dynamic slot, in slot order
+    ///
+    /// Attributes not listed here were written into the HTML or dropped, so
+    /// code generation only takes expressions from the listed ones. An element's
+    /// first spread stands for all props merged into its spread call.
+    pub slot_attributes: Vec<Span>,
 }
 
 /// Represents a position where dynamic content needs to be inserted
@@ -74,6 +88,9 @@ pub struct DynamicSlot {
     /// Path to the marker node (for text content insertion positioning)
     /// None if this is a trailing expression (insert at end with null)
     pub marker_path: Option<Vec<String>>,
+    /// Whether this text content is the only child of its parent element,
+    /// so it can be inserted without a marker argument
+    pub only_child: bool,
 }
 
 /// Type of dynamic slot
@@ -83,6 +100,13 @@ pub enum SlotType {
     TextContent,
     /// Attribute or property
     Attribute(String),
+    /// Attribute in an XML namespace (`xlink:href`), set with `setAttributeNS`
+    AttributeNS {
+        /// Namespace URI
+        ns: String,
+        /// Local attribute name
+        name: String,
+    },
     /// Event handler
     EventHandler(String),
     /// Ref binding
@@ -108,9 +132,19 @@ pub enum SlotType {
     /// Class name binding (class: prefix)
     ClassName(String),
     /// Spread attribute {...props}
-    Spread,
+    Spread {
+        /// Whether the element is an SVG element
+        is_svg: bool,
+        /// Whether the element has JSX children, so the spread leaves `children` alone
+        has_children: bool,
+    },
 }
 
+/// Text of the comments in a file, with the span each comment covers
+///
+/// Used to keep `{/* ... */}` children in templates as HTML comments.
+pub type JsxComments = Vec<(Span, String)>;
+
 /// Build a template from a JSX element
 pub fn build_template(element: &JSXElement) -> Template {
     build_template_with_options(element, None)
@@ -120,12 +154,23 @@ pub fn build_template(element: &JSXElement) -> Template {
 pub fn build_template_with_options(
     element: &JSXElement,
     options: Option<&crate::options::DomExpressionsOptions>,
+) -> Template {
+    build_template_with_comments(element, options, None)
+}
+
+/// Build a template from a JSX element with options, writing the given
+/// comments found in `{/* ... */}` children as `<!--...-->` nodes
+pub fn build_template_with_comments(
+    element: &JSXElement,
+    options: Option<&crate::options::DomExpressionsOptions>,
+    comments: Option<&JsxComments>,
 ) -> Template {
     use crate::options::GenerateMode;
 
     let mut template = Template {
         html: String::new(),
         dynamic_slots: Vec::new(),
+        slot_attributes: Vec::new(),
     };
 
     // Build standard HTML from JSX
@@ -133,22 +178,21 @@ pub fn build_template_with_options(
         element,
         &mut template.html,
         &mut template.dynamic_slots,
+        &mut template.slot_attributes,
         &mut Vec::new(),
+        options,
+        comments,
     );
 
-    // Apply minimalization only for DOM mode
+    // Apply minimalization only for DOM output (including hydratable)
     // SSR needs complete HTML with all closing tags and proper quoting
     if let Some(opts) = options {
-        if opts.generate == GenerateMode::Dom {
+        if opts.generate != GenerateMode::Ssr {
             #[cfg(feature = "opt")]
             {
                 template.html = crate::opt::minimizer::minimize_template(&template.html, opts);
             }
             // Without opt feature, HTML is used as-is
-        } else {
-            // SSR mode: unescape braces that were escaped for template literals
-            // Template literals need \{ but string literals don't
-            template.html = template.html.replace("\\{", "{");
         }
     }
 
@@ -161,18 +205,69 @@ fn build_element_html(
     element: &JSXElement,
     html: &mut String,
     slots: &mut Vec<DynamicSlot>,
+    slot_attributes: &mut Vec<Span>,
     path: &mut Vec<String>,
+    options: Option<&crate::options::DomExpressionsOptions>,
+    comments: Option<&JsxComments>,
 ) {
     let tag_name = get_element_name(&element.opening_element);
 
     // Opening tag
     let _ = write!(html, "<{}", tag_name);
 
+    // Attributes merged into the element's spread are applied by the spread call
+    let spread_props: Vec<Span> = spread_prop_attributes(&element.opening_element.attributes)
+        .map(GetSpan::span)
+        .collect();
+
+    // A dynamic class would overwrite classList names folded into the template
+    let has_dynamic_class = element.opening_element.attributes.iter().any(|attr| {
+        matches!(attr, JSXAttributeItem::Attribute(attr)
+            if get_attribute_name(&attr.name).is_some_and(|name| name == "class")
+                && !is_static_class_value(attr))
+    });
+
+    // A fully static classList is folded into the template's class attribute
+    let mut folded_classes = if has_dynamic_class {
+        None
+    } else {
+        element
+            .opening_element
+            .attributes
+            .iter()
+            .find_map(|attr| match attr {
+                JSXAttributeItem::Attribute(attr)
+                    if !spread_props.contains(&attr.span)
+                        && get_attribute_name(&attr.name)
+                            .is_some_and(|name| is_class_list_binding(&name)) =>
+                {
+                    static_class_list_classes(attr)
+                }
+                _ => None,
+            })
+    };
+
+    // Static innerHTML/textContent/innerText, written as the element's content
+    let mut static_content: Option<String> = None;
+
     // Process attributes
     for attr in &element.opening_element.attributes {
+        // The first spread owns the slot for all merged props
+        if spread_props.contains(&attr.span()) && spread_props.first() != Some(&attr.span()) {
+            continue;
+        }
+
+        let slot_count = slots.len();
         match attr {
             JSXAttributeItem::Attribute(attr) => {
                 if let Some(name) = get_attribute_name(&attr.name) {
+                    // SVG spells namespaced attributes like `xlinkHref` as `xlink:href`
+                    let name = if is_svg_element(&tag_name) {
+                        get_svg_attribute_name(&name)
+                    } else {
+                        name
+                    };
+
                     // Check for special bindings
                     if is_ref_binding(&name) {
                         // Ref binding - track for later code generation
@@ -180,14 +275,18 @@ fn build_element_html(
                             path: path.clone(),
                             slot_type: SlotType::Ref,
                             marker_path: None,
+                            only_child: false,
                         });
                     } else if is_class_list_binding(&name) {
-                        // ClassList binding
-                        slots.push(DynamicSlot {
-                            path: path.clone(),
-                            slot_type: SlotType::ClassList,
-                            marker_path: None,
-                        });
+                        // ClassList binding - static objects were folded above
+                        if has_dynamic_class || static_class_list_classes(attr).is_none() {
+                            slots.push(DynamicSlot {
+                                path: path.clone(),
+                                slot_type: SlotType::ClassList,
+                                marker_path: None,
+                                only_child: false,
+                            });
+                        }
                     } else if is_style_binding(&name) && attr.value.is_some() {
                         // Style object binding
                         if !matches!(attr.value, Some(JSXAttributeValue::StringLiteral(_))) {
@@ -204,10 +303,22 @@ fn build_element_html(
                                     &attr.value
                                 {
                                     if let Some(expr) = container.expression.as_expression() {
-                                        if let Some(css) =
+                                        if let Expression::StringLiteral(lit) = expr {
+                                            // style={"color: red"} is a static style string
+                                            let css = normalize_style_string(&lit.value);
+                                            let _ = write!(
+                                                html,
+                                                " style=\"{}\"",
+                                                escape_attribute_value(&css)
+                                            );
+                                        } else if let Some(css) =
                                             crate::utils::static_style_object_to_css(expr)
                                         {
-                                            let _ = write!(html, " style={}", css);
+                                            let _ = write!(
+                                                html,
+                                                " style=\"{}\"",
+                                                escape_attribute_value(&css)
+                                            );
                                         }
                                     }
                                 }
@@ -217,12 +328,14 @@ fn build_element_html(
                                     path: path.clone(),
                                     slot_type: SlotType::StyleObject,
                                     marker_path: None,
+                                    only_child: false,
                                 });
                             }
                         } else if let Some(value) = &attr.value {
                             // Static style string
                             if let Some(static_value) = get_static_attribute_value(value) {
-                                let _ = write!(html, " style=\"{}\"", static_value);
+                                let css = normalize_style_string(&static_value);
+                                let _ = write!(html, " style=\"{}\"", escape_attribute_value(&css));
                             }
                         }
                     } else if is_on_prefix_event(&name) {
@@ -232,6 +345,7 @@ fn build_element_html(
                                 path: path.clone(),
                                 slot_type: SlotType::OnEvent(event_name.to_string()),
                                 marker_path: None,
+                                only_child: false,
                             });
                         }
                     } else if is_on_capture_event(&name) {
@@ -241,6 +355,7 @@ fn build_element_html(
                                 path: path.clone(),
                                 slot_type: SlotType::OnCaptureEvent(event_name.to_string()),
                                 marker_path: None,
+                                only_child: false,
                             });
                         }
                     } else if is_bool_attribute(&name) {
@@ -329,6 +444,7 @@ fn build_element_html(
                                     path: path.clone(),
                                     slot_type: SlotType::BoolAttribute(attr_name.to_string()),
                                     marker_path: None,
+                                    only_child: false,
                                 });
                             }
                         }
@@ -339,6 +455,7 @@ fn build_element_html(
                                 path: path.clone(),
                                 slot_type: SlotType::PropAttribute(attr_name.to_string()),
                                 marker_path: None,
+                                only_child: false,
                             });
                         }
                     } else if is_attr_attribute(&name) {
@@ -348,6 +465,7 @@ fn build_element_html(
                                 path: path.clone(),
                                 slot_type: SlotType::AttrAttribute(attr_name.to_string()),
                                 marker_path: None,
+                                only_child: false,
                             });
                         }
                     } else if is_use_directive(&name) {
@@ -357,6 +475,7 @@ fn build_element_html(
                                 path: path.clone(),
                                 slot_type: SlotType::UseDirective(directive_name.to_string()),
                                 marker_path: None,
+                                only_child: false,
                             });
                         }
                     } else if is_style_property(&name) {
@@ -366,6 +485,7 @@ fn build_element_html(
                                 path: path.clone(),
                                 slot_type: SlotType::StyleProperty(prop_name.to_string()),
                                 marker_path: None,
+                                only_child: false,
                             });
                         }
                     } else if is_class_name_binding(&name) {
@@ -375,6 +495,7 @@ fn build_element_html(
                                 path: path.clone(),
                                 slot_type: SlotType::ClassName(class_name.to_string()),
                                 marker_path: None,
+                                only_child: false,
                             });
                         }
                     } else if is_event_handler(&name) {
@@ -384,6 +505,7 @@ fn build_element_html(
                                 path: path.clone(),
                                 slot_type: SlotType::EventHandler(event_name.to_string()),
                                 marker_path: None,
+                                only_child: false,
                             });
                         }
                     } else if let Some(value) = &attr.value {
@@ -392,22 +514,75 @@ fn build_element_html(
                         let is_content_attr = name == "innerHTML" || name == "textContent" || name == "innerText";
                         
                         if is_content_attr {
-                            // Always make content attributes dynamic
+                            // Content attributes are properties; known values become the
+                            // element's content in the template
                             // NOTE: Babel adds space marker for textContent when expression is "dynamic enough"
                             // (e.g., member expressions like row.label that need effect wrapper).
                             // This requires checking if the expression will be wrapped in effect,
                             // which needs isDynamic() logic from babel-plugin.
-                            // For now, we always create a dynamic slot without the space optimization.
-                            slots.push(DynamicSlot {
-                                path: path.clone(),
-                                slot_type: SlotType::Attribute(name.clone()),
-                                marker_path: None,
-                            });
+                            // For now, we create a dynamic slot without the space optimization.
+                            if let Some(content) = static_content_attribute_value(attr, &tag_name) {
+                                static_content = Some(if name == "innerHTML" {
+                                    content
+                                } else {
+                                    escape_html(&content)
+                                });
+                            } else {
+                                slots.push(DynamicSlot {
+                                    path: path.clone(),
+                                    slot_type: SlotType::PropAttribute(name.clone()),
+                                    marker_path: None,
+                                    only_child: false,
+                                });
+                            }
                         } else {
+                            // Properties of host elements are assigned directly (el.value = ...)
+                            // and namespaced attributes go through setAttributeNS
+                            let dynamic_slot_type = match get_dom_property_name(&tag_name, &name) {
+                                Some(prop)
+                                    if !is_svg_element(&tag_name) && !tag_name.contains('-') =>
+                                {
+                                    SlotType::PropAttribute(prop.to_string())
+                                }
+                                // Listed custom element props are assigned as properties
+                                _ if tag_name.contains('-')
+                                    && options.is_some_and(|opts| {
+                                        opts.custom_element_properties.contains(&name)
+                                    }) =>
+                                {
+                                    SlotType::PropAttribute(name.clone())
+                                }
+                                _ => match get_attribute_namespace(&name) {
+                                    Some((ns, local_name)) => SlotType::AttributeNS {
+                                        ns: ns.to_string(),
+                                        name: local_name.to_string(),
+                                    },
+                                    None => SlotType::Attribute(name.clone()),
+                                },
+                            };
+
                             match value {
+                                JSXAttributeValue::StringLiteral(lit) if name == "class" => {
+                                    // Static class - merge in any folded classList names
+                                    let classes = merge_class_names(
+                                        &decode_html_entities(&lit.value),
+                                        folded_classes.take(),
+                                    );
+                                    let _ = write!(
+                                        html,
+                                        " class=\"{}\"",
+                                        escape_attribute_value(&classes)
+                                    );
+                                }
                                 JSXAttributeValue::StringLiteral(lit) => {
                                     // Static string - add to template
-                                    let _ = write!(html, " {}=\"{}\"", name, lit.value);
+                                    let value = decode_html_entities(&lit.value);
+                                    let _ = write!(
+                                        html,
+                                        " {}=\"{}\"",
+                                        name,
+                                        escape_attribute_value(&value)
+                                    );
                                 }
                                 JSXAttributeValue::ExpressionContainer(container) => {
                                     #[cfg_attr(not(feature = "opt"), allow(unused_variables))]
@@ -420,9 +595,27 @@ fn build_element_html(
                                             if eval_result.confident {
                                                 // We can determine the value at compile time
                                                 match &eval_result.value {
+                                                    Some(EvaluatedValue::String(s))
+                                                        if name == "class" =>
+                                                    {
+                                                        let classes = merge_class_names(
+                                                            s,
+                                                            folded_classes.take(),
+                                                        );
+                                                        let _ = write!(
+                                                            html,
+                                                            " class=\"{}\"",
+                                                            escape_attribute_value(&classes)
+                                                        );
+                                                    }
                                                     Some(EvaluatedValue::String(s)) => {
                                                         // String value - inline in template
-                                                        let _ = write!(html, " {}=\"{}\"", name, s);
+                                                        let _ = write!(
+                                                            html,
+                                                            " {}=\"{}\"",
+                                                            name,
+                                                            escape_attribute_value(s)
+                                                        );
                                                     }
                                                     Some(EvaluatedValue::Number(n)) => {
                                                         // Number value - inline in template
@@ -433,16 +626,30 @@ fn build_element_html(
                                                         };
                                                         let _ = write!(html, " {}=\"{}\"", name, num_str);
                                                     }
-                                                    Some(EvaluatedValue::Boolean(b)) => {
-                                                        // Boolean value - inline in template
+                                                    Some(EvaluatedValue::Boolean(b))
+                                                        if is_enumerated_attribute(&name) =>
+                                                    {
+                                                        // Enumerated attributes spell out the value
                                                         let _ = write!(html, " {}=\"{}\"", name, b);
                                                     }
+                                                    Some(EvaluatedValue::Boolean(true)) => {
+                                                        // true - write the bare attribute name
+                                                        let _ = write!(html, " {}", name);
+                                                    }
+                                                    Some(EvaluatedValue::Boolean(false)) => {
+                                                        // false - omit the attribute
+                                                    }
+                                                    Some(EvaluatedValue::Null)
+                                                    | Some(EvaluatedValue::Undefined) => {
+                                                        // null and undefined - omit the attribute
+                                                    }
                                                     _ => {
                                                         // Other static values or non-evaluatable - make it dynamic
                                                         slots.push(DynamicSlot {
                                                             path: path.clone(),
-                                                            slot_type: SlotType::Attribute(name.clone()),
+                                                            slot_type: dynamic_slot_type.clone(),
                                                             marker_path: None,
+                                                            only_child: false,
                                                         });
                                                     }
                                                 }
@@ -450,8 +657,9 @@ fn build_element_html(
                                                 // Not confident - make it dynamic
                                                 slots.push(DynamicSlot {
                                                     path: path.clone(),
-                                                    slot_type: SlotType::Attribute(name.clone()),
+                                                    slot_type: dynamic_slot_type.clone(),
                                                     marker_path: None,
+                                                    only_child: false,
                                                 });
                                             }
                                         }
@@ -460,28 +668,29 @@ fn build_element_html(
                                             // Without opt feature, always make it dynamic
                                             slots.push(DynamicSlot {
                                                 path: path.clone(),
-                                                slot_type: SlotType::Attribute(name.clone()),
+                                                slot_type: dynamic_slot_type.clone(),
                                                 marker_path: None,
+                                                only_child: false,
                                             });
                                         }
-                                    } else {
-                                        // Empty expression or other - make it dynamic
-                                        slots.push(DynamicSlot {
-                                            path: path.clone(),
-                                            slot_type: SlotType::Attribute(name.clone()),
-                                            marker_path: None,
-                                        });
                                     }
+                                    // Empty `{}` values produce no attribute
                                 }
                                 _ => {
                                     // Fragment or other - shouldn't happen but handle it
                                     if let Some(static_value) = get_static_attribute_value(value) {
-                                        let _ = write!(html, " {}=\"{}\"", name, static_value);
+                                        let _ = write!(
+                                            html,
+                                            " {}=\"{}\"",
+                                            name,
+                                            escape_attribute_value(&static_value)
+                                        );
                                     } else {
                                         slots.push(DynamicSlot {
                                             path: path.clone(),
-                                            slot_type: SlotType::Attribute(name.clone()),
+                                            slot_type: dynamic_slot_type.clone(),
                                             marker_path: None,
+                                            only_child: false,
                                         });
                                     }
                                 }
@@ -495,111 +704,186 @@ fn build_element_html(
             }
             JSXAttributeItem::SpreadAttribute(_spread) => {
                 // Spread attribute - track for later code generation
-                // Spread attributes don't contribute to the template HTML
+                // Spread props don't contribute to the template HTML
                 // They will be processed during code generation
                 slots.push(DynamicSlot {
                     path: path.clone(),
-                    slot_type: SlotType::Spread,
+                    slot_type: SlotType::Spread {
+                        is_svg: is_svg_element(&tag_name),
+                        has_children: !element.children.is_empty(),
+                    },
                     marker_path: None,
+                    only_child: false,
                 });
             }
         }
+
+        if slots.len() > slot_count {
+            slot_attributes.push(attr.span());
+        }
+    }
+
+    // No static class attribute took the folded classList names
+    if let Some(classes) = folded_classes.filter(|classes| !classes.is_empty()) {
+        let _ = write!(html, " class=\"{}\"", escape_attribute_value(&classes));
     }
 
     let _ = write!(html, ">");
 
+    if let Some(content) = static_content {
+        html.push_str(&content);
+    }
+
     // Children
     if !is_void_element(&tag_name) {
-        let child_path_start = path.len();
-
-        // First pass: determine which children will create nodes
-        let mut will_create_node = Vec::new();
-        for child in &element.children {
-            let creates_node = match child {
-                JSXChild::Text(text) => {
-                    let text_value = text.value.as_str();
-                    !(text_value.trim().is_empty() && text_value.contains('\n'))
-                }
-                JSXChild::Element(_) => true,
-                JSXChild::ExpressionContainer(container) => !matches!(
-                    &container.expression,
-                    JSXExpression::StringLiteral(_)
-                        | JSXExpression::NumericLiteral(_)
-                        | JSXExpression::EmptyExpression(_)
-                ),
-                JSXChild::Fragment(_) | JSXChild::Spread(_) => false,
-            };
-            will_create_node.push(creates_node);
-        }
+        let parent_path = path.clone();
 
-        // Second pass: process children and track paths
+        // Process children and track the DOM nodes each one leaves in the template.
+        // Adjacent static text merges into a single text node, expressions only
+        // occupy a node when they need a `<!>` marker.
         let mut num_nodes_added = 0;
+        let mut prev_is_text = false;
+        let mut prev_is_expression = false;
         let mut last_marker_path: Option<Vec<String>> = None;
 
         for (i, child) in element.children.iter().enumerate() {
-            let is_last_child = i == element.children.len() - 1;
-            let is_expression = matches!(child, JSXChild::ExpressionContainer(_));
+            let kind = classify_child(child, comments);
+            if kind == ChildKind::Skipped {
+                continue;
+            }
 
-            // Check if previous child was an expression (to detect adjacent expressions)
-            let prev_is_expression = if i > 0 {
-                matches!(element.children[i - 1], JSXChild::ExpressionContainer(_))
-            } else {
-                false
-            };
+            // The last child that produces output (trailing formatting whitespace doesn't count)
+            let is_last_child = element.children[i + 1..]
+                .iter()
+                .all(|next| classify_child(next, comments) == ChildKind::Skipped);
+
+            // Whether a template node comes after this child, past any expressions
+            let node_follows = element.children[i + 1..].iter().any(|next| {
+                matches!(
+                    classify_child(next, comments),
+                    ChildKind::Text | ChildKind::Element | ChildKind::Comment
+                )
+            });
 
             // Calculate the path for this child based on nodes added so far
-            let mut child_path = Vec::new();
-            if num_nodes_added == 0 {
-                child_path.push("firstChild".to_string());
-            } else {
-                child_path.push("firstChild".to_string());
-                for _ in 0..num_nodes_added {
-                    child_path.push("nextSibling".to_string());
-                }
+            *path = parent_path.clone();
+            path.push("firstChild".to_string());
+            for _ in 0..num_nodes_added {
+                path.push("nextSibling".to_string());
             }
 
-            // Set the path for processing this child
-            *path = child_path.clone();
-
             // Process the child
-            build_child_html_with_context(
+            let added_marker = build_child_html_with_context(
                 child,
                 html,
                 slots,
+                slot_attributes,
                 path,
+                &parent_path,
                 is_last_child,
+                node_follows,
                 prev_is_expression,
                 num_nodes_added,
                 &mut last_marker_path,
-                &element.children,
-                i,
+                options,
+                comments,
             );
 
-            // Update count if this child will create a node (marker or actual content)
-            if will_create_node[i] {
-                num_nodes_added += 1;
+            match kind {
+                ChildKind::Text => {
+                    if !prev_is_text {
+                        num_nodes_added += 1;
+                    }
+                    prev_is_text = true;
+                }
+                ChildKind::Element | ChildKind::Comment => {
+                    num_nodes_added += 1;
+                    prev_is_text = false;
+                }
+                ChildKind::Expression => {
+                    if added_marker {
+                        num_nodes_added += 1;
+                        prev_is_text = false;
+                    }
+                }
+                ChildKind::Skipped => {}
             }
 
+            prev_is_expression = kind == ChildKind::Expression;
+
             // Clear last_marker_path if this wasn't an expression
-            if !is_expression {
+            if !prev_is_expression {
                 last_marker_path = None;
             }
         }
 
         // Restore path
-        path.truncate(child_path_start);
+        *path = parent_path;
 
         // Always add closing tag for standard HTML
         let _ = write!(html, "</{}>", tag_name);
     }
 }
 
+/// What a JSX child contributes to the template
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ChildKind {
+    /// Static text, merged with adjacent static text into one text node
+    Text,
+    /// A host element, component or fragment
+    Element,
+    /// A dynamic expression or spread child inserted at runtime
+    Expression,
+    /// A `{/* ... */}` comment kept as an HTML comment node
+    Comment,
+    /// Formatting whitespace, comments and `{null}`/`{undefined}`
+    Skipped,
+}
+
+/// Classify a JSX child by the template output it produces
+fn classify_child(child: &JSXChild, comments: Option<&JsxComments>) -> ChildKind {
+    match child {
+        JSXChild::Text(text) => {
+            if normalize_text_whitespace(text.value.as_str()).is_empty() {
+                ChildKind::Skipped
+            } else {
+                ChildKind::Text
+            }
+        }
+        JSXChild::Element(_) => ChildKind::Element,
+        JSXChild::ExpressionContainer(container) => match &container.expression {
+            JSXExpression::EmptyExpression(_) => {
+                if container_comment(container.span, comments).is_some() {
+                    ChildKind::Comment
+                } else {
+                    ChildKind::Skipped
+                }
+            }
+            expr if is_nullish_jsx_expression(expr) => ChildKind::Skipped,
+            expr if static_child_text(expr).is_some() => ChildKind::Text,
+            _ => ChildKind::Expression,
+        },
+        JSXChild::Spread(_) => ChildKind::Expression,
+        JSXChild::Fragment(_) => ChildKind::Skipped,
+    }
+}
+
+/// Text of the comments inside a `{/* ... */}` child, joined by a space
+fn container_comment(span: Span, comments: Option<&JsxComments>) -> Option<String> {
+    let texts: Vec<&str> = comments?
+        .iter()
+        .filter(|(comment, _)| comment.start >= span.start && comment.end <= span.end)
+        .map(|(_, text)| text.as_str())
+        .collect();
+    (!texts.is_empty()).then(|| texts.join(" "))
+}
+
 /// Normalize whitespace in text nodes following HTML/DOM rules:
 /// - If text contains only whitespace including newlines, it's formatting whitespace - skip it
 /// - Otherwise, collapse consecutive whitespace (spaces, tabs, newlines) to single space
 /// - Trim whitespace at edges that include newlines (formatting indentation)
 /// - Preserve simple leading/trailing spaces (important for inline text layout)
-fn normalize_text_whitespace(text: &str) -> String {
+pub(crate) fn normalize_text_whitespace(text: &str) -> String {
     // Check if this is pure formatting whitespace (contains newlines and is all whitespace)
     if text.trim().is_empty() && text.contains('\n') {
         return String::new(); // Will be skipped
@@ -644,25 +928,41 @@ fn normalize_text_whitespace(text: &str) -> String {
 }
 
 /// Escape HTML special characters in text content
-/// Only < and & need escaping in text content (> is optional)
-fn escape_html(text: &str) -> String {
-    text.replace('&', "&amp;").replace('<', "&lt;")
+/// Only < and & need escaping in text content (> is optional).
+/// Non-breaking spaces are written as `&nbsp;` like babel keeps them.
+pub(crate) fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('\u{a0}', "&nbsp;")
+}
+
+/// Escape a static attribute value for a double-quoted HTML attribute
+///
+/// Only `&` and `"` need escaping inside the quotes.
+pub(crate) fn escape_attribute_value(value: &str) -> String {
+    value.replace('&', "&amp;").replace('"', "&quot;")
 }
 
 /// Build HTML for a JSX child with context about its position
+///
+/// `path` is the path to the child itself and `parent_path` the path to the
+/// element containing it. Returns true when a `<!>` marker node was added.
 #[allow(clippy::too_many_arguments)] // All parameters are needed for context tracking
 fn build_child_html_with_context(
     child: &JSXChild,
     html: &mut String,
     slots: &mut Vec<DynamicSlot>,
+    slot_attributes: &mut Vec<Span>,
     path: &mut Vec<String>,
+    parent_path: &[String],
     is_last_child: bool,
+    node_follows: bool,
     prev_is_expression: bool,
     num_nodes_so_far: usize,
     last_marker_path: &mut Option<Vec<String>>,
-    _all_children: &[JSXChild],
-    _i: usize,
-) {
+    options: Option<&crate::options::DomExpressionsOptions>,
+    comments: Option<&JsxComments>,
+) -> bool {
     match child {
         JSXChild::Text(text) => {
             // Static text - normalize whitespace following HTML/DOM rules
@@ -673,85 +973,89 @@ fn build_child_html_with_context(
 
             // Skip if text becomes empty after normalization
             if normalized.is_empty() {
-                return;
+                return false;
             }
 
-            // Escape for template literals - only escape opening braces
-            let escaped = normalized.replace('\\', "\\\\").replace('{', "\\{");
-            html.push_str(&escaped);
+            // Decode entities like the JSX runtime would, then escape for HTML
+            html.push_str(&escape_html(&decode_html_entities(&normalized)));
+            return false;
         }
         JSXChild::Element(elem) => {
-            build_element_html(elem, html, slots, path);
+            build_element_html(elem, html, slots, slot_attributes, path, options, comments);
+            return false;
         }
         JSXChild::ExpressionContainer(container) => {
             // Check if this is a static literal that can be inlined
             match &container.expression {
-                JSXExpression::StringLiteral(string_lit) => {
-                    // Static string - include in template with HTML escaping and template literal escaping
-                    let text_value = string_lit.value.as_str();
-
-                    // First escape HTML special characters (< > &)
-                    let html_escaped = escape_html(text_value);
-
-                    // Then escape for template literals (backslash and opening brace)
-                    let escaped = html_escaped.replace('\\', "\\\\").replace('{', "\\{");
-                    html.push_str(&escaped);
-                    return;
+                JSXExpression::EmptyExpression(_) => {
+                    // Empty expression (comment) - kept only when requested
+                    if let Some(text) = container_comment(container.span, comments) {
+                        let _ = write!(html, "<!--{}-->", text);
+                    }
+                    return false;
                 }
-                JSXExpression::NumericLiteral(num_lit) => {
-                    // Static number - include in template
-                    html.push_str(&num_lit.value.to_string());
-                    return;
+                expr if is_nullish_jsx_expression(expr) => {
+                    // {null} and {undefined} render nothing - skip it
+                    return false;
                 }
-                JSXExpression::EmptyExpression(_) => {
-                    // Empty expression (comment) - skip it
-                    return;
+                expr => {
+                    if let Some(text) = static_child_text(expr) {
+                        // Static text - include in template with HTML escaping
+                        html.push_str(&escape_html(&text));
+                        return false;
+                    }
                 }
-                _ => {}
             }
-
-            // Dynamic content - determine marker strategy:
-            // The babel plugin minimizes template size by avoiding markers when possible.
-            // Rules:
-            // 1. Adjacent expressions share one marker
-            // 2. If this is the first NODE (num_nodes_so_far == 0), use next node as insertion point
-            // 3. If expression is LAST child, insert at end with null (no marker)
-            // 4. Otherwise, add a marker after the expression
-
-            // Check if this is the first real node (not counting skipped formatting whitespace)
-            let is_first_node = num_nodes_so_far == 0;
-
-            let marker_path = if prev_is_expression && last_marker_path.is_some() {
-                // Adjacent to previous expression - reuse marker
-                last_marker_path.clone()
-            } else if is_first_node && !is_last_child {
-                // First node but not last child - use next node as insertion point
-                Some(vec!["firstChild".to_string()])
-            } else if is_last_child {
-                // Last child - insert at end
-                None
-            } else {
-                // Middle child - add marker
-                html.push_str("<!>");
-                let marker = Some(path.clone());
-                *last_marker_path = marker.clone();
-                marker
-            };
-
-            slots.push(DynamicSlot {
-                path: Vec::new(), // Insert into parent element (empty path)
-                slot_type: SlotType::TextContent,
-                marker_path,
-            });
         }
-        JSXChild::Fragment(_) | JSXChild::Spread(_) => {
+        // Spread children ({...items}) are inserted like any other expression
+        JSXChild::Spread(_) => {}
+        JSXChild::Fragment(_) => {
             // Not implemented yet
+            return false;
         }
     }
+
+    // Dynamic content - determine marker strategy:
+    // The babel plugin minimizes template size by avoiding markers when possible.
+    // Rules:
+    // 1. Adjacent expressions share one marker
+    // 2. If no node follows the run of expressions, insert at end with null (no marker)
+    // 3. If this is the first NODE (num_nodes_so_far == 0), use next node as insertion point
+    // 4. Otherwise, add a marker after the expression
+
+    // Check if this is the first real node (not counting skipped formatting whitespace)
+    let is_first_node = num_nodes_so_far == 0;
+
+    let mut added_marker = false;
+    let marker_path = if prev_is_expression && last_marker_path.is_some() {
+        // Adjacent to previous expression - reuse marker
+        last_marker_path.clone()
+    } else if !node_follows {
+        // Nothing but expressions until the end - insert at end
+        None
+    } else if is_first_node {
+        // First node - use next node as insertion point
+        Some(path.clone())
+    } else {
+        // Middle child - add marker
+        html.push_str("<!>");
+        added_marker = true;
+        let marker = Some(path.clone());
+        *last_marker_path = marker.clone();
+        marker
+    };
+
+    slots.push(DynamicSlot {
+        path: parent_path.to_vec(), // Insert into parent element
+        slot_type: SlotType::TextContent,
+        marker_path,
+        only_child: is_first_node && is_last_child && !prev_is_expression,
+    });
+    added_marker
 }
 
 /// Get element name from JSX opening element
-fn get_element_name(opening: &JSXOpeningElement) -> String {
+pub(crate) fn get_element_name(opening: &JSXOpeningElement) -> String {
     match &opening.name {
         JSXElementName::Identifier(ident) => ident.name.to_string(),
         JSXElementName::IdentifierReference(ident) => ident.name.to_string(),
@@ -767,7 +1071,7 @@ fn get_element_name(opening: &JSXOpeningElement) -> String {
 }
 
 /// Get attribute name from JSX attribute name
-fn get_attribute_name(name: &JSXAttributeName) -> Option<String> {
+pub(crate) fn get_attribute_name(name: &JSXAttributeName) -> Option<String> {
     match name {
         JSXAttributeName::Identifier(ident) => {
             // Convert JSX attribute names to HTML
@@ -785,10 +1089,214 @@ fn get_attribute_name(name: &JSXAttributeName) -> Option<String> {
     }
 }
 
+/// Get the attributes an element merges into the props of its spread call
+///
+/// From the first spread on, spreads and all attributes that can be spread are
+/// merged in source order, so a later attribute overrides a spread like in JSX.
+pub(crate) fn spread_prop_attributes<'b, 'a>(
+    attributes: &'b [JSXAttributeItem<'a>],
+) -> impl Iterator<Item = &'b JSXAttributeItem<'a>> {
+    attributes
+        .iter()
+        .skip_while(|attr| matches!(attr, JSXAttributeItem::Attribute(_)))
+        .filter(|attr| match attr {
+            JSXAttributeItem::Attribute(attr) => {
+                get_attribute_name(&attr.name).is_some_and(|name| can_native_spread(&name))
+            }
+            JSXAttributeItem::SpreadAttribute(_) => true,
+        })
+}
+
+/// Get the expression bound by an attribute's dynamic slot
+///
+/// String values become string literals, a missing value becomes `true` and
+/// an empty `{}` becomes `undefined`.
+pub(crate) fn attribute_slot_expression<'a>(
+    attr: &JSXAttribute<'a>,
+    allocator: &'a Allocator,
+) -> Expression<'a> {
+    match &attr.value {
+        Some(JSXAttributeValue::ExpressionContainer(container)) => {
+            match container.expression.as_expression() {
+                Some(expr) => expr.clone_in(allocator),
+                None => Expression::Identifier(Box::new_in(
+                    IdentifierReference {
+                        span: SPAN,
+                        name: Atom::from("undefined"),
+                        reference_id: None.into(),
+                    },
+                    allocator,
+                )),
+            }
+        }
+        Some(JSXAttributeValue::StringLiteral(lit)) => {
+            Expression::StringLiteral(lit.clone_in(allocator))
+        }
+        Some(JSXAttributeValue::Element(elem)) => Expression::JSXElement(elem.clone_in(allocator)),
+        Some(JSXAttributeValue::Fragment(frag)) => {
+            Expression::JSXFragment(frag.clone_in(allocator))
+        }
+        None => Expression::BooleanLiteral(Box::new_in(
+            BooleanLiteral {
+                span: SPAN,
+                value: true,
+            },
+            allocator,
+        )),
+    }
+}
+
+/// Get the text a child expression is folded into the template as
+///
+/// String and numeric literals are always folded; with the `opt` feature any
+/// expression that evaluates to a confident string or number is folded too.
+/// Returns the unescaped text, or None if the child needs a runtime insert.
+pub(crate) fn static_child_text(expr: &JSXExpression) -> Option<String> {
+    match expr {
+        JSXExpression::StringLiteral(lit) => Some(lit.value.to_string()),
+        JSXExpression::NumericLiteral(num) => Some(num.value.to_string()),
+        #[cfg(feature = "opt")]
+        _ => {
+            let eval_result = evaluate_expression(expr.as_expression()?);
+            match eval_result.value {
+                Some(value @ (EvaluatedValue::String(_) | EvaluatedValue::Number(_)))
+                    if eval_result.confident =>
+                {
+                    Some(value.to_string())
+                }
+                _ => None,
+            }
+        }
+        #[cfg(not(feature = "opt"))]
+        _ => None,
+    }
+}
+
+/// Class names enabled by a classList object whose values are all known
+///
+/// `classList={{ active: true, hidden: false }}` yields `Some("active")`.
+/// Returns `None` when any key or value is only known at runtime.
+fn static_class_list_classes(attr: &JSXAttribute) -> Option<String> {
+    let Some(JSXAttributeValue::ExpressionContainer(container)) = &attr.value else {
+        return None;
+    };
+    let Some(Expression::ObjectExpression(obj)) = container.expression.as_expression() else {
+        return None;
+    };
+
+    let mut classes = Vec::new();
+    for prop in &obj.properties {
+        let ObjectPropertyKind::ObjectProperty(prop) = prop else {
+            return None;
+        };
+        let class_name = match &prop.key {
+            PropertyKey::StaticIdentifier(ident) if !prop.computed => ident.name.as_str(),
+            PropertyKey::StringLiteral(lit) => lit.value.as_str(),
+            _ => return None,
+        };
+        if is_static_truthy(&prop.value)? {
+            classes.extend(class_name.split_whitespace());
+        }
+    }
+
+    Some(classes.join(" "))
+}
+
+/// Whether a class attribute is written into the template
+fn is_static_class_value(attr: &JSXAttribute) -> bool {
+    match &attr.value {
+        Some(JSXAttributeValue::StringLiteral(_)) => true,
+        #[cfg(feature = "opt")]
+        Some(JSXAttributeValue::ExpressionContainer(container)) => {
+            container.expression.as_expression().is_some_and(|expr| {
+                let eval_result = evaluate_expression(expr);
+                eval_result.confident
+                    && matches!(eval_result.value, Some(EvaluatedValue::String(_)))
+            })
+        }
+        _ => false,
+    }
+}
+
+/// Truthiness of an expression known at compile time
+fn is_static_truthy(expr: &Expression) -> Option<bool> {
+    #[cfg(feature = "opt")]
+    {
+        let eval_result = evaluate_expression(expr);
+        if !eval_result.confident {
+            return None;
+        }
+        match eval_result.value? {
+            EvaluatedValue::Boolean(b) => Some(b),
+            EvaluatedValue::String(s) => Some(!s.is_empty()),
+            EvaluatedValue::Number(n) => Some(n != 0.0 && !n.is_nan()),
+            EvaluatedValue::Null | EvaluatedValue::Undefined => Some(false),
+            EvaluatedValue::Object(_) => Some(true),
+        }
+    }
+    #[cfg(not(feature = "opt"))]
+    {
+        match expr {
+            Expression::BooleanLiteral(lit) => Some(lit.value),
+            _ => None,
+        }
+    }
+}
+
+/// Append folded classList names to a static class value
+fn merge_class_names(class: &str, folded: Option<String>) -> String {
+    match folded {
+        Some(extra) if !extra.is_empty() => {
+            let class = class.trim();
+            if class.is_empty() {
+                extra
+            } else {
+                format!("{} {}", class, extra)
+            }
+        }
+        _ => class.to_string(),
+    }
+}
+
+/// The known value of an innerHTML, textContent or innerText attribute
+///
+/// SVG markup in `innerHTML` is never folded: the template is parsed as HTML,
+/// while assigning `innerHTML` at runtime parses it in the element's SVG
+/// namespace, keeping self-closing tags and foreign elements intact.
+fn static_content_attribute_value(attr: &JSXAttribute, tag_name: &str) -> Option<String> {
+    let is_inner_html =
+        matches!(&attr.name, JSXAttributeName::Identifier(ident) if ident.name == "innerHTML");
+    if is_inner_html && is_svg_element(tag_name) {
+        return None;
+    }
+
+    static_content_value(attr)
+}
+
+/// The known value of a content attribute, unescaped
+pub(crate) fn static_content_value(attr: &JSXAttribute) -> Option<String> {
+    match &attr.value {
+        Some(JSXAttributeValue::StringLiteral(lit)) => Some(lit.value.to_string()),
+        #[cfg(feature = "opt")]
+        Some(JSXAttributeValue::ExpressionContainer(container)) => {
+            let eval_result = evaluate_expression(container.expression.as_expression()?);
+            match eval_result.value {
+                Some(value @ (EvaluatedValue::String(_) | EvaluatedValue::Number(_)))
+                    if eval_result.confident =>
+                {
+                    Some(value.to_string())
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 /// Get static value from JSX attribute value
 fn get_static_attribute_value(value: &JSXAttributeValue) -> Option<String> {
     match value {
-        JSXAttributeValue::StringLiteral(lit) => Some(lit.value.to_string()),
+        JSXAttributeValue::StringLiteral(lit) => Some(decode_html_entities(&lit.value)),
         JSXAttributeValue::ExpressionContainer(container) => {
             // Check if the expression is a static literal
             match &container.expression {
@@ -806,18 +1314,112 @@ fn get_static_attribute_value(value: &JSXAttributeValue) -> Option<String> {
 mod tests {
     use super::*;
 
-    // Note: These are placeholder tests
-    // In a full implementation, we would parse JSX and test template generation
-
     #[test]
     fn test_template_struct() {
         let template = Template {
             html: String::from("<div></div>"),
             dynamic_slots: Vec::new(),
+            slot_attributes: vec![],
         };
         assert_eq!(template.html, "<div></div>");
         assert_eq!(template.dynamic_slots.len(), 0);
     }
+
+    /// Build the template for a single JSX element expression
+    fn template_for(code: &str) -> Template {
+        let allocator = oxc_allocator::Allocator::default();
+        let ret = oxc_parser::Parser::new(&allocator, code, oxc_span::SourceType::jsx()).parse();
+
+        let Some(Statement::ExpressionStatement(stmt)) = ret.program.body.first() else {
+            panic!("expected an expression statement");
+        };
+        let Expression::JSXElement(elem) = &stmt.expression else {
+            panic!("expected a JSX element");
+        };
+        build_template(elem)
+    }
+
+    fn path(segments: &[&str]) -> Vec<String> {
+        segments.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_slot_attributes() {
+        let code = r#"<div id="a" title={t()} class={"x"} textContent="y"><b ref={el} onClick={h} /></div>"#;
+        let template = template_for(code);
+
+        // Only attributes that were not written into the HTML are listed, in slot order
+        let attributes: Vec<_> = template
+            .slot_attributes
+            .iter()
+            .map(|span| &code[span.start as usize..span.end as usize])
+            .collect();
+        assert_eq!(attributes, ["title={t()}", "ref={el}", "onClick={h}"]);
+        assert_eq!(template.dynamic_slots.len(), 3);
+    }
+
+    #[test]
+    fn test_void_element_before_expression() {
+        let template = template_for("<div><input/>{value}</div>");
+
+        assert_eq!(template.html, "<div><input></div>");
+        assert_eq!(template.dynamic_slots.len(), 1);
+        assert_eq!(template.dynamic_slots[0].path, Vec::<String>::new());
+        assert_eq!(template.dynamic_slots[0].marker_path, None);
+    }
+
+    #[test]
+    fn test_void_siblings_before_marker() {
+        let template = template_for(r#"<div><br/><hr/><img src="x"/>{value}<span/></div>"#);
+
+        assert_eq!(
+            template.html,
+            r#"<div><br><hr><img src="x"><!><span></span></div>"#
+        );
+        // Each void element is a single node, so the marker is the fourth child
+        assert_eq!(
+            template.dynamic_slots[0].marker_path,
+            Some(path(&[
+                "firstChild",
+                "nextSibling",
+                "nextSibling",
+                "nextSibling"
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_void_sibling_attribute_path() {
+        let template = template_for("<div><input/><p><input value={v()}/></p></div>");
+
+        assert_eq!(template.dynamic_slots.len(), 1);
+        assert_eq!(
+            template.dynamic_slots[0].path,
+            path(&["firstChild", "nextSibling", "firstChild"])
+        );
+    }
+
+    #[test]
+    fn test_deeply_nested_attribute_path() {
+        let template = template_for("<div><span><b id={x()}/></span></div>");
+
+        assert_eq!(template.dynamic_slots.len(), 1);
+        assert_eq!(
+            template.dynamic_slots[0].path,
+            path(&["firstChild", "firstChild"])
+        );
+    }
+
+    #[test]
+    fn test_adjacent_static_sibling_path() {
+        let template = template_for("<div><a/><b/><c class={x()}/></div>");
+
+        assert_eq!(template.dynamic_slots.len(), 1);
+        assert_eq!(
+            template.dynamic_slots[0].path,
+            path(&["firstChild", "nextSibling", "nextSibling"])
+        );
+    }
 }
 #[cfg(test)]
 mod template_debug {
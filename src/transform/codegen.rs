@@ -8,6 +8,7 @@ use oxc_ast::ast::*;
 use oxc_span::{Atom, SPAN};
 
 use super::DomExpressions;
+use crate::utils::escape_template_literal;
 
 impl<'a> DomExpressions<'a> {
     /// Helper: Create an identifier reference
@@ -77,8 +78,22 @@ impl<'a> DomExpressions<'a> {
     /// Create template declarations for all collected templates
     /// Uses _$template directly from the runtime
     pub(super) fn create_template_declarations(&self) -> Vec<Statement<'a>> {
-        self.template_map
+        use crate::compat::naming::extract_template_counter;
+
+        // Declare templates in creation order; `template_map` iterates in hash order
+        let mut templates: Vec<_> = self
+            .template_map
             .iter()
+            .chain(
+                self.undeduplicated_templates
+                    .iter()
+                    .map(|(html, var)| (html, var)),
+            )
+            .collect();
+        templates.sort_by_key(|(_, var_name)| extract_template_counter(var_name).unwrap_or(0));
+
+        templates
+            .into_iter()
             .map(|(html, var_name)| {
                 // Create template literal
                 let mut quasis = OxcVec::new_in(self.allocator);
@@ -86,7 +101,7 @@ impl<'a> DomExpressions<'a> {
                     span: SPAN,
                     tail: true,
                     value: TemplateElementValue {
-                        raw: Atom::from(self.allocator.alloc_str(html)),
+                        raw: Atom::from(self.allocator.alloc_str(&escape_template_literal(html))),
                         cooked: Some(Atom::from(self.allocator.alloc_str(html))),
                     },
                     lone_surrogates: false,
@@ -103,7 +118,7 @@ impl<'a> DomExpressions<'a> {
                     self.allocator,
                 )));
 
-                let template_call = self.call_expr(self.allocator.alloc_str("_$template"), args);
+                let template_call = self.call_expr(self.runtime_name("template"), args);
                 self.const_decl(self.allocator.alloc_str(var_name.as_str()), template_call)
             })
             .collect()
@@ -188,13 +203,24 @@ impl<'a> DomExpressions<'a> {
     /// Create helper function statements by parsing the JavaScript helper code
     /// Returns just the import statement - we use runtime functions directly
     pub(super) fn create_helper_statements(&self) -> Vec<Statement<'a>> {
-        use super::helper::get_runtime_imports;
+        use super::helper::{get_runtime_imports, get_shared_template_imports};
         use oxc_parser::Parser;
         use oxc_span::SourceType;
         
         // Get the import statement with needed functions
         let imports: Vec<&str> = self.imports_needed.iter().map(|s| s.as_str()).collect();
-        let imports_code_owned = get_runtime_imports(&self.options.module_name, &imports);
+        let mut imports_code_owned = get_runtime_imports(
+            &self.options.module_name,
+            &self.options.import_prefix,
+            &imports,
+        );
+        if let Some(registry) = &self.template_registry {
+            imports_code_owned.push('\n');
+            imports_code_owned.push_str(&get_shared_template_imports(
+                registry.module(),
+                &self.shared_templates,
+            ));
+        }
         
         // Allocate the code in the allocator so it lives as long as 'a
         let imports_code = self.allocator.alloc_str(&imports_code_owned);
@@ -259,7 +285,7 @@ impl<'a> DomExpressions<'a> {
             )));
         }
         
-        let call = self.call_expr(self.allocator.alloc_str("_$insert"), args);
+        let call = self.call_expr(self.runtime_name("insert"), args);
         
         Statement::ExpressionStatement(Box::new_in(
             ExpressionStatement {
@@ -286,7 +312,7 @@ impl<'a> DomExpressions<'a> {
         )));
         set_attr_args.push(Argument::from(value_expr));
         
-        let set_attr_call = self.call_expr(self.allocator.alloc_str("_$setAttribute"), set_attr_args);
+        let set_attr_call = self.call_expr(self.runtime_name("setAttribute"), set_attr_args);
         
         // Wrap in arrow function: () => _$setAttribute(...)
         let mut arrow_body_stmts = OxcVec::new_in(self.allocator);
@@ -330,7 +356,7 @@ impl<'a> DomExpressions<'a> {
         let mut effect_args = OxcVec::new_in(self.allocator);
         effect_args.push(Argument::ArrowFunctionExpression(Box::new_in(arrow_fn, self.allocator)));
         
-        let effect_call = self.call_expr(self.allocator.alloc_str("_$effect"), effect_args);
+        let effect_call = self.call_expr(self.runtime_name("effect"), effect_args);
         
         Statement::ExpressionStatement(Box::new_in(
             ExpressionStatement {
@@ -363,7 +389,7 @@ impl<'a> DomExpressions<'a> {
             self.allocator,
         )));
         
-        let call = self.call_expr(self.allocator.alloc_str("_$addEventListener"), args);
+        let call = self.call_expr(self.runtime_name("addEventListener"), args);
         
         Statement::ExpressionStatement(Box::new_in(
             ExpressionStatement {
@@ -6,31 +6,25 @@
 //! - Only import what we actually use
 
 /// Get import statement for needed runtime functions
-pub fn get_runtime_imports(module_name: &str, imports: &[&str]) -> String {
-    if imports.is_empty() {
-        format!(r#"import {{ template as _$template }} from "{}";"#, module_name)
-    } else {
-        let mut all_imports = vec!["template as _$template"];
-        for imp in imports {
-            all_imports.push(match *imp {
-                "insert" => "insert as _$insert",
-                "effect" => "effect as _$effect",
-                "setAttribute" => "setAttribute as _$setAttribute",
-                "addEventListener" => "addEventListener as _$addEventListener",
-                "delegateEvents" => "delegateEvents as _$delegateEvents",
-                "classList" => "classList as _$classList",
-                "style" => "style as _$style",
-                "spread" => "spread as _$spread",
-                "use" => "use as _$use",
-                _ => imp,
-            });
+///
+/// Each function is imported under its prefixed local name (`insert as _$insert`).
+pub fn get_runtime_imports(module_name: &str, prefix: &str, imports: &[&str]) -> String {
+    let mut all_imports = vec![format!("template as {}template", prefix)];
+    for imp in imports {
+        if *imp != "template" {
+            all_imports.push(format!("{} as {}{}", imp, prefix, imp));
         }
-        format!(
-            r#"import {{ {} }} from "{}";"#,
-            all_imports.join(", "),
-            module_name
-        )
     }
+    format!(
+        r#"import {{ {} }} from "{}";"#,
+        all_imports.join(", "),
+        module_name
+    )
+}
+
+/// Get import statement for templates shared through a registry module
+pub fn get_shared_template_imports(module: &str, names: &[String]) -> String {
+    format!(r#"import {{ {} }} from "{}";"#, names.join(", "), module)
 }
 
 #[cfg(test)]
@@ -39,15 +33,32 @@ mod tests {
 
     #[test]
     fn test_imports_generation() {
-        let imports = get_runtime_imports("solid-js/web", &[]);
+        let imports = get_runtime_imports("solid-js/web", "_$", &[]);
         assert!(imports.contains("solid-js/web"));
         assert!(imports.contains("_$template"));
     }
     
     #[test]
     fn test_imports_with_functions() {
-        let imports = get_runtime_imports("solid-js/web", &["insert", "effect"]);
-        assert!(imports.contains("_$insert"));
-        assert!(imports.contains("_$effect"));
+        let imports = get_runtime_imports("solid-js/web", "_$", &["insert", "effect"]);
+        assert!(imports.contains("insert as _$insert"));
+        assert!(imports.contains("effect as _$effect"));
+    }
+
+    #[test]
+    fn test_imports_with_custom_prefix() {
+        let imports = get_runtime_imports("solid-js/web", "_dom$", &["insert"]);
+        assert!(imports.contains("template as _dom$template"));
+        assert!(imports.contains("insert as _dom$insert"));
+    }
+
+    #[test]
+    fn test_shared_template_imports() {
+        let names = vec!["_tmpl$".to_string(), "_tmpl$3".to_string()];
+        let imports = get_shared_template_imports("virtual:templates", &names);
+        assert_eq!(
+            imports,
+            r#"import { _tmpl$, _tmpl$3 } from "virtual:templates";"#
+        );
     }
 }
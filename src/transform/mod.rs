@@ -14,8 +14,11 @@ use crate::template::Template;
 // Sub-modules
 mod codegen;
 mod helper;
+mod registry;
 mod traverse_impl;
 
+pub use registry::TemplateRegistry;
+
 /// The modern DOM expressions transformer
 pub struct DomExpressions<'a> {
     pub(super) allocator: &'a Allocator,
@@ -24,6 +27,8 @@ pub struct DomExpressions<'a> {
     pub(super) templates: Vec<Template>,
     /// Map of template HTML to variable name for deduplication
     pub(super) template_map: HashMap<String, String>,
+    /// Templates created while deduplication is disabled, as (HTML, variable name)
+    pub(super) undeduplicated_templates: Vec<(String, String)>,
     /// Counter for generating unique template variable names
     pub(super) template_counter: usize,
     /// Optimizer for template analysis
@@ -33,6 +38,12 @@ pub struct DomExpressions<'a> {
     pub(super) helper_injected: bool,
     /// Track which runtime functions we need to import
     pub(super) imports_needed: HashSet<String>,
+    /// Registry that templates are shared through instead of declared locally
+    pub(super) template_registry: Option<TemplateRegistry>,
+    /// Names of the shared templates this file imports, in first-use order
+    pub(super) shared_templates: Vec<String>,
+    /// Registry recording the templates used by this file, and the file's name
+    pub(super) template_usage: Option<(TemplateRegistry, String)>,
 }
 
 impl<'a> DomExpressions<'a> {
@@ -43,19 +54,58 @@ impl<'a> DomExpressions<'a> {
             options,
             templates: Vec::new(),
             template_map: HashMap::new(),
+            undeduplicated_templates: Vec::new(),
             template_counter: 0,
             #[cfg(feature = "opt")]
             optimizer: TemplateOptimizer::new(),
             helper_injected: false,
             imports_needed: HashSet::new(),
+            template_registry: None,
+            shared_templates: Vec::new(),
+            template_usage: None,
+        }
+    }
+
+    /// Create a transformer recording the templates of the file `origin` in a registry (usage mode)
+    ///
+    /// The file keeps declaring its own templates and the registry's module is
+    /// not used; the registry only accumulates usage across transformations so
+    /// [`TemplateRegistry::shared_templates`] can report templates duplicated
+    /// between files. To import templates from the registry instead, use
+    /// [`Self::with_template_registry`].
+    pub fn new_with_registry(
+        allocator: &'a Allocator,
+        options: DomExpressionsOptions,
+        registry: TemplateRegistry,
+        origin: impl Into<String>,
+    ) -> Self {
+        Self {
+            template_usage: Some((registry, origin.into())),
+            ..Self::new(allocator, options)
         }
     }
 
+    /// Share templates through a registry instead of declaring them in the file (sharing mode)
+    ///
+    /// Templates are imported from [`TemplateRegistry::module`] under the name
+    /// the registry assigns them, so files transformed with clones of one
+    /// registry reuse the same template declarations. To only record which
+    /// files use each template, use [`Self::new_with_registry`].
+    pub fn with_template_registry(mut self, registry: TemplateRegistry) -> Self {
+        self.template_registry = Some(registry);
+        self
+    }
+
     /// Get the current options
     pub fn options(&self) -> &DomExpressionsOptions {
         &self.options
     }
 
+    /// Get the templates collected during transformation
+    pub fn templates(&self) -> &[Template] {
+        &self.templates
+    }
+
     /// Get template statistics for optimization analysis
     #[cfg(feature = "opt")]
     pub fn get_template_stats(&self) -> TemplateStats {
@@ -80,6 +130,25 @@ impl<'a> DomExpressions<'a> {
 
     /// Get or create a template variable for given HTML
     pub(super) fn get_template_var(&mut self, html: &str) -> String {
+        if let Some((registry, origin)) = &self.template_usage {
+            registry.record(html, origin);
+        }
+
+        if let Some(registry) = &self.template_registry {
+            let var = registry.register(html);
+            if !self.shared_templates.contains(&var) {
+                self.shared_templates.push(var.clone());
+            }
+            return var;
+        }
+
+        if !self.options.template_dedup {
+            let var = self.generate_template_var();
+            self.undeduplicated_templates
+                .push((html.to_string(), var.clone()));
+            return var;
+        }
+
         if let Some(var) = self.template_map.get(html) {
             var.clone()
         } else {
@@ -89,6 +158,11 @@ impl<'a> DomExpressions<'a> {
         }
     }
     
+    /// Get the local name of a runtime function (e.g. `_$insert`)
+    pub(super) fn runtime_name(&self, name: &str) -> &'a str {
+        self.allocator.alloc_str(&self.options.runtime_name(name))
+    }
+
     /// Mark a runtime function as needed for import
     pub(super) fn add_import(&mut self, name: &str) {
         self.imports_needed.insert(name.to_string());
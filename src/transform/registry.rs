@@ -0,0 +1,189 @@
+//! Templates shared between transformed files
+//!
+//! Build tools transform every file with its own [`DomExpressions`], so each
+//! file normally declares its own copy of a template. Giving the transformers
+//! clones of one [`TemplateRegistry`] assigns every distinct template a single
+//! exported name instead: files import `_tmpl$N` from the registry's module,
+//! and [`TemplateRegistry::module_code`] generates that module once all files
+//! are transformed.
+//!
+//! A registry can also just record which files use each template, through
+//! [`DomExpressions::new_with_registry`], leaving the files' own template
+//! declarations untouched. [`TemplateRegistry::shared_templates`] then reports
+//! the templates duplicated across files.
+//!
+//! [`DomExpressions`]: super::DomExpressions
+//! [`DomExpressions::new_with_registry`]: super::DomExpressions::new_with_registry
+
+use std::sync::{Arc, Mutex};
+
+use crate::options::DomExpressionsOptions;
+use crate::utils::escape_template_literal;
+
+/// A template manifest that persists across transformations
+///
+/// Clones share the same templates, so one registry can be handed to
+/// transformers running on different threads.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateRegistry {
+    /// Module specifier the shared templates are imported from
+    module: String,
+    /// Templates in registration order; a template's index decides its name
+    templates: Arc<Mutex<Vec<RegisteredTemplate>>>,
+}
+
+/// A template and the files it was recorded for
+#[derive(Debug)]
+struct RegisteredTemplate {
+    html: String,
+    origins: Vec<String>,
+}
+
+impl TemplateRegistry {
+    /// Create an empty registry whose templates are imported from `module`
+    pub fn new(module: impl Into<String>) -> Self {
+        Self {
+            module: module.into(),
+            templates: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// The module specifier the shared templates are imported from
+    pub fn module(&self) -> &str {
+        &self.module
+    }
+
+    /// Get the exported name of a template, registering it on first use
+    pub fn register(&self, html: &str) -> String {
+        let mut templates = self.templates.lock().unwrap();
+        template_name(entry_index(&mut templates, html))
+    }
+
+    /// Record that the file `origin` uses a template
+    pub fn record(&self, html: &str, origin: &str) {
+        let mut templates = self.templates.lock().unwrap();
+        let index = entry_index(&mut templates, html);
+        let origins = &mut templates[index].origins;
+        if !origins.iter().any(|existing| existing == origin) {
+            origins.push(origin.to_string());
+        }
+    }
+
+    /// All registered templates as (exported name, HTML), in registration order
+    pub fn templates(&self) -> Vec<(String, String)> {
+        let templates = self.templates.lock().unwrap();
+        templates
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| (template_name(index), entry.html.clone()))
+            .collect()
+    }
+
+    /// Templates recorded for more than one file, as (HTML, files)
+    pub fn shared_templates(&self) -> Vec<(String, Vec<String>)> {
+        let templates = self.templates.lock().unwrap();
+        templates
+            .iter()
+            .filter(|entry| entry.origins.len() > 1)
+            .map(|entry| (entry.html.clone(), entry.origins.clone()))
+            .collect()
+    }
+
+    /// Number of distinct templates registered
+    pub fn len(&self) -> usize {
+        self.templates.lock().unwrap().len()
+    }
+
+    /// Whether no template has been registered
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Source of the shared module exporting every registered template
+    ///
+    /// `template` is imported from the options' runtime module under the
+    /// options' import prefix.
+    pub fn module_code(&self, options: &DomExpressionsOptions) -> String {
+        let template_fn = options.runtime_name("template");
+        let mut code = format!(
+            "import {{ template as {} }} from \"{}\";\n",
+            template_fn, options.module_name
+        );
+        for (name, html) in self.templates() {
+            code.push_str(&format!(
+                "export const {} = /*#__PURE__*/ {}(`{}`);\n",
+                name,
+                template_fn,
+                escape_template_literal(&html)
+            ));
+        }
+        code
+    }
+}
+
+/// Index of a template's entry, adding the entry on first use
+fn entry_index(templates: &mut Vec<RegisteredTemplate>, html: &str) -> usize {
+    match templates.iter().position(|entry| entry.html == html) {
+        Some(index) => index,
+        None => {
+            templates.push(RegisteredTemplate {
+                html: html.to_string(),
+                origins: Vec::new(),
+            });
+            templates.len() - 1
+        }
+    }
+}
+
+/// Exported name of the template at `index`: `_tmpl$`, `_tmpl$2`, ...
+fn template_name(index: usize) -> String {
+    if index == 0 {
+        "_tmpl$".to_string()
+    } else {
+        format!("_tmpl${}", index + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_deduplicates() {
+        let registry = TemplateRegistry::new("virtual:templates");
+        assert_eq!(registry.register("<div>"), "_tmpl$");
+        assert_eq!(registry.register("<span>"), "_tmpl$2");
+        assert_eq!(registry.clone().register("<div>"), "_tmpl$");
+        assert_eq!(registry.len(), 2);
+    }
+
+    #[test]
+    fn test_shared_templates() {
+        let registry = TemplateRegistry::default();
+        registry.record("<div>", "a.jsx");
+        registry.record("<div>", "a.jsx");
+        registry.record("<span>", "a.jsx");
+        registry.record("<div>", "b.jsx");
+
+        assert_eq!(
+            registry.shared_templates(),
+            vec![(
+                "<div>".to_string(),
+                vec!["a.jsx".to_string(), "b.jsx".to_string()]
+            )]
+        );
+    }
+
+    #[test]
+    fn test_module_code() {
+        let registry = TemplateRegistry::new("virtual:templates");
+        registry.register("<div>`a`</div>");
+
+        let code = registry.module_code(&DomExpressionsOptions::new("solid-js/web"));
+        assert_eq!(
+            code,
+            "import { template as _$template } from \"solid-js/web\";\n\
+             export const _tmpl$ = /*#__PURE__*/ _$template(`<div>\\`a\\`</div>`);\n"
+        );
+    }
+}
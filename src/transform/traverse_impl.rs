@@ -6,7 +6,10 @@ use oxc_ast::ast::*;
 use oxc_span::{Atom, SPAN};
 use oxc_traverse::{Traverse, TraverseCtx};
 
-use crate::template::build_template_with_options;
+use crate::template::{
+    attribute_slot_expression, build_template_with_options, static_child_text, Template,
+};
+use crate::utils::is_nullish_jsx_expression;
 
 use super::DomExpressions;
 
@@ -121,7 +124,7 @@ impl<'a> DomExpressions<'a> {
         } else {
             // Has dynamic content - extract expressions and generate binding code
             let mut expressions = Vec::new();
-            self.extract_expressions_from_jsx(jsx_elem, &mut expressions);
+            self.extract_expressions_from_jsx(jsx_elem, &template, &mut expressions);
             
             self.templates.push(template.clone());
             
@@ -179,11 +182,10 @@ impl<'a> DomExpressions<'a> {
                         
                         // For text content, use the slot's path (parent element) and marker
                         let parent_expr = self.navigate_to_element(el_var, &slot.path);
-                        let marker_expr = if let Some(marker_path) = &slot.marker_path {
-                            Some(self.navigate_to_element(el_var, marker_path))
-                        } else {
-                            None
-                        };
+                        let marker_expr = slot
+                            .marker_path
+                            .as_ref()
+                            .map(|marker_path| self.navigate_to_element(el_var, marker_path));
                         
                         let insert_stmt = self.create_insert_call(
                             parent_expr,
@@ -227,52 +229,63 @@ impl<'a> DomExpressions<'a> {
     }
     
     /// Extract expressions from JSX element
-    fn extract_expressions_from_jsx(&self, jsx_elem: &JSXElement<'a>, expressions: &mut Vec<Expression<'a>>) {
+    fn extract_expressions_from_jsx(
+        &self,
+        jsx_elem: &JSXElement<'a>,
+        template: &Template,
+        expressions: &mut Vec<Expression<'a>>,
+    ) {
         use oxc_allocator::CloneIn;
 
         for attr in &jsx_elem.opening_element.attributes {
-            if let JSXAttributeItem::Attribute(attr) = attr {
-                if let Some(JSXAttributeValue::ExpressionContainer(container)) = &attr.value {
-                    match &container.expression {
-                        JSXExpression::StringLiteral(_)
-                        | JSXExpression::NumericLiteral(_)
-                        | JSXExpression::EmptyExpression(_) => {}
-                        expr => {
-                            if let Some(expr_ref) = expr.as_expression() {
-                                expressions.push(expr_ref.clone_in(self.allocator));
-                            }
-                        }
+            match attr {
+                JSXAttributeItem::Attribute(attr) => {
+                    // Only attributes with a dynamic slot contribute an expression
+                    if template.slot_attributes.contains(&attr.span) {
+                        expressions.push(attribute_slot_expression(attr, self.allocator));
+                    }
+                }
+                JSXAttributeItem::SpreadAttribute(spread) => {
+                    // Only the first spread of an element has a slot
+                    if template.slot_attributes.contains(&spread.span) {
+                        expressions.push(spread.argument.clone_in(self.allocator));
                     }
                 }
             }
         }
 
         for child in &jsx_elem.children {
-            self.extract_expressions_from_child(child, expressions);
+            self.extract_expressions_from_child(child, template, expressions);
         }
     }
 
     /// Extract expressions from a JSX child
-    fn extract_expressions_from_child(&self, child: &JSXChild<'a>, expressions: &mut Vec<Expression<'a>>) {
+    fn extract_expressions_from_child(
+        &self,
+        child: &JSXChild<'a>,
+        template: &Template,
+        expressions: &mut Vec<Expression<'a>>,
+    ) {
         use oxc_allocator::CloneIn;
 
         match child {
             JSXChild::Element(elem) => {
-                self.extract_expressions_from_jsx(elem, expressions);
+                self.extract_expressions_from_jsx(elem, template, expressions);
             }
-            JSXChild::ExpressionContainer(container) => {
-                match &container.expression {
-                    JSXExpression::StringLiteral(_)
-                    | JSXExpression::NumericLiteral(_)
-                    | JSXExpression::EmptyExpression(_) => {}
-                    expr => {
-                        if let Some(expr_ref) = expr.as_expression() {
-                            expressions.push(expr_ref.clone_in(self.allocator));
-                        }
+            JSXChild::ExpressionContainer(container) => match &container.expression {
+                JSXExpression::EmptyExpression(_) => {}
+                expr if is_nullish_jsx_expression(expr) => {}
+                expr if static_child_text(expr).is_some() => {}
+                expr => {
+                    if let Some(expr_ref) = expr.as_expression() {
+                        expressions.push(expr_ref.clone_in(self.allocator));
                     }
                 }
+            },
+            JSXChild::Spread(spread) => {
+                expressions.push(spread.expression.clone_in(self.allocator));
             }
-            JSXChild::Text(_) | JSXChild::Fragment(_) | JSXChild::Spread(_) => {}
+            JSXChild::Text(_) | JSXChild::Fragment(_) => {}
         }
     }
 }
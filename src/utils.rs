@@ -31,10 +31,20 @@ pub fn is_html_element(tag_name: &str) -> bool {
 }
 
 /// Check if a tag name is a component (mixed case or capital case)
+///
+/// Hyphenated tags like `<my-widget>` are custom elements and always host elements.
 #[allow(dead_code)] // Used by full implementation
 pub fn is_component(tag_name: &str) -> bool {
     // Components start with uppercase or contain mixed case
-    tag_name.chars().next().is_some_and(|c| c.is_uppercase())
+    !tag_name.contains('-') && tag_name.chars().next().is_some_and(|c| c.is_uppercase())
+}
+
+/// Built-in components that behave like a JSX fragment (`<>...</>`)
+pub const FRAGMENT_COMPONENTS: &[&str] = &["Fragment"];
+
+/// Check if a component name should be treated as a fragment
+pub fn is_fragment_component(tag_name: &str) -> bool {
+    FRAGMENT_COMPONENTS.contains(&tag_name)
 }
 
 /// Check if an attribute is an event handler
@@ -108,6 +118,21 @@ pub fn is_bool_attribute(attr_name: &str) -> bool {
     attr_name.starts_with("bool:") && attr_name.len() > 5
 }
 
+/// Check if an attribute is enumerated with `"true"`/`"false"` values
+///
+/// Unlike boolean attributes, presence alone doesn't mean true for these, so
+/// static booleans are written out as strings.
+pub fn is_enumerated_attribute(attr_name: &str) -> bool {
+    ["contenteditable", "draggable", "spellcheck"]
+        .iter()
+        .any(|name| attr_name.eq_ignore_ascii_case(name))
+}
+
+/// Check if an attribute sets the element's content (`innerHTML`, `textContent`, `innerText`)
+pub fn is_content_attribute(attr_name: &str) -> bool {
+    matches!(attr_name, "innerHTML" | "textContent" | "innerText")
+}
+
 /// Check if an attribute uses prop: prefix
 pub fn is_prop_attribute(attr_name: &str) -> bool {
     attr_name.starts_with("prop:") && attr_name.len() > 5
@@ -133,6 +158,18 @@ pub fn is_class_name_binding(attr_name: &str) -> bool {
     attr_name.starts_with("class:") && attr_name.len() > 6
 }
 
+/// Check if an attribute can be merged into the props of an element's spread
+///
+/// `ref` and the `class:`, `style:`, `use:`, `prop:` and `attr:` namespaces
+/// are compiled on the element even when it has a spread.
+pub fn can_native_spread(attr_name: &str) -> bool {
+    attr_name != "ref"
+        && !matches!(
+            attr_name.split_once(':'),
+            Some(("class" | "style" | "use" | "prop" | "attr", _))
+        )
+}
+
 /// Get the name after a prefix
 pub fn get_prefixed_name(attr_name: &str) -> Option<&str> {
     if let Some(rest) = attr_name.strip_prefix("bool:") {
@@ -153,15 +190,19 @@ pub fn get_prefixed_name(attr_name: &str) -> Option<&str> {
 }
 
 /// Check if an event should be delegated
+///
+/// Delegated handlers run from a document listener, so events that aren't
+/// `composed` never reach them from inside a shadow root.
 #[allow(dead_code)] // Used by full implementation
 pub fn should_delegate_event(event_name: &str) -> bool {
     // List of events that can be safely delegated
     // Based on babel-plugin-jsx-dom-expressions behavior
-    // Events like change, input, blur, focus are NOT delegated as they have issues with delegation
+    // Events like change, blur, focus are NOT delegated as they have issues with delegation
     matches!(
         event_name.to_lowercase().as_str(),
         "click"
             | "dblclick"
+            | "input"
             | "mousedown"
             | "mouseup"
             | "mouseover"
@@ -187,6 +228,14 @@ pub fn should_delegate_event(event_name: &str) -> bool {
     )
 }
 
+/// Check if an event is scroll-related, so its listeners can be passive
+pub fn is_passive_event(event_name: &str) -> bool {
+    matches!(
+        event_name.to_lowercase().as_str(),
+        "scroll" | "touchstart" | "touchmove" | "wheel" | "mousewheel"
+    )
+}
+
 /// List of void elements that don't have closing tags
 #[allow(dead_code)] // Used by full implementation
 pub fn is_void_element(tag_name: &str) -> bool {
@@ -209,6 +258,294 @@ pub fn is_void_element(tag_name: &str) -> bool {
     )
 }
 
+/// Check if a tag name is an SVG element
+///
+/// SVG tag names are case-sensitive (`linearGradient`, `feGaussianBlur`).
+/// Tags shared with HTML (`a`, `script`, `style`, `title`) are treated as HTML.
+pub fn is_svg_element(tag_name: &str) -> bool {
+    matches!(
+        tag_name,
+        "svg"
+            | "animate"
+            | "animateMotion"
+            | "animateTransform"
+            | "circle"
+            | "clipPath"
+            | "defs"
+            | "desc"
+            | "ellipse"
+            | "feBlend"
+            | "feColorMatrix"
+            | "feComponentTransfer"
+            | "feComposite"
+            | "feConvolveMatrix"
+            | "feDiffuseLighting"
+            | "feDisplacementMap"
+            | "feDistantLight"
+            | "feDropShadow"
+            | "feFlood"
+            | "feFuncA"
+            | "feFuncB"
+            | "feFuncG"
+            | "feFuncR"
+            | "feGaussianBlur"
+            | "feImage"
+            | "feMerge"
+            | "feMergeNode"
+            | "feMorphology"
+            | "feOffset"
+            | "fePointLight"
+            | "feSpecularLighting"
+            | "feSpotLight"
+            | "feTile"
+            | "feTurbulence"
+            | "filter"
+            | "foreignObject"
+            | "g"
+            | "image"
+            | "line"
+            | "linearGradient"
+            | "marker"
+            | "mask"
+            | "metadata"
+            | "mpath"
+            | "path"
+            | "pattern"
+            | "polygon"
+            | "polyline"
+            | "radialGradient"
+            | "rect"
+            | "set"
+            | "stop"
+            | "switch"
+            | "symbol"
+            | "text"
+            | "textPath"
+            | "tspan"
+            | "use"
+            | "view"
+    )
+}
+
+/// Split a namespaced attribute (`xlink:href`) into its namespace URI and local name
+///
+/// Only the XML namespaces babel knows about (`xlink` and `xml`) are recognized.
+pub fn get_attribute_namespace(attr_name: &str) -> Option<(&'static str, &str)> {
+    let (prefix, local_name) = attr_name.split_once(':')?;
+    let namespace = match prefix {
+        "xlink" => "http://www.w3.org/1999/xlink",
+        "xml" => "http://www.w3.org/XML/1998/namespace",
+        _ => return None,
+    };
+    Some((namespace, local_name))
+}
+
+/// Map a camelCase SVG attribute in an XML namespace to its namespaced spelling
+///
+/// `xlinkHref` becomes `xlink:href`, `xmlLang` becomes `xml:lang` and
+/// `xmlnsXlink` becomes `xmlns:xlink`. Other names, including camelCase SVG
+/// attributes like `viewBox`, are kept exactly as written.
+pub fn get_svg_attribute_name(attr_name: &str) -> String {
+    for prefix in ["xmlns", "xlink", "xml"] {
+        if let Some(local_name) = attr_name.strip_prefix(prefix) {
+            if local_name.starts_with(|c: char| c.is_ascii_uppercase()) {
+                return format!("{}:{}", prefix, local_name.to_ascii_lowercase());
+            }
+        }
+    }
+    attr_name.to_string()
+}
+
+/// Get the DOM property a host-element attribute is set through, if any
+///
+/// Mirrors babel's `Properties` set: `value` and the boolean attributes are
+/// assigned as properties (`el.checked = value`) instead of via `setAttribute`.
+/// Some attributes are only properties on specific elements (`max` on `<progress>`).
+pub fn get_dom_property_name(tag_name: &str, attr_name: &str) -> Option<&'static str> {
+    Some(match (tag_name, attr_name) {
+        ("progress", "max") => "max",
+        (_, attr_name) => return get_global_dom_property_name(attr_name),
+    })
+}
+
+/// Check if an attribute is a native boolean attribute, true when present
+///
+/// These are the properties of [`get_dom_property_name`] other than `value`,
+/// like `checked`, `disabled` or `open` on `<dialog>` and `<details>`.
+pub fn is_boolean_dom_attribute(attr_name: &str) -> bool {
+    attr_name != "value" && get_global_dom_property_name(attr_name).is_some()
+}
+
+/// Properties shared by all host elements
+fn get_global_dom_property_name(attr_name: &str) -> Option<&'static str> {
+    Some(match attr_name {
+        "value" => "value",
+        "checked" => "checked",
+        "selected" => "selected",
+        "disabled" => "disabled",
+        "multiple" => "multiple",
+        "muted" => "muted",
+        "indeterminate" => "indeterminate",
+        "readonly" | "readOnly" => "readOnly",
+        "required" => "required",
+        "hidden" => "hidden",
+        "open" => "open",
+        "autofocus" => "autofocus",
+        "autoplay" => "autoplay",
+        "controls" => "controls",
+        "loop" => "loop",
+        "default" => "default",
+        "async" => "async",
+        "inert" => "inert",
+        "reversed" => "reversed",
+        "seamless" => "seamless",
+        "allowfullscreen" => "allowFullscreen",
+        "formnovalidate" | "formNoValidate" => "formNoValidate",
+        "novalidate" | "noValidate" => "noValidate",
+        "ismap" | "isMap" => "isMap",
+        "nomodule" | "noModule" => "noModule",
+        "playsinline" | "playsInline" => "playsInline",
+        _ => return None,
+    })
+}
+
+/// Check if an expression can change between evaluations
+///
+/// Like babel's `isDynamic`, calls and member accesses are dynamic while
+/// identifiers, literals and function expressions are not. Expressions that
+/// await are never dynamic: they can't be moved into a thunk, and the awaited
+/// value is only read once anyway.
+pub fn is_dynamic_expression(expr: &Expression) -> bool {
+    !contains_await_expression(expr) && has_dynamic_parts(expr)
+}
+
+fn has_dynamic_parts(expr: &Expression) -> bool {
+    match expr {
+        Expression::CallExpression(_)
+        | Expression::NewExpression(_)
+        | Expression::TaggedTemplateExpression(_)
+        | Expression::StaticMemberExpression(_)
+        | Expression::ComputedMemberExpression(_)
+        | Expression::PrivateFieldExpression(_)
+        | Expression::ChainExpression(_)
+        | Expression::AwaitExpression(_)
+        | Expression::YieldExpression(_) => true,
+        Expression::ParenthesizedExpression(paren) => has_dynamic_parts(&paren.expression),
+        Expression::UnaryExpression(unary) => has_dynamic_parts(&unary.argument),
+        Expression::BinaryExpression(binary) => {
+            has_dynamic_parts(&binary.left) || has_dynamic_parts(&binary.right)
+        }
+        Expression::LogicalExpression(logical) => {
+            has_dynamic_parts(&logical.left) || has_dynamic_parts(&logical.right)
+        }
+        Expression::ConditionalExpression(cond) => {
+            has_dynamic_parts(&cond.test)
+                || has_dynamic_parts(&cond.consequent)
+                || has_dynamic_parts(&cond.alternate)
+        }
+        Expression::SequenceExpression(seq) => seq.expressions.iter().any(has_dynamic_parts),
+        Expression::TemplateLiteral(tpl) => tpl.expressions.iter().any(has_dynamic_parts),
+        Expression::ArrayExpression(array) => array.elements.iter().any(|elem| match elem {
+            ArrayExpressionElement::SpreadElement(spread) => has_dynamic_parts(&spread.argument),
+            ArrayExpressionElement::Elision(_) => false,
+            _ => elem.as_expression().is_some_and(has_dynamic_parts),
+        }),
+        Expression::ObjectExpression(obj) => obj.properties.iter().any(|prop| match prop {
+            ObjectPropertyKind::ObjectProperty(p) => {
+                (p.computed && p.key.as_expression().is_some_and(has_dynamic_parts))
+                    || has_dynamic_parts(&p.value)
+            }
+            ObjectPropertyKind::SpreadProperty(spread) => has_dynamic_parts(&spread.argument),
+        }),
+        _ => false,
+    }
+}
+
+/// Check if an expression awaits outside of any nested function
+///
+/// Such expressions must stay in the enclosing async scope, so they can't be
+/// wrapped in accessors and the element IIFE around them has to be async.
+pub fn contains_await_expression(expr: &Expression) -> bool {
+    match expr {
+        Expression::AwaitExpression(_) => true,
+        Expression::ParenthesizedExpression(paren) => contains_await_expression(&paren.expression),
+        Expression::UnaryExpression(unary) => contains_await_expression(&unary.argument),
+        Expression::BinaryExpression(binary) => {
+            contains_await_expression(&binary.left) || contains_await_expression(&binary.right)
+        }
+        Expression::LogicalExpression(logical) => {
+            contains_await_expression(&logical.left) || contains_await_expression(&logical.right)
+        }
+        Expression::ConditionalExpression(cond) => {
+            contains_await_expression(&cond.test)
+                || contains_await_expression(&cond.consequent)
+                || contains_await_expression(&cond.alternate)
+        }
+        Expression::AssignmentExpression(assign) => contains_await_expression(&assign.right),
+        Expression::SequenceExpression(seq) => {
+            seq.expressions.iter().any(contains_await_expression)
+        }
+        Expression::TemplateLiteral(tpl) => tpl.expressions.iter().any(contains_await_expression),
+        Expression::TaggedTemplateExpression(tagged) => {
+            contains_await_expression(&tagged.tag)
+                || tagged
+                    .quasi
+                    .expressions
+                    .iter()
+                    .any(contains_await_expression)
+        }
+        Expression::CallExpression(call) => {
+            contains_await_expression(&call.callee)
+                || call.arguments.iter().any(argument_contains_await)
+        }
+        Expression::NewExpression(new) => {
+            contains_await_expression(&new.callee)
+                || new.arguments.iter().any(argument_contains_await)
+        }
+        Expression::StaticMemberExpression(member) => contains_await_expression(&member.object),
+        Expression::ComputedMemberExpression(member) => {
+            contains_await_expression(&member.object)
+                || contains_await_expression(&member.expression)
+        }
+        Expression::ChainExpression(chain) => match &chain.expression {
+            ChainElement::CallExpression(call) => {
+                contains_await_expression(&call.callee)
+                    || call.arguments.iter().any(argument_contains_await)
+            }
+            ChainElement::TSNonNullExpression(non_null) => {
+                contains_await_expression(&non_null.expression)
+            }
+            member => member
+                .as_member_expression()
+                .is_some_and(|member| contains_await_expression(member.object())),
+        },
+        Expression::ArrayExpression(array) => array.elements.iter().any(|elem| match elem {
+            ArrayExpressionElement::SpreadElement(spread) => {
+                contains_await_expression(&spread.argument)
+            }
+            ArrayExpressionElement::Elision(_) => false,
+            _ => elem.as_expression().is_some_and(contains_await_expression),
+        }),
+        Expression::ObjectExpression(obj) => obj.properties.iter().any(|prop| match prop {
+            ObjectPropertyKind::ObjectProperty(p) => {
+                (p.computed && p.key.as_expression().is_some_and(contains_await_expression))
+                    || contains_await_expression(&p.value)
+            }
+            ObjectPropertyKind::SpreadProperty(spread) => {
+                contains_await_expression(&spread.argument)
+            }
+        }),
+        _ => false,
+    }
+}
+
+fn argument_contains_await(arg: &Argument) -> bool {
+    match arg {
+        Argument::SpreadElement(spread) => contains_await_expression(&spread.argument),
+        _ => arg.as_expression().is_some_and(contains_await_expression),
+    }
+}
+
 /// Check if an expression contains only static/literal values
 /// Returns true for literals (strings, numbers, booleans, null), false for anything else
 pub fn is_static_expression(expr: &Expression) -> bool {
@@ -247,6 +584,16 @@ pub fn is_static_expression(expr: &Expression) -> bool {
     }
 }
 
+/// Check if a JSX expression is a literal `null` or `undefined`
+/// Such children render nothing, so they need no marker or insert call
+pub fn is_nullish_jsx_expression(expr: &JSXExpression) -> bool {
+    match expr {
+        JSXExpression::NullLiteral(_) => true,
+        JSXExpression::Identifier(ident) => ident.name == "undefined",
+        _ => false,
+    }
+}
+
 /// Check if a JSX attribute value is fully static
 pub fn is_static_jsx_attribute_value(value: &JSXAttributeValue) -> bool {
     match value {
@@ -302,8 +649,69 @@ pub fn static_style_object_to_css(expr: &Expression) -> Option<String> {
     }
 }
 
+/// Normalize a static inline style string
+/// Example: "color: red;  font-size : 12px;" => "color:red;font-size:12px"
+///
+/// Whitespace around property names and values is removed and empty declarations
+/// are dropped, so equivalent styles written differently produce the same template.
+/// Semicolons and colons inside quotes or parentheses (e.g. `url(a;b)`) are preserved.
+pub fn normalize_style_string(css: &str) -> String {
+    let mut declarations = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+    let mut paren_depth = 0usize;
+
+    for ch in css.chars() {
+        match quote {
+            Some(q) => {
+                if ch == q {
+                    quote = None;
+                }
+            }
+            None => match ch {
+                '"' | '\'' => quote = Some(ch),
+                '(' => paren_depth += 1,
+                ')' => paren_depth = paren_depth.saturating_sub(1),
+                ';' if paren_depth == 0 => {
+                    declarations.push(std::mem::take(&mut current));
+                    continue;
+                }
+                _ => {}
+            },
+        }
+        current.push(ch);
+    }
+    declarations.push(current);
+
+    declarations
+        .iter()
+        .filter_map(|declaration| {
+            let declaration = declaration.trim();
+            if declaration.is_empty() {
+                return None;
+            }
+            Some(match declaration.split_once(':') {
+                Some((name, value)) => format!("{}:{}", name.trim(), value.trim()),
+                None => declaration.to_string(),
+            })
+        })
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+/// Escape template HTML for the raw value of a template literal
+///
+/// Backslashes, backticks and `${` would otherwise end the literal or start a
+/// substitution. Like the babel plugin, every `{` is escaped.
+pub fn escape_template_literal(html: &str) -> String {
+    html.replace('\\', "\\\\")
+        .replace('`', "\\`")
+        .replace('{', "\\{")
+}
+
 /// Decode HTML entities to their Unicode equivalents
-/// This is needed for component/fragment children where HTML entities should be decoded
+/// JSX text is decoded like the JSX runtime would: component/fragment children become
+/// string literals and host-element text is re-escaped into the template
 pub fn decode_html_entities(text: &str) -> String {
     let mut result = String::with_capacity(text.len());
     let mut chars = text.chars().peekable();
@@ -337,6 +745,29 @@ pub fn decode_html_entities(text: &str) -> String {
                     "apos" => '\'',
                     "nbsp" => '\u{A0}',     // non-breaking space
                     "hellip" => '\u{2026}', // horizontal ellipsis
+                    "copy" => '\u{A9}',
+                    "reg" => '\u{AE}',
+                    "trade" => '\u{2122}',
+                    "deg" => '\u{B0}',
+                    "plusmn" => '\u{B1}',
+                    "times" => '\u{D7}',
+                    "divide" => '\u{F7}',
+                    "middot" => '\u{B7}',
+                    "bull" => '\u{2022}',
+                    "sect" => '\u{A7}',
+                    "para" => '\u{B6}',
+                    "cent" => '\u{A2}',
+                    "pound" => '\u{A3}',
+                    "yen" => '\u{A5}',
+                    "euro" => '\u{20AC}',
+                    "ndash" => '\u{2013}',
+                    "mdash" => '\u{2014}',
+                    "lsquo" => '\u{2018}',
+                    "rsquo" => '\u{2019}',
+                    "ldquo" => '\u{201C}',
+                    "rdquo" => '\u{201D}',
+                    "laquo" => '\u{AB}',
+                    "raquo" => '\u{BB}',
                     // Numeric entities
                     _ if entity.starts_with('#') => {
                         if let Some(num_str) = entity.strip_prefix('#') {
@@ -406,12 +837,137 @@ mod tests {
         assert!(!is_html_element("Component"));
     }
 
+    #[test]
+    fn test_is_enumerated_attribute() {
+        assert!(is_enumerated_attribute("contentEditable"));
+        assert!(is_enumerated_attribute("draggable"));
+        assert!(!is_enumerated_attribute("disabled"));
+    }
+
+    #[test]
+    fn test_escape_template_literal() {
+        assert_eq!(escape_template_literal("<b>a</b>"), "<b>a</b>");
+        assert_eq!(escape_template_literal("`${x}`"), "\\`$\\{x}\\`");
+        assert_eq!(escape_template_literal("a\\b {c}"), "a\\\\b \\{c}");
+    }
+
     #[test]
     fn test_is_component() {
         assert!(is_component("MyComponent"));
         assert!(is_component("Component"));
         assert!(!is_component("div"));
         assert!(!is_component("span"));
+        assert!(!is_component("my-widget"));
+        assert!(!is_component("My-widget"));
+    }
+
+    #[test]
+    fn test_is_svg_element() {
+        assert!(is_svg_element("svg"));
+        assert!(is_svg_element("path"));
+        assert!(is_svg_element("linearGradient"));
+        assert!(!is_svg_element("lineargradient"));
+        assert!(!is_svg_element("div"));
+        assert!(!is_svg_element("a"));
+    }
+
+    #[test]
+    fn test_get_prefixed_name_keeps_custom_properties() {
+        assert_eq!(get_prefixed_name("style:color"), Some("color"));
+        assert_eq!(get_prefixed_name("style:--my-var"), Some("--my-var"));
+    }
+
+    #[test]
+    fn test_get_svg_attribute_name() {
+        assert_eq!(get_svg_attribute_name("xlinkHref"), "xlink:href");
+        assert_eq!(get_svg_attribute_name("xmlnsXlink"), "xmlns:xlink");
+        assert_eq!(get_svg_attribute_name("xmlLang"), "xml:lang");
+        assert_eq!(get_svg_attribute_name("viewBox"), "viewBox");
+        assert_eq!(get_svg_attribute_name("xmlns"), "xmlns");
+        assert_eq!(get_svg_attribute_name("xlink:href"), "xlink:href");
+    }
+
+    #[test]
+    fn test_get_attribute_namespace() {
+        assert_eq!(
+            get_attribute_namespace("xlink:href"),
+            Some(("http://www.w3.org/1999/xlink", "href"))
+        );
+        assert_eq!(
+            get_attribute_namespace("xml:lang"),
+            Some(("http://www.w3.org/XML/1998/namespace", "lang"))
+        );
+        assert_eq!(get_attribute_namespace("on:click"), None);
+        assert_eq!(get_attribute_namespace("href"), None);
+    }
+
+    #[test]
+    fn test_get_dom_property_name() {
+        assert_eq!(get_dom_property_name("input", "value"), Some("value"));
+        assert_eq!(get_dom_property_name("input", "checked"), Some("checked"));
+        assert_eq!(get_dom_property_name("input", "readonly"), Some("readOnly"));
+        assert_eq!(get_dom_property_name("progress", "max"), Some("max"));
+        assert_eq!(get_dom_property_name("input", "max"), None);
+        assert_eq!(get_dom_property_name("div", "id"), None);
+        assert_eq!(get_dom_property_name("div", "class"), None);
+    }
+
+    #[test]
+    fn test_is_boolean_dom_attribute() {
+        assert!(is_boolean_dom_attribute("open"));
+        assert!(is_boolean_dom_attribute("disabled"));
+        assert!(!is_boolean_dom_attribute("value"));
+        assert!(!is_boolean_dom_attribute("id"));
+    }
+
+    #[test]
+    fn test_is_nullish_jsx_expression() {
+        let allocator = oxc_allocator::Allocator::default();
+        let source = "<div>{null}{undefined}{value}{0}</div>";
+        let ret = oxc_parser::Parser::new(&allocator, source, oxc_span::SourceType::jsx()).parse();
+
+        let Some(Statement::ExpressionStatement(stmt)) = ret.program.body.first() else {
+            panic!("expected an expression statement");
+        };
+        let Expression::JSXElement(elem) = &stmt.expression else {
+            panic!("expected a JSX element");
+        };
+
+        let nullish: Vec<bool> = elem
+            .children
+            .iter()
+            .map(|child| match child {
+                JSXChild::ExpressionContainer(container) => {
+                    is_nullish_jsx_expression(&container.expression)
+                }
+                _ => false,
+            })
+            .collect();
+        assert_eq!(nullish, vec![true, true, false, false]);
+    }
+
+    #[test]
+    fn test_normalize_style_string() {
+        assert_eq!(
+            normalize_style_string("color: red; font-size: 12px"),
+            "color:red;font-size:12px"
+        );
+        assert_eq!(
+            normalize_style_string(" color:red ;; font-size :12px; "),
+            "color:red;font-size:12px"
+        );
+        assert_eq!(
+            normalize_style_string("background: url(\"a;b.png\"); color: blue"),
+            "background:url(\"a;b.png\");color:blue"
+        );
+        assert_eq!(normalize_style_string(""), "");
+    }
+
+    #[test]
+    fn test_is_fragment_component() {
+        assert!(is_fragment_component("Fragment"));
+        assert!(!is_fragment_component("Comp"));
+        assert!(!is_fragment_component("fragment"));
     }
 
     #[test]
@@ -429,6 +985,15 @@ mod tests {
         assert_eq!(get_event_name("class"), None);
     }
 
+    #[test]
+    fn test_is_passive_event() {
+        assert!(is_passive_event("TouchMove"));
+        assert!(is_passive_event("scroll"));
+        assert!(is_passive_event("wheel"));
+        assert!(!is_passive_event("click"));
+        assert!(!is_passive_event("touchend"));
+    }
+
     #[test]
     fn test_should_delegate_event() {
         // Events that should be delegated
@@ -438,13 +1003,19 @@ mod tests {
         assert!(should_delegate_event("keydown"));
 
         // Events that should NOT be delegated
-        assert!(!should_delegate_event("input"));
         assert!(!should_delegate_event("change"));
         assert!(!should_delegate_event("blur"));
         assert!(!should_delegate_event("focus"));
         assert!(!should_delegate_event("customEvent"));
     }
 
+    #[test]
+    fn test_should_delegate_input_event() {
+        // Input bubbles and is composed, so babel delegates it
+        assert!(should_delegate_event("input"));
+        assert!(should_delegate_event("Input"));
+    }
+
     #[test]
     fn test_is_void_element() {
         assert!(is_void_element("br"));
@@ -479,6 +1050,10 @@ mod tests {
         );
         assert_eq!(decode_html_entities("&amp;&lt;&gt;&quot;&apos;"), "&<>\"'");
         assert_eq!(decode_html_entities("Search&hellip;"), "Search\u{2026}");
+        assert_eq!(
+            decode_html_entities("&copy; 2024&mdash;"),
+            "\u{A9} 2024\u{2014}"
+        );
         assert_eq!(decode_html_entities("plain text"), "plain text");
         assert_eq!(decode_html_entities("&#60;&#62;"), "<>");
         assert_eq!(decode_html_entities("&#x3C;&#x3E;"), "<>");
@@ -0,0 +1,118 @@
+//! Entry point for WASM and N-API wrappers
+//!
+//! Bundler plugins pass the babel plugin's options as JSON, so
+//! [`transform_sync`] takes them as a string and returns the generated code:
+//!
+//! ```rust
+//! use oxc_dom_expressions::transform_sync;
+//!
+//! let code = transform_sync(
+//!     "const view = <div>{count()}</div>;",
+//!     r#"{ "moduleName": "solid-js/web", "delegateEvents": false }"#,
+//! )
+//! .unwrap();
+//!
+//! assert!(code.contains("_$insert"));
+//! ```
+
+use oxc_span::SourceType;
+
+use crate::api::transform;
+use crate::options::DomExpressionsOptions;
+
+/// Transform JSX source with options given as JSON
+///
+/// Options use camelCase field names and fall back to their defaults when
+/// omitted. Returns the generated code, or a message for invalid options or
+/// parse errors.
+pub fn transform_sync(source: &str, options_json: &str) -> Result<String, String> {
+    let options: DomExpressionsOptions =
+        serde_json::from_str(options_json).map_err(|e| format!("Invalid options: {}", e))?;
+
+    let output = transform(source, SourceType::jsx(), options);
+    if output.errors.is_empty() {
+        Ok(output.code)
+    } else {
+        Err(output.errors.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::options::{GenerateMode, OutputStyle, PureCommentStyle, VarKind};
+
+    #[test]
+    fn test_options_from_json() {
+        let json = r#"{
+            "moduleName": "r-dom",
+            "generate": "ssr",
+            "hydratable": true,
+            "delegateEvents": false,
+            "passiveEvents": true,
+            "builtIns": ["For", "Show"],
+            "customElementProperties": ["value"],
+            "importPrefix": "_dom$",
+            "requireInScripts": false,
+            "mergedImports": true,
+            "minifyNames": true,
+            "omitOptionalTags": true,
+            "collapseWhitespace": true,
+            "preserveComments": true,
+            "outputStyle": "oxc",
+            "pureComment": "babel",
+            "templateDedup": false,
+            "sourcemap": true,
+            "templateDeclKind": "const"
+        }"#;
+        let options: DomExpressionsOptions = serde_json::from_str(json).unwrap();
+
+        let expected = DomExpressionsOptions::new("r-dom")
+            .with_generate(GenerateMode::Ssr)
+            .with_hydratable(true)
+            .with_delegate_events(false)
+            .with_passive_events(true)
+            .with_built_ins(vec!["For".to_string(), "Show".to_string()])
+            .with_custom_element_properties(vec!["value".to_string()])
+            .with_import_prefix("_dom$")
+            .with_require_in_scripts(false)
+            .with_merged_imports(true)
+            .with_minify_names(true)
+            .with_omit_optional_tags(true)
+            .with_collapse_whitespace(true)
+            .with_preserve_comments(true)
+            .with_output_style(OutputStyle::Oxc)
+            .with_pure_comment(PureCommentStyle::Babel)
+            .with_template_dedup(false)
+            .with_sourcemap(true)
+            .with_template_decl_kind(VarKind::Const);
+
+        assert_eq!(format!("{:?}", options), format!("{:?}", expected));
+    }
+
+    #[test]
+    fn test_missing_options_use_defaults() {
+        let options: DomExpressionsOptions = serde_json::from_str("{}").unwrap();
+        assert_eq!(
+            format!("{:?}", options),
+            format!("{:?}", DomExpressionsOptions::default())
+        );
+    }
+
+    #[test]
+    fn test_transform_sync() {
+        let code = transform_sync(
+            "const view = <div onClick={select}>{count()}</div>;",
+            r#"{ "moduleName": "r-dom", "delegateEvents": false }"#,
+        )
+        .unwrap();
+        assert!(code.contains(r#"from "r-dom""#));
+        assert!(!code.contains("delegateEvents"));
+
+        let error = transform_sync("const view = <div>", "{}").unwrap_err();
+        assert!(!error.is_empty());
+
+        let error = transform_sync("", r#"{ "generate": "html" }"#).unwrap_err();
+        assert!(error.starts_with("Invalid options"));
+    }
+}
@@ -0,0 +1,206 @@
+//! Snapshot harness running the babel-plugin-jsx-dom-expressions fixtures
+//! through the babel-compatible (compat2) transformer
+//!
+//! Every fixture directory under `tests/fixtures/{dom,hydratable,ssr}` is
+//! transformed with the options of the babel plugin's spec for that category
+//! and compared against its `output.js`. The run prints a pass/fail report per
+//! fixture and fails when a fixture outside `KNOWN_FAILING` breaks, when the
+//! output of a known-failing fixture differs from its snapshot under
+//! `tests/snapshots/compat2`, or when a known-failing fixture starts passing and
+//! should be removed from the list.
+
+use oxc_dom_expressions::{transform, DomExpressionsOptions, GenerateMode};
+use oxc_span::SourceType;
+use similar::TextDiff;
+use std::fs;
+use std::path::PathBuf;
+
+/// Fixtures whose output doesn't match babel yet, as `category/name`
+const KNOWN_FAILING: &[&str] = &[
+    "dom/SVG",
+    "dom/attributeExpressions",
+    "dom/components",
+    "dom/conditionalExpressions",
+    "dom/customElements",
+    "dom/eventExpressions",
+    "dom/fragments",
+    "dom/insertChildren",
+    "dom/namespaceElements",
+    "dom/textInterpolation",
+    "hydratable/SVG",
+    "hydratable/attributeExpressions",
+    "hydratable/components",
+    "hydratable/conditionalExpressions",
+    "hydratable/customElements",
+    "hydratable/document",
+    "hydratable/eventExpressions",
+    "hydratable/flags",
+    "hydratable/fragments",
+    "hydratable/insertChildren",
+    "hydratable/textInterpolation",
+    "ssr/SVG",
+    "ssr/attributeExpressions",
+    "ssr/components",
+    "ssr/conditionalExpressions",
+    "ssr/customElements",
+    "ssr/fragments",
+    "ssr/insertChildren",
+    "ssr/simpleElements",
+    "ssr/textInterpolation",
+];
+
+/// Options matching the babel plugin's spec file for a fixture category
+fn options_for(category: &str) -> DomExpressionsOptions {
+    let built_ins = vec!["For".to_string(), "Show".to_string()];
+    match category {
+        "ssr" => DomExpressionsOptions::new("r-server")
+            .with_generate(GenerateMode::Ssr)
+            .with_built_ins(built_ins),
+        "hydratable" => DomExpressionsOptions::new("r-dom")
+            .with_generate(GenerateMode::Hydratable)
+            .with_built_ins(built_ins),
+        _ => DomExpressionsOptions::new("r-dom").with_built_ins(built_ins),
+    }
+}
+
+/// Normalize code so only meaningful differences remain
+///
+/// Outside string literals, whitespace, quote style and the counters of
+/// generated names such as `_el$2` or `_tmpl$3` are ignored. String and
+/// template literal contents are kept as they are.
+fn normalize(code: &str) -> String {
+    let mut normalized = String::with_capacity(code.len());
+    let mut chars = code.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' | '\'' | '`' => {
+                normalized.push(if ch == '\'' { '"' } else { ch });
+                while let Some(inner) = chars.next() {
+                    if inner == ch {
+                        break;
+                    }
+                    normalized.push(inner);
+                    if inner == '\\' {
+                        normalized.extend(chars.next());
+                    }
+                }
+                normalized.push(if ch == '\'' { '"' } else { ch });
+            }
+            // Generated names end in `$` and an optional counter
+            '$' if normalized.ends_with(|c: char| c.is_ascii_alphabetic())
+                && generated_name_start(&normalized) =>
+            {
+                normalized.push('$');
+                while chars.next_if(char::is_ascii_digit).is_some() {}
+            }
+            _ if ch.is_whitespace() => {}
+            _ => normalized.push(ch),
+        }
+    }
+    normalized
+}
+
+/// Whether the identifier at the end of `code` starts with an underscore
+fn generated_name_start(code: &str) -> bool {
+    let ident = code.trim_end_matches(|c: char| c.is_ascii_alphanumeric());
+    ident.ends_with('_')
+        && !ident[..ident.len() - 1].ends_with(|c: char| c.is_ascii_alphanumeric() || c == '$')
+}
+
+/// Checked-in output of a known-failing fixture
+///
+/// Known failures still have their actual output pinned, so unrelated changes
+/// to them show up. Set `UPDATE_SNAPSHOTS=1` to rewrite the snapshots.
+fn snapshot_path(category: &str, name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots/compat2")
+        .join(category)
+        .join(format!("{}.js", name))
+}
+
+/// All fixture directories as `(category, name, path)`, sorted
+fn fixtures() -> Vec<(String, String, PathBuf)> {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let mut fixtures = Vec::new();
+    for category in ["dom", "hydratable", "ssr"] {
+        let entries = fs::read_dir(root.join(category))
+            .unwrap_or_else(|e| panic!("Failed to read fixtures for {}: {}", category, e));
+        for entry in entries {
+            let path = entry.unwrap().path();
+            if path.join("code.js").exists() {
+                let name = path.file_name().unwrap().to_string_lossy().into_owned();
+                fixtures.push((category.to_string(), name, path));
+            }
+        }
+    }
+    fixtures.sort();
+    fixtures
+}
+
+#[test]
+fn test_babel_fixtures() {
+    let mut regressions = Vec::new();
+    let mut changed = Vec::new();
+    let mut fixed = Vec::new();
+
+    for (category, name, path) in fixtures() {
+        let id = format!("{}/{}", category, name);
+        let code = fs::read_to_string(path.join("code.js")).unwrap();
+        let expected = fs::read_to_string(path.join("output.js")).unwrap();
+
+        let output = transform(&code, SourceType::jsx(), options_for(&category));
+        let passed = output.errors.is_empty() && normalize(&output.code) == normalize(&expected);
+        let known_failing = KNOWN_FAILING.contains(&id.as_str());
+
+        println!("{} {}", if passed { "PASS" } else { "FAIL" }, id);
+        match (passed, known_failing) {
+            (false, false) => {
+                let diff = TextDiff::from_lines(expected.as_str(), output.code.as_str());
+                println!("{}", diff.unified_diff().header("expected", "actual"));
+                regressions.push(id);
+            }
+            (false, true) => {
+                // Fixtures that don't parse pin their errors instead
+                let actual = if output.errors.is_empty() {
+                    output.code.clone()
+                } else {
+                    output
+                        .errors
+                        .iter()
+                        .map(|error| format!("// {}\n", error))
+                        .collect()
+                };
+                let snapshot = snapshot_path(&category, &name);
+                if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+                    fs::create_dir_all(snapshot.parent().unwrap()).unwrap();
+                    fs::write(&snapshot, &actual).unwrap();
+                    continue;
+                }
+                let pinned = fs::read_to_string(&snapshot).unwrap_or_default();
+                if pinned != actual {
+                    let diff = TextDiff::from_lines(pinned.as_str(), actual.as_str());
+                    println!("{}", diff.unified_diff().header("snapshot", "actual"));
+                    changed.push(id);
+                }
+            }
+            (true, true) => fixed.push(id),
+            (true, false) => {}
+        }
+    }
+
+    assert!(
+        regressions.is_empty(),
+        "fixtures no longer match babel: {:?}",
+        regressions
+    );
+    assert!(
+        changed.is_empty(),
+        "known-failing fixtures changed, check the diffs and update their snapshots: {:?}",
+        changed
+    );
+    assert!(
+        fixed.is_empty(),
+        "fixtures now pass, remove them from KNOWN_FAILING: {:?}",
+        fixed
+    );
+}
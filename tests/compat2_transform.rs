@@ -0,0 +1,2231 @@
+//! Tests for the babel-compatible (compat2) transformer
+//!
+//! These tests run small JSX snippets through `DomExpressionsCompat2` and
+//! check the generated code for the runtime calls and templates we expect.
+
+use oxc_allocator::Allocator;
+use oxc_codegen::Codegen;
+use oxc_dom_expressions::{
+    DomExpressions, DomExpressionsCompat2, DomExpressionsOptions, DynamicSlot, GenerateMode,
+    Severity, SlotType, Template, VarKind,
+};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_traverse::traverse_mut;
+
+/// Transform JSX source with the given options and return the generated code
+fn transform(source: &str, options: DomExpressionsOptions) -> String {
+    let source_type = SourceType::default().with_jsx(true).with_module(true);
+    transform_source(source, source_type, options)
+}
+
+fn transform_source(
+    source: &str,
+    source_type: SourceType,
+    options: DomExpressionsOptions,
+) -> String {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source, source_type).parse();
+    assert!(ret.errors.is_empty(), "Parse errors: {:?}", ret.errors);
+
+    let mut program = ret.program;
+    let semantic = SemanticBuilder::new().build(&program).semantic;
+    let scoping = semantic.into_scoping();
+
+    let mut transformer = DomExpressionsCompat2::new(&allocator, options);
+    traverse_mut(&mut transformer, &allocator, &mut program, scoping, ());
+
+    Codegen::new().build(&program).code
+}
+
+#[test]
+fn test_hydratable_mode() {
+    let source = r#"
+        const template = <div id="main">Hello</div>;
+        const dynamic = <div>{count()}</div>;
+    "#;
+
+    let dom = transform(source, DomExpressionsOptions::new("r-dom"));
+    assert!(dom.contains("_tmpl$()"));
+    assert!(!dom.contains("getNextElement"));
+
+    let options = DomExpressionsOptions::new("r-dom").with_generate(GenerateMode::Hydratable);
+    let hydratable = transform(source, options);
+
+    assert!(hydratable.contains(r#"import { getNextElement as _$getNextElement } from "r-dom";"#));
+    assert!(hydratable.contains("const template = _$getNextElement(_tmpl$);"));
+    assert!(hydratable.contains("var _el$ = _$getNextElement(_tmpl$2)"));
+    assert!(!hydratable.contains("_tmpl$()"));
+    // Templates are still declared as DOM templates
+    assert!(hydratable.contains("_$template(`<div id=main>Hello`)"));
+}
+
+#[test]
+fn test_hydratable_flag_with_dom_mode() {
+    let source = r#"const template = <div>Hello</div>;"#;
+
+    let options = DomExpressionsOptions::new("r-dom").with_hydratable(true);
+    let output = transform(source, options);
+
+    assert!(output.contains("const template = _$getNextElement(_tmpl$);"));
+}
+
+#[test]
+fn test_fragment_component() {
+    let source = r#"
+        import { Fragment } from "solid-js";
+        const multiple = <Fragment>{a}{b}</Fragment>;
+        const nested = <Comp><Fragment>{a}</Fragment></Comp>;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains("const multiple = [a, b];"));
+    assert!(output.contains("_$createComponent(Comp, { children: a })"));
+    assert!(!output.contains("_$createComponent(Fragment"));
+}
+
+#[test]
+fn test_ssr_dynamic_content() {
+    let source = r#"
+        const view = <div id={id()} class={cls()} onClick={handler}><span>{name()}</span> and {count}</div>;
+    "#;
+
+    let dom = transform(source, DomExpressionsOptions::new("r-dom"));
+    assert!(dom.contains("_$insert("));
+    assert!(dom.contains(r#"_$setAttribute(_el$, "id", id())"#));
+
+    let options = DomExpressionsOptions::new("r-server").with_generate(GenerateMode::Ssr);
+    let ssr = transform(source, options);
+
+    // The template is split at every dynamic position
+    for part in [
+        r#""<div""#,
+        r#"" class=\"""#,
+        r#""\"><span>""#,
+        r#""</span> and ""#,
+        r#""</div>""#,
+    ] {
+        assert!(ssr.contains(part), "missing template part {}", part);
+    }
+    assert!(ssr.contains(
+        r#"_$ssr(_tmpl$, _$ssrAttribute("id", _$escape(id(), true), false), _$escape(cls(), true), _$escape(name()), _$escape(count))"#
+    ));
+    assert!(ssr.contains(r#"import { escape as _$escape } from "r-server";"#));
+    assert!(ssr.contains(r#"import { ssrAttribute as _$ssrAttribute } from "r-server";"#));
+
+    // No DOM-oriented runtime calls on the server
+    assert!(!ssr.contains("_$insert"));
+    assert!(!ssr.contains("_$setAttribute"));
+    assert!(!ssr.contains("_$effect"));
+    assert!(!ssr.contains("delegateEvents"));
+}
+
+#[test]
+fn test_ssr_static_and_style() {
+    let source = r#"
+        const plain = <div id="main">Hello</div>;
+        const styled = <div style={{ color: color() }}>Hi</div>;
+    "#;
+
+    let options = DomExpressionsOptions::new("r-server").with_generate(GenerateMode::Ssr);
+    let ssr = transform(source, options);
+
+    assert!(ssr.contains(r#"_tmpl$ = "<div id=\"main\">Hello</div>""#));
+    assert!(ssr.contains("const plain = _$ssr(_tmpl$);"));
+    assert!(ssr.contains(r#"["<div style=\"", "\">Hi</div>"]"#));
+    assert!(ssr.contains("_$ssr(_tmpl$2, _$ssrStyle({ color: color() }))"));
+}
+
+#[test]
+fn test_ssr_content_attributes() {
+    let source = r#"
+        const markup = <div innerHTML="<b>x</b>" />;
+        const text = <p textContent={"a<b"} />;
+        const html = <div innerHTML={html()} />;
+        const bound = <div textContent={t()} />;
+        const inner = <span innerText={label} />;
+    "#;
+
+    let options = DomExpressionsOptions::new("r-server").with_generate(GenerateMode::Ssr);
+    let ssr = transform(source, options);
+
+    // Known values are folded into the template, innerHTML as raw markup
+    assert!(ssr.contains(r#""<div><b>x</b></div>""#), "{}", ssr);
+    assert!(ssr.contains(r#""<p>a&lt;b</p>""#), "{}", ssr);
+    // Dynamic values become the content, text properties escaped
+    assert!(ssr.contains(r#"["<div>", "</div>"]"#), "{}", ssr);
+    assert!(ssr.contains("_$ssr(_tmpl$3, html())"), "{}", ssr);
+    assert!(ssr.contains("_$ssr(_tmpl$3, _$escape(t()))"), "{}", ssr);
+    assert!(ssr.contains("_$escape(label)"), "{}", ssr);
+    assert!(!ssr.contains("innerHTML"), "{}", ssr);
+    assert!(!ssr.contains("textContent"), "{}", ssr);
+    assert!(!ssr.contains("innerText"), "{}", ssr);
+    assert!(!ssr.contains("ssrAttribute"), "{}", ssr);
+}
+
+#[test]
+fn test_static_style_string_normalization() {
+    let source = r#"
+        const a = <div style="color: red; font-size: 12px">A</div>;
+        const b = <div style="color:red;font-size:12px;">A</div>;
+        const c = <div style={"color : red ;font-size: 12px"}>A</div>;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    // All three spellings share one template
+    assert!(output.contains(r#"_$template(`<div style="color:red;font-size:12px">A`)"#));
+    assert!(!output.contains("_tmpl$2"));
+
+    let options = DomExpressionsOptions::new("r-server").with_generate(GenerateMode::Ssr);
+    let ssr = transform(source, options);
+    assert!(ssr.contains(r#"_tmpl$ = "<div style=\"color:red;font-size:12px\">A</div>""#));
+    assert!(!ssr.contains("_tmpl$2"));
+}
+
+#[test]
+fn test_nullish_children_render_nothing() {
+    let source = r#"
+        const a = <div>{null}</div>;
+        const b = <div>{undefined}</div>;
+        const c = <div>{null}<span />{x}</div>;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains("const a = _tmpl$();"));
+    assert!(output.contains("const b = _tmpl$();"));
+    assert!(!output.contains("_$insert(_el$, null"));
+    assert!(!output.contains("_$insert(_el$, undefined"));
+    assert!(output.contains("_$insert(_el$, x"));
+    assert!(output.contains("_$template(`<div><span>`)"));
+
+    let options = DomExpressionsOptions::new("r-server").with_generate(GenerateMode::Ssr);
+    let ssr = transform(source, options);
+    assert!(ssr.contains(r#"_tmpl$ = "<div></div>""#));
+    assert!(!ssr.contains("_$escape(null)"));
+    assert!(!ssr.contains("_$escape(undefined)"));
+}
+
+#[test]
+fn test_svg_template_namespace() {
+    let source = r#"
+        const icon = <svg viewBox="0 0 10 10"><g><path d={d()} /></g></svg>;
+        const shape = <rect width="10" />;
+        const wrapped = <div><svg /></div>;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    // An <svg> root is parsed in the SVG namespace on its own
+    assert!(output.contains(r#"_$template(`<svg viewBox="0 0 10 10"><g><path>`)"#));
+    // Other SVG roots are wrapped in <svg> and flagged with isSVG
+    assert!(output.contains("_$template(`<svg><rect width=10></svg>`, false, true, false)"));
+    // Only the root element decides the namespace
+    assert!(output.contains("_$template(`<div><svg>`)"));
+    // The nested path child is reached through the g element
+    assert!(output.contains("_el$1 = _el$.firstChild, _el$2 = _el$1.firstChild"));
+    assert!(output.contains(r#"_$setAttribute(_el$2, "d", d())"#));
+}
+
+#[test]
+fn test_custom_event_handlers() {
+    let source = r#"
+        const a = <div onMyCustomEvent={h} />;
+        const b = <div onMyCustomEvent={() => go()} onClick={() => c()} />;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    // Unknown events are never delegated and use the lowercase event name
+    assert!(output.contains(r#"_$addEventListener(_el$, "mycustomevent", h)"#));
+    assert!(output.contains(r#"_el$1.addEventListener("mycustomevent", () => go())"#));
+    assert!(output.contains("_el$1.$$click = () => c()"));
+    assert!(output.contains(r#"_$delegateEvents(["click"])"#));
+}
+
+#[test]
+fn test_boolean_literal_attributes() {
+    let source = r#"
+        const on = <input disabled={true} />;
+        const off = <input disabled={false} title={t()} />;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains("_$template(`<input disabled>`)"));
+    assert!(output.contains("_$template(`<input>`)"));
+    assert!(!output.contains("disabled=true"));
+    assert!(!output.contains("disabled=false"));
+    // Inlined attributes don't shift the expressions of the remaining bindings
+    assert!(output.contains(r#"_$setAttribute(_el$, "title", t())"#));
+
+    let options = DomExpressionsOptions::new("r-server").with_generate(GenerateMode::Ssr);
+    let ssr = transform(source, options);
+    assert!(ssr.contains(r#"_tmpl$ = "<input disabled>""#));
+    assert!(ssr.contains(r#"["<input", ">"]"#));
+}
+
+#[test]
+fn test_host_text_entities_are_decoded() {
+    let source = r#"
+        const nbsp = <div>a&nbsp;b</div>;
+        const amp = <div>Tom &amp; Jerry</div>;
+        const hex = <div>it&#x27;s</div>;
+        const mixed = <div>&copy; 2024&#8212;{year}</div>;
+        const literal = <div>{"&amp;"}</div>;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains("_$template(`<div>a&nbsp;b`)"));
+    // Decoded characters that are special in HTML are escaped again
+    assert!(output.contains("_$template(`<div>Tom &amp; Jerry`)"));
+    assert!(output.contains("_$template(`<div>it's`)"));
+    assert!(output.contains("_$template(`<div>\u{a9} 2024\u{2014}`)"));
+    // JS string literals are not entity-decoded
+    assert!(output.contains("_$template(`<div>&amp;amp;`)"));
+
+    let options = DomExpressionsOptions::new("r-server").with_generate(GenerateMode::Ssr);
+    let ssr = transform(source, options);
+    assert!(ssr.contains(r#""<div>Tom &amp; Jerry</div>""#));
+    assert!(ssr.contains(r#""<div>it's</div>""#));
+}
+
+#[test]
+fn test_label_wrapping_input() {
+    let source = r#"const field = <label>Name: <input value={v()} /></label>;"#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains("_$template(`<label>Name: <input>`)"));
+    // The input follows the text node
+    assert!(output.contains("_el$1 = _el$.firstChild, _el$2 = _el$1.nextSibling"));
+    assert!(output.contains("_$effect(() => _el$2.value = v())"));
+    assert!(!output.contains("_$setAttribute"));
+}
+
+#[test]
+fn test_custom_import_prefix() {
+    let source = r#"
+        const view = <div id={id()} onClick={() => go()}>{count()}</div>;
+        const comp = <Comp />;
+    "#;
+
+    let options = DomExpressionsOptions::new("r-dom").with_import_prefix("_dom$");
+    let output = transform(source, options);
+
+    for import in [
+        "template as _dom$template",
+        "insert as _dom$insert",
+        "effect as _dom$effect",
+        "setAttribute as _dom$setAttribute",
+        "createComponent as _dom$createComponent",
+        "delegateEvents as _dom$delegateEvents",
+    ] {
+        assert!(output.contains(import), "missing import {}", import);
+    }
+    assert!(output.contains("_dom$template(`"));
+    assert!(output.contains(r#"_dom$effect(() => _dom$setAttribute(_el$, "id", id()))"#));
+    assert!(output.contains("_dom$insert(_el$, count"));
+    assert!(output.contains("_dom$createComponent(Comp, {})"));
+    assert!(output.contains("_dom$delegateEvents(["));
+    assert!(!output.contains("_$"));
+
+    let options = DomExpressionsOptions::new("r-server")
+        .with_generate(GenerateMode::Ssr)
+        .with_import_prefix("_dom$");
+    let ssr = transform(source, options);
+    assert!(ssr.contains("_dom$ssr(_tmpl$"));
+    assert!(ssr.contains("_dom$escape("));
+    assert!(!ssr.contains("_$"));
+}
+
+#[test]
+fn test_conditional_class_attribute() {
+    let source = r#"const view = <div class={a() ? "x" : "y"} />;"#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains("_$template(`<div>`)"));
+    assert!(output.contains(r#"_$effect(() => _$setAttribute(_el$, "class", a() ? "x" : "y"))"#));
+}
+
+#[test]
+fn test_script_source_uses_require() {
+    let source = r#"const view = <div onClick={handler}>{count()}</div>;"#;
+    let script = SourceType::default().with_jsx(true).with_script(true);
+
+    let output = transform_source(source, script, DomExpressionsOptions::new("r-dom"));
+    assert!(!output.contains("import "));
+    assert!(output.contains(r#"const { template: _$template } = require("r-dom");"#));
+    assert!(output.contains(r#"const { insert: _$insert } = require("r-dom");"#));
+    assert!(output.contains("_$delegateEvents(["));
+
+    let options = DomExpressionsOptions::new("r-dom").with_require_in_scripts(false);
+    let output = transform_source(source, script, options);
+    assert!(!output.contains("import "));
+    assert!(!output.contains("require("));
+    assert!(output.contains("_$template(`<div>`)"));
+}
+
+#[test]
+fn test_merged_imports() {
+    let source = r#"const view = <div onClick={handler}>{count()}</div>;"#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+    assert!(output.contains(r#"import { template as _$template } from "r-dom";"#));
+    assert!(output.contains(r#"import { insert as _$insert } from "r-dom";"#));
+
+    let options = DomExpressionsOptions::new("r-dom").with_merged_imports(true);
+    let output = transform(source, options);
+    assert_eq!(output.matches("import ").count(), 1);
+    assert!(output.contains(
+        "import { template as _$template, delegateEvents as _$delegateEvents, insert as _$insert"
+    ));
+    assert!(output.contains(r#"} from "r-dom";"#));
+
+    let script = SourceType::default().with_jsx(true).with_script(true);
+    let options = DomExpressionsOptions::new("r-dom").with_merged_imports(true);
+    let output = transform_source(source, script, options);
+    assert_eq!(output.matches("require(").count(), 1);
+    assert!(output.contains(r#"template: _$template"#));
+}
+
+#[test]
+fn test_progress_numeric_properties() {
+    let source = r#"const view = <progress value={v()} max={m()} />;"#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains("_$template(`<progress>`)"));
+    assert!(output.contains("_$effect(() => _el$.value = v())"));
+    assert!(output.contains("_$effect(() => _el$.max = m())"));
+    assert!(!output.contains("setAttribute"));
+}
+
+#[test]
+fn test_single_text_child() {
+    let source = r#"
+        const folded = <div>{1 + 2}{"a" + "b"}</div>;
+        const dynamic = <div>{count()}</div>;
+        const trailing = <div><span />{count()}</div>;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains("_$template(`<div>3ab`)"));
+    assert!(output.contains("const folded = _tmpl$();"));
+    assert!(output.contains("_$insert(_el$, count);"));
+    assert!(!output.contains("_el$.firstChild,"));
+    assert!(output.contains(", count, null);"));
+}
+
+#[test]
+fn test_ref_inside_for_callback() {
+    let source = r#"const list = <For each={items()}>{item => <li ref={r}>{item}</li>}</For>;"#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains("_$createComponent(For, {"));
+    assert!(output.contains("children: (item) => (() => {"));
+    assert!(output.contains(r#"typeof _ref$ === "function" ? _$use(_ref$, _el$) : r = _el$;"#));
+    assert!(output.contains("_$insert(_el$, item);"));
+    assert!(output.contains(r#"import { use as _$use } from "r-dom";"#));
+}
+
+#[test]
+fn test_namespaced_svg_attribute() {
+    let source = r#"const icon = <svg><use xlink:href={href()} /></svg>;"#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains("_$template(`<svg><use>`)"));
+    assert!(output.contains(
+        r#"_$effect(() => _$setAttributeNS(_el$1, "http://www.w3.org/1999/xlink", "href", href()))"#
+    ));
+    assert!(output.contains(r#"import { setAttributeNS as _$setAttributeNS } from "r-dom";"#));
+    assert!(!output.contains("_$setAttribute("));
+}
+
+#[test]
+fn test_use_directive_with_value() {
+    let source = r#"
+        const input = <input use:model={value} />;
+        const bare = <div use:tooltip />;
+        const accessor = <div use:drag={() => opts} />;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains(r#"import { use as _$use } from "r-dom";"#));
+    assert!(output.contains("_$use(model, _el$, () => value);"));
+    assert!(output.contains("_$use(tooltip, _el$1, () => true);"));
+    assert!(output.contains("_$use(drag, _el$2, () => opts);"));
+}
+
+#[test]
+fn test_typed_event_handlers_in_tsx() {
+    let source = r#"
+        const button = (
+            <button on:input={(e: InputEvent) => log(e as any)} onClick={(e: MouseEvent): void => go(e)}>
+                x
+            </button>
+        );
+    "#;
+
+    let output = transform_source(
+        source,
+        SourceType::tsx(),
+        DomExpressionsOptions::new("r-dom"),
+    );
+
+    // Type annotations are left in place for the TypeScript stage to erase
+    assert!(
+        output.contains(r#"_$addEventListener(_el$, "input", (e: InputEvent) => log(e as any));"#)
+    );
+    assert!(output.contains("_el$.$$click = (e: MouseEvent): void => go(e);"));
+}
+
+#[test]
+fn test_minify_names() {
+    let source = r#"
+        const a = 1;
+        function f(b) {
+            return <div><span>{b}</span>{c()}</div>;
+        }
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+    assert!(output.contains("var _tmpl$ = /* @__PURE__ */ _$template(`<div><span>`);"));
+    assert!(output.contains("var _el$ = _tmpl$(), _el$1 = _el$.firstChild;"));
+
+    let options = DomExpressionsOptions::new("r-dom").with_minify_names(true);
+    let output = transform(source, options);
+    // a, b, c and f are taken by the program
+    assert!(output.contains("var d = /* @__PURE__ */ _$template(`<div><span>`);"));
+    assert!(output.contains("var e = d(), g = e.firstChild;"));
+    assert!(output.contains("_$insert(g, b);"));
+    assert!(output.contains("_$insert(e, c, null);"));
+    assert!(!output.contains("_el$"));
+    assert!(!output.contains("_tmpl$"));
+}
+
+#[test]
+fn test_style_properties() {
+    let source = r#"const view = <div style:color={color()} style:background-color={bg} />;"#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains(r#"_$effect(() => _$setStyleProperty(_el$, "color", color()));"#));
+    assert!(output.contains(r#"_$setStyleProperty(_el$, "background-color", bg);"#));
+
+    // Custom properties keep their leading dashes
+    let source = r#"const view = <div style={{ "--my-var": size() }} style:width={width} />;"#;
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+    assert!(output.contains(r#""--my-var": size()"#));
+    assert!(output.contains(r#"_$setStyleProperty(_el$, "width", width);"#));
+}
+
+#[test]
+fn test_class_name_toggles() {
+    let source = r#"
+        const view = <div class="base" class:active={isActive()} class:done={done} class:open={state.open} />;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains("_$template(`<div class=base>`)"));
+    let active = output
+        .find(r#"_$effect(() => _$className(_el$, "active", isActive()));"#)
+        .expect("reactive toggle");
+    let done = output
+        .find(r#"_$className(_el$, "done", done);"#)
+        .expect("static toggle");
+    let open = output
+        .find(r#"_$effect(() => _$className(_el$, "open", state.open));"#)
+        .expect("member toggle");
+    assert!(active < done && done < open);
+}
+
+#[test]
+fn test_static_class_list_folds_into_class() {
+    let source = r#"
+        const merged = <div class="base" classList={{ extra: true, off: false }} />;
+        const alone = <div classList={{ "a b": 1 }} />;
+        const reactive = <div class="base" classList={{ on: on() }} />;
+        const styled = <div class="base" style={{ color: "red" }} />;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains(r#"_$template(`<div class="base extra">`)"#));
+    assert!(output.contains(r#"_$template(`<div class="a b">`)"#));
+    assert!(output.contains("_$template(`<div class=base>`)"));
+    assert!(output.contains("_$template(`<div class=base style=color:red>`)"));
+    assert!(output.contains("_$classList(_el$, { on: on() });"));
+    assert_eq!(output.matches("_$classList(").count(), 1);
+}
+
+#[test]
+fn test_static_class_list_with_dynamic_class() {
+    let source = r#"
+        const view = <div classList={{ b: true }} class={dyn()} />;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    // The dynamic class replaces the template's class, so classList stays a
+    // runtime binding
+    assert!(output.contains("_$template(`<div>`)"), "{}", output);
+    assert!(
+        output.contains("_$classList(_el$, { b: true });"),
+        "{}",
+        output
+    );
+    assert!(output.contains("dyn()"), "{}", output);
+}
+
+#[test]
+fn test_multiple_element_spreads() {
+    let source = r#"
+        const view = <div {...a} {...b} />;
+        const between = <div {...first} middle={m} {...second} />;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains("_$mergeProps(a, b)"), "{}", output);
+    // Attributes between spreads keep their place in the merged props
+    assert!(
+        output.contains("_$mergeProps(first, { middle: m }, second)"),
+        "{}",
+        output
+    );
+    assert_eq!(output.matches("_$spread(").count(), 2);
+    assert!(!output.contains("_$setAttribute("));
+}
+
+#[test]
+fn test_attributes_after_element_spread() {
+    let source = r#"
+        const view = <div start="Hi" {...props} title="x" data-id={id()} hidden ref={el} />;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    // Attributes before the first spread stay in the template, later ones
+    // override the spread props
+    assert!(
+        output.contains("_$template(`<div start=Hi>`)"),
+        "{}",
+        output
+    );
+    assert!(output.contains("_$mergeProps(props, {"), "{}", output);
+    assert!(output.contains(r#"title: "x","#), "{}", output);
+    assert!(output.contains(r#"get "data-id"() {"#), "{}", output);
+    assert!(output.contains("hidden: true"), "{}", output);
+    // ref is never passed to spread
+    assert!(
+        output.contains("typeof _ref$ === \"function\""),
+        "{}",
+        output
+    );
+}
+
+#[test]
+fn test_reactive_component_props_use_getters() {
+    let source = r#"
+        const view = <Counter literal={5} ident={x} call={count()} member={state.value} />;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains("literal: 5,"));
+    assert!(output.contains("ident: x,"));
+    assert!(output.contains("get call() {\n\t\treturn count();\n\t}"));
+    assert!(output.contains("get member() {\n\t\treturn state.value;\n\t}"));
+}
+
+#[test]
+fn test_reactive_component_prop_expressions_use_getters() {
+    let source = r#"
+        const view = <Counter
+            value={count() + 1}
+            other={x ? y() : z}
+            label={`${count()} items`}
+            sum={a + b}
+            pick={x ? y : z}
+        />;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    // Anything that reads a call or member access is wrapped, like insert values
+    assert!(
+        output.contains("get value() {\n\t\treturn count() + 1;\n\t}"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("get other() {\n\t\treturn x ? y() : z;\n\t}"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("get label() {\n\t\treturn `${count()} items`;\n\t}"),
+        "{}",
+        output
+    );
+    assert!(output.contains("sum: a + b,"), "{}", output);
+    assert!(output.contains("pick: x ? y : z"), "{}", output);
+}
+
+#[test]
+fn test_component_event_props_are_plain_props() {
+    let source = r#"
+        const view = <Button onClick={handle} on:custom={custom} />;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains("onClick: handle,"));
+    assert!(output.contains(r#""on:custom": custom"#));
+    assert!(!output.contains("delegateEvents"));
+    assert!(!output.contains("addEventListener"));
+}
+
+#[test]
+fn test_entity_only_text() {
+    let source = r#"
+        const host = <div>&nbsp;</div>;
+        const component = <Comp>&nbsp;</Comp>;
+        const fragment = <>&nbsp;</>;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    // Host templates keep the entity, JS strings get the decoded character
+    assert!(output.contains("_$template(`<div>&nbsp;`)"));
+    assert!(output.contains(r#"children: "\xA0""#));
+    assert!(output.contains(r#"const fragment = "\xA0";"#));
+
+    let options = DomExpressionsOptions::new("r-server").with_generate(GenerateMode::Ssr);
+    let ssr = transform(source, options);
+    assert!(ssr.contains(r#""<div>&nbsp;</div>""#));
+}
+
+#[test]
+fn test_space_between_expressions_is_kept() {
+    let source = r#"
+        const greet = <span>{greeting} {name}</span>;
+        const more = <span>{greeting} {name}<b /></span>;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains("_$template(`<span> `)"));
+    assert!(output.contains("var _el$ = _tmpl$(), _el$1 = _el$.firstChild;"));
+    assert!(output.contains("_$insert(_el$, greeting, _el$1);"));
+    assert!(output.contains("_$insert(_el$, name, null);"));
+
+    // A following element gets its own marker after the space
+    assert!(output.contains("_$template(`<span> <!><b>`)"));
+    assert!(output.contains("_el$3 = _el$2.firstChild, _el$4 = _el$3.nextSibling;"));
+    assert!(output.contains("_$insert(_el$2, greeting, _el$3);"));
+    assert!(output.contains("_$insert(_el$2, name, _el$4);"));
+}
+
+#[test]
+fn test_consecutive_expressions_share_insertion_point() {
+    let source = r#"
+        const two = <div>{a}{b}</div>;
+        const three = <div>{a}{b}{c}</div>;
+        const four = <div>{a}{b}{c}{d}</div>;
+        const before = <div>{a}{b}{c}<b /></div>;
+        const between = <div><i />{a}{b}{c}<b />text<u /></div>;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    // Without a following node every expression is appended at the end
+    for name in ["a", "b", "c", "d"] {
+        assert!(output.contains(&format!("_$insert(_el$4, {}, null);", name)));
+    }
+    assert!(output.contains("_$insert(_el$, b, null);"));
+    assert!(output.contains("_$insert(_el$2, c, null);"));
+
+    // A run before a node is inserted before that node
+    assert!(output.contains("_$template(`<div><b>`)"));
+    for name in ["a", "b", "c"] {
+        assert!(output.contains(&format!("_$insert(_el$6, {}, _el$7);", name)));
+    }
+
+    // A run between nodes shares one marker, later siblings keep their paths
+    assert!(output.contains("_$template(`<div><i></i><!><b></b>text<u>`)"));
+    assert!(output.contains("_el$9 = _el$8.firstChild, _el$10 = _el$9.nextSibling;"));
+    for name in ["a", "b", "c"] {
+        assert!(output.contains(&format!("_$insert(_el$8, {}, _el$10);", name)));
+    }
+}
+
+#[test]
+fn test_form_attributes() {
+    let source = r#"
+        const view = <form action={url()} method="post"><button type="submit" /></form>;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains("_$template(`<form method=post><button type=submit>`)"));
+    assert!(output.contains(r#"_$effect(() => _$setAttribute(_el$, "action", url()));"#));
+}
+
+#[test]
+fn test_ref_and_use_directive_on_one_element() {
+    let source = r#"
+        const view = <div ref={r} use:clickOutside={cb}><span use:tip={t} ref={s} /></div>;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    let calls = [
+        "_$use(_ref$, _el$) : r = _el$;",
+        "_$use(clickOutside, _el$, () => cb);",
+        "_$use(tip, _el$1, () => t);",
+        "_$use(_ref$2, _el$1) : s = _el$1;",
+    ];
+    let positions: Vec<usize> = calls
+        .iter()
+        .map(|call| {
+            output
+                .find(call)
+                .unwrap_or_else(|| panic!("missing {call}"))
+        })
+        .collect();
+    assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn test_conditional_text_or_element_child() {
+    let source = r#"
+        const view = <div>{cond() ? "text" : <span />}</div>;
+        const plain = <div>{ready ? "yes" : "no"}</div>;
+        const nested = <div>{state.items()}{list()()}</div>;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    // The element branch becomes a template call inside a reactive accessor
+    assert!(output.contains("_$template(`<span>`)"));
+    assert!(output.contains(r#"_$insert(_el$, () => cond() ? "text" : _tmpl$());"#));
+    // Conditionals without reactive reads are inserted as-is
+    assert!(output.contains(r#"_$insert(_el$1, ready ? "yes" : "no");"#));
+    // Only argument-free calls of plain identifiers are passed by reference
+    assert!(output.contains("_$insert(_el$2, () => state.items(), null);"));
+    assert!(output.contains("_$insert(_el$2, () => list()(), null);"));
+}
+
+#[test]
+fn test_omit_optional_tags() {
+    let source = r#"
+        const list = <ul><li>a</li><li>b</li></ul>;
+        const table = <tbody><tr><td>{a()}</td><td>b</td></tr><tr><td>c</td></tr></tbody>;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+    assert!(output.contains("_$template(`<ul><li>a</li><li>b`)"));
+
+    let options = DomExpressionsOptions::new("r-dom").with_omit_optional_tags(true);
+    let output = transform(source, options);
+    assert!(output.contains("_$template(`<ul><li>a<li>b`)"));
+    assert!(output.contains("_$template(`<tbody><tr><td><td>b<tr><td>c`)"));
+    // Element paths are unchanged by the shorter template
+    assert!(output.contains("_$insert(_el$2, a);"));
+}
+
+#[test]
+fn test_collapse_whitespace() {
+    let source = r#"
+        const text = <div>{"a    b"}</div>;
+        const pre = <pre>{"a    b"}</pre>;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+    assert!(output.contains("_$template(`<div>a    b`)"));
+
+    let options = DomExpressionsOptions::new("r-dom").with_collapse_whitespace(true);
+    let output = transform(source, options);
+    assert!(output.contains("_$template(`<div>a b`)"));
+    assert!(output.contains("_$template(`<pre>a    b`)"));
+}
+
+#[test]
+fn test_form_associated_elements() {
+    let source = r#"
+        const view = (
+            <fieldset disabled={off()}>
+                <legend>{title()}</legend>
+                <output name="sum" for="a b">{sum()}</output>
+            </fieldset>
+        );
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(
+        output.contains(r#"_$template(`<fieldset><legend></legend><output name=sum for="a b">`)"#)
+    );
+    assert!(output.contains("_el$1 = _el$.firstChild, _el$2 = _el$1.nextSibling;"));
+    assert!(output.contains("_$effect(() => _el$.disabled = off());"));
+    assert!(output.contains("_$insert(_el$1, title);"));
+    assert!(output.contains("_$insert(_el$2, sum);"));
+}
+
+#[test]
+fn test_component_spread_with_trailing_static_props() {
+    let source = r#"
+        const plain = <Comp {...props} class="x" />;
+        const reactive = <Comp {...props} class="x" title={t()} />;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains(
+        r#"const plain = _$createComponent(Comp, _$mergeProps(props, { class: "x" }));"#
+    ));
+    // The trailing segment is one object holding both static and getter props
+    assert!(output.contains(
+        "_$mergeProps(props, {\n\tclass: \"x\",\n\tget title() {\n\t\treturn t();\n\t}\n}))"
+    ));
+}
+
+#[test]
+fn test_reactive_inner_html() {
+    let source = r#"
+        const view = <div innerHTML={html()} />;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains("_$template(`<div>`)"));
+    assert!(output.contains("_$effect(() => _el$.innerHTML = html());"));
+    assert_eq!(output.matches("_$effect(").count(), 1);
+    assert!(!output.contains("_$insert"));
+    assert!(!output.contains("setAttribute"));
+}
+
+#[test]
+fn test_static_content_attributes() {
+    let source = r#"
+        const markup = <div innerHTML="<b>bold</b>" />;
+        const text = <p textContent={"a<b"} />;
+        const bound = <div innerHTML={html} />;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    // Known values become the element's content, innerHTML as raw markup
+    assert!(output.contains("_$template(`<div><b>bold`)"));
+    assert!(output.contains("_$template(`<p>a&lt;b`)"));
+    assert!(output.contains("_el$.innerHTML = html;"));
+    assert!(!output.contains("innerHTML=\""));
+    assert!(!output.contains("textContent"));
+}
+
+#[test]
+fn test_adjacent_static_siblings() {
+    let source = r#"
+        const view = <div><a/><b/><c class={x()}/></div>;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains("_$template(`<div><a></a><b></b><c>`)"));
+    assert!(output.contains(
+        "_el$1 = _el$.firstChild, _el$2 = _el$1.nextSibling, _el$3 = _el$2.nextSibling;"
+    ));
+    assert!(output.contains(r#"_$setAttribute(_el$3, "class", x())"#));
+}
+
+#[test]
+fn test_custom_elements_are_host_elements() {
+    let source = r#"
+        const widget = <my-widget foo={bar} baz={qux()} />;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains("_$template(`<my-widget>`)"));
+    assert!(!output.contains("_$createComponent"));
+    assert!(output.contains(r#"_$setAttribute(_el$, "foo", bar)"#));
+
+    let options =
+        DomExpressionsOptions::new("r-dom").with_custom_element_properties(vec!["foo".into()]);
+    let output = transform(source, options);
+
+    // Listed props are assigned directly, others stay attributes
+    assert!(output.contains("_el$.foo = bar"));
+    assert!(output.contains(r#"_$setAttribute(_el$, "baz", qux())"#));
+}
+
+#[test]
+fn test_built_in_components_are_imported() {
+    let source = r#"
+        const list = <For each={items}>{(item) => item}</For>;
+        const other = <Show when={ok} />;
+    "#;
+
+    let options = DomExpressionsOptions::new("r-dom").with_built_ins(vec!["For".into()]);
+    let output = transform(source, options);
+
+    assert!(output.contains(r#"import { For as _$For } from "r-dom";"#));
+    assert!(output.contains("_$createComponent(_$For, {"));
+    assert!(output.contains("_$createComponent(Show, {"));
+}
+
+#[test]
+fn test_template_literal_characters_in_text() {
+    let source = r#"
+        const code = <code>`{"${x}"}` a\b</code>;
+        const attr = <p title="a`b${c}" />;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains(r#"_$template(`<code>\`$\{x}\` a\\b`)"#));
+    assert!(output.contains(r#"_$template(`<p title="a\`b$\{c}">`)"#));
+
+    // The generated template literals parse back
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, &output, SourceType::mjs()).parse();
+    assert!(ret.errors.is_empty(), "Parse errors: {:?}", ret.errors);
+}
+
+#[test]
+fn test_nullish_style_properties() {
+    let source = r#"const view = <div style:color={x()} style:width={null} />;"#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    // The runtime removes the property when the value is nullish
+    assert!(output.contains(r#"_$effect(() => _$setStyleProperty(_el$, "color", x()));"#));
+    assert!(output.contains(r#"_$setStyleProperty(_el$, "width", null);"#));
+    assert!(output.contains("_$template(`<div>`)"));
+}
+
+#[test]
+fn test_media_boolean_attributes() {
+    let source = r#"
+        const video = <video muted autoplay={true} controls={false} />;
+        const audio = <audio muted={m()} autoplay={a()} controls={c} />;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    // Static values are presence attributes, dynamic ones are properties
+    assert!(output.contains("_$template(`<video muted autoplay>`)"));
+    assert!(output.contains("_$template(`<audio>`)"));
+    assert!(output.contains("_$effect(() => _el$.muted = m());"));
+    assert!(output.contains("_$effect(() => _el$.autoplay = a());"));
+    assert!(output.contains("_el$.controls = c;"));
+    assert!(!output.contains("setAttribute"));
+}
+
+#[test]
+fn test_ssr_comment_children() {
+    let source = r#"
+        const a = <div>{/* note */}a{x}</div>;
+        const b = <div>{/* only */}</div>;
+    "#;
+
+    // Comments render nothing and leave no hole in the template, with or
+    // without hydration
+    for hydratable in [false, true] {
+        let options = DomExpressionsOptions::new("r-server")
+            .with_generate(GenerateMode::Ssr)
+            .with_hydratable(hydratable);
+        let ssr = transform(source, options);
+
+        assert!(ssr.contains(r#"["<div>a", "</div>"]"#));
+        assert!(ssr.contains(r#""<div></div>""#));
+        assert!(ssr.contains("_$ssr(_tmpl$, _$escape(x))"));
+        assert!(!ssr.contains("note"));
+        assert!(!ssr.contains("<!--"));
+    }
+}
+
+#[test]
+fn test_ssr_fragment_elements() {
+    let source = r#"
+        const view = <>{a}<b /></>;
+    "#;
+
+    let options = DomExpressionsOptions::new("r-server").with_generate(GenerateMode::Ssr);
+    let ssr = transform(source, options);
+
+    // Rendered elements are plain strings, not reactive children
+    assert!(ssr.contains("const view = [a, _$ssr(_tmpl$)];"), "{}", ssr);
+    assert!(!ssr.contains("_$memo"));
+}
+
+#[test]
+fn test_template_decl_kind() {
+    let source = r#"const view = <div><span>{count()}</span></div>;"#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+    assert!(output.contains("var _tmpl$ = "));
+    assert!(output.contains("var _el$ = _tmpl$()"));
+
+    let options = DomExpressionsOptions::new("r-dom").with_template_decl_kind(VarKind::Const);
+    let output = transform(source, options);
+    assert!(output.contains("const _tmpl$ = "));
+    assert!(output.contains("const _el$ = _tmpl$(), _el$1 = _el$.firstChild;"));
+    assert!(!output.contains("var "));
+}
+
+#[test]
+fn test_deeply_nested_dynamic_attribute() {
+    let source = r#"const view = <div><span><b id={x()} /></span></div>;"#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains("_$template(`<div><span><b>`)"));
+    // _el$2 is _el$.firstChild.firstChild
+    assert!(output.contains("_el$1 = _el$.firstChild, _el$2 = _el$1.firstChild;"));
+    assert!(output.contains(r#"_$effect(() => _$setAttribute(_el$2, "id", x()));"#));
+}
+
+#[test]
+fn test_spread_children() {
+    let source = r#"
+        const list = <ul>{...items}</ul>;
+        const text = <p>before {...items} after</p>;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains("_$template(`<ul>`)"));
+    assert!(output.contains("_$insert(_el$, items);"));
+    // Between static text the spread gets a marker like any expression
+    assert!(output.contains("_$template(`<p>before <!> after`)"));
+    assert!(output.contains("_$insert(_el$1, items, _el$3);"));
+
+    let options = DomExpressionsOptions::new("r-server").with_generate(GenerateMode::Ssr);
+    let ssr = transform(source, options);
+    assert!(ssr.contains(r#"["<p>before ", " after</p>"]"#));
+    assert!(ssr.contains("_$ssr(_tmpl$2, _$escape(items))"));
+}
+
+#[test]
+fn test_enumerated_attributes() {
+    let source = r#"
+        const plain = <div contentEditable="plaintext-only" />;
+        const on = <div contenteditable={true} />;
+        const off = <div draggable={false} spellcheck={false} />;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    // Static booleans are spelled out instead of becoming presence attributes
+    assert!(output.contains("_$template(`<div contentEditable=plaintext-only>`)"));
+    assert!(output.contains("_$template(`<div contenteditable=true>`)"));
+    assert!(output.contains("_$template(`<div draggable=false spellcheck=false>`)"));
+
+    let options = DomExpressionsOptions::new("r-server").with_generate(GenerateMode::Ssr);
+    let ssr = transform(source, options);
+    assert!(ssr.contains(r#""<div contenteditable=\"true\"></div>""#));
+    assert!(ssr.contains(r#""<div draggable=\"false\" spellcheck=\"false\"></div>""#));
+}
+
+#[test]
+fn test_custom_element_event_delegation() {
+    let source = r#"const widget = <my-widget onClick={handle} on:select={pick} />;"#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    // Custom elements delegate like any host element; on: binds directly
+    assert!(output.contains("_$template(`<my-widget>`)"));
+    assert!(output.contains(r#"_$addEventListener(_el$, "click", handle, true);"#));
+    assert!(output.contains(r#"_$addEventListener(_el$, "select", pick);"#));
+    assert!(output.contains(r#"_$delegateEvents(["click"]);"#));
+}
+
+#[test]
+fn test_fragment_conditional_children_use_memo() {
+    let source = r#"
+        const ternary = <>{cond ? <A /> : <B />}</>;
+        const and = <>{ok && <div />}</>;
+        const plain = <>{a ? b : c}</>;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains(
+        "const ternary = _$memo(() => cond ? _$createComponent(A, {}) : _$createComponent(B, {}));"
+    ));
+    assert!(output.contains("const and = _$memo(() => ok && _tmpl$());"));
+    // Nothing in the condition can change, so it is left as-is
+    assert!(output.contains("const plain = a ? b : c;"));
+}
+
+#[test]
+fn test_select_multiple_array_value() {
+    let source = r#"
+        const picker = <select multiple value={selected()}><option value="a">A</option></select>;
+        const fixed = <select multiple value={["a", "b"]} />;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    // Array values are assigned to the property verbatim
+    assert!(output.contains("_$template(`<select multiple><option value=a>A`)"));
+    assert!(output.contains("_$effect(() => _el$.value = selected());"));
+    assert!(output.contains(r#"_el$1.value = ["a", "b"];"#));
+    assert!(!output.contains("setAttribute"));
+}
+
+#[test]
+fn test_conditional_children_are_accessors() {
+    let source = r#"
+        const ternary = <div>{cond ? <A /> : <B />}</div>;
+        const and = <div>{ok() && <span />}</div>;
+        const signal = <div>{value()}</div>;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains(
+        "_$insert(_el$, () => cond ? _$createComponent(A, {}) : _$createComponent(B, {}));"
+    ));
+    assert!(output.contains("_$insert(_el$1, () => ok() && _tmpl$2());"));
+    // A bare signal call is passed as the accessor itself
+    assert!(output.contains("_$insert(_el$2, value);"));
+}
+
+#[test]
+fn test_user_iife_children_are_accessors() {
+    let source = r#"
+        const arrow = <div>{(() => count())()}</div>;
+        const func = <div>{(function() { return count(); })()}</div>;
+        const element = <div>{<span>{count()}</span>}</div>;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    // IIFEs written by the user are re-run reactively, whatever their form
+    assert!(
+        output.contains("_$insert(_el$, () => (() => count())());"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("_$insert(_el$1, () => (function() {"),
+        "{}",
+        output
+    );
+    // The IIFE of an element with dynamic parts is still inserted as-is
+    assert!(output.contains("_$insert(_el$3, (() => {"), "{}", output);
+}
+
+#[test]
+fn test_templates_accessor() {
+    let source = r#"const el = <div id={id()} onClick={handler}>{count()}</div>;"#;
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_jsx(true).with_module(true);
+    let options = DomExpressionsOptions::new("solid-js/web");
+
+    let mut program = Parser::new(&allocator, source, source_type).parse().program;
+    let scoping = SemanticBuilder::new()
+        .build(&program)
+        .semantic
+        .into_scoping();
+    let mut compat2 = DomExpressionsCompat2::new(&allocator, options.clone());
+    traverse_mut(&mut compat2, &allocator, &mut program, scoping, ());
+
+    let mut program = Parser::new(&allocator, source, source_type).parse().program;
+    let scoping = SemanticBuilder::new()
+        .build(&program)
+        .semantic
+        .into_scoping();
+    let mut modern = DomExpressions::new(&allocator, options);
+    traverse_mut(&mut modern, &allocator, &mut program, scoping, ());
+
+    for templates in [compat2.templates(), modern.templates()] {
+        assert_eq!(templates.len(), 1);
+        let template: &Template = &templates[0];
+        assert_eq!(template.html, "<div>");
+        let slots: Vec<&SlotType> = template
+            .dynamic_slots
+            .iter()
+            .map(|slot: &DynamicSlot| &slot.slot_type)
+            .collect();
+        assert!(slots
+            .iter()
+            .any(|slot| matches!(slot, SlotType::Attribute(name) if name == "id")));
+        assert!(slots
+            .iter()
+            .any(|slot| matches!(slot, SlotType::EventHandler(name) if name == "Click")));
+        assert!(slots
+            .iter()
+            .any(|slot| matches!(slot, SlotType::TextContent)));
+    }
+}
+
+#[test]
+fn test_delegated_events_in_creation_order() {
+    let source = r#"
+        const el = (
+            <div>
+                <button onClick={select}>Select</button>
+                <input onInput={update} />
+                <button onClick={remove}>Remove</button>
+            </div>
+        );
+    "#;
+    let output = transform(source, DomExpressionsOptions::new("solid-js/web"));
+    let normalized: String = output.split_whitespace().collect();
+
+    assert!(
+        normalized.contains(r#"_$delegateEvents(["click","input"]);"#),
+        "{}",
+        output
+    );
+}
+
+#[test]
+fn test_svg_spread() {
+    let source = r#"
+        const icon = <svg {...svgProps}><rect {...props} /></svg>;
+        const box = <div {...props} />;
+    "#;
+    let output = transform(source, DomExpressionsOptions::new("solid-js/web"));
+
+    // The third argument of spread tells the runtime to set SVG attributes,
+    // the fourth whether the element's JSX children take precedence over props
+    assert!(
+        output.contains("_$spread(_el$, svgProps, true, true);"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("_$spread(_el$1, props, true, false);"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("_$spread(_el$2, props, false, false);"),
+        "{}",
+        output
+    );
+}
+
+#[test]
+fn test_spread_has_children() {
+    let source = r#"
+        const link = <a {...props} />;
+        const labelled = <a {...props}>{label()}</a>;
+    "#;
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    // Without JSX children the spread also applies `props.children`
+    assert!(
+        output.contains("_$spread(_el$, props, false, false);"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("_$spread(_el$1, props, false, true);"),
+        "{}",
+        output
+    );
+}
+
+#[test]
+fn test_input_value_as_properties() {
+    let source = r#"
+        const a = <input type="number" prop:valueAsNumber={n()} prop:valueAsDate={date()} />;
+        const b = <input prop:valueAsNumber={count} />;
+    "#;
+    let output = transform(source, DomExpressionsOptions::new("solid-js/web"));
+
+    assert!(
+        output.contains("_$effect(() => _el$.valueAsNumber = n());"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("_$effect(() => _el$.valueAsDate = date());"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("_el$1.valueAsNumber = count;"),
+        "{}",
+        output
+    );
+    assert!(!output.contains("valueAsNumber="), "{}", output);
+}
+
+#[test]
+fn test_on_event_listener_objects() {
+    let source = r#"
+        const el = (
+            <div
+                on:CustomEvent={handler}
+                on:scroll={{ handleEvent: onScroll, passive: true, once: true }}
+                on:Ready={{ handleEvent: onReady }}
+            />
+        );
+    "#;
+    let output = transform(source, DomExpressionsOptions::new("solid-js/web"));
+    let normalized: String = output.split_whitespace().collect();
+
+    // Bare handlers go through the runtime helper, keeping the event's case
+    assert!(
+        output.contains(r#"_$addEventListener(_el$, "CustomEvent", handler);"#),
+        "{}",
+        output
+    );
+    // Listener objects are bound directly and stay intact; one with options
+    // is also passed as the options
+    assert!(
+        normalized.contains(
+            r#"var_listener$={handleEvent:onScroll,passive:true,once:true};_el$.addEventListener("scroll",_listener$,_listener$);"#
+        ),
+        "{}",
+        output
+    );
+    assert!(
+        normalized.contains(r#"_el$.addEventListener("Ready",{handleEvent:onReady});"#),
+        "{}",
+        output
+    );
+}
+
+#[test]
+fn test_delegate_events_option() {
+    let source = r#"
+        const el = (
+            <div onClick={() => select()} onMouseDown={[pick, id]}>
+                <button onClick={remove} />
+            </div>
+        );
+    "#;
+
+    let delegated = transform(source, DomExpressionsOptions::new("solid-js/web"));
+    assert!(
+        delegated.contains("_el$.$$click = () => select();"),
+        "{}",
+        delegated
+    );
+    assert!(
+        delegated.contains("_el$.$$mousedown = pick;"),
+        "{}",
+        delegated
+    );
+    assert!(
+        delegated.contains("_el$.$$mousedownData = id;"),
+        "{}",
+        delegated
+    );
+    assert!(
+        delegated.contains(r#"_$addEventListener(_el$1, "click", remove, true);"#),
+        "{}",
+        delegated
+    );
+    assert!(delegated.contains("_$delegateEvents(["), "{}", delegated);
+
+    let direct = transform(
+        source,
+        DomExpressionsOptions::new("solid-js/web").with_delegate_events(false),
+    );
+    assert!(!direct.contains("$$"), "{}", direct);
+    assert!(!direct.contains("delegateEvents"), "{}", direct);
+    assert!(
+        direct.contains(r#"_el$.addEventListener("click", () => select());"#),
+        "{}",
+        direct
+    );
+    assert!(
+        direct.contains(r#"_el$.addEventListener("mousedown", (e) => pick(id, e));"#),
+        "{}",
+        direct
+    );
+    assert!(
+        direct.contains(r#"_$addEventListener(_el$1, "click", remove);"#),
+        "{}",
+        direct
+    );
+}
+
+#[test]
+fn test_jsx_in_switch_cases() {
+    let source = r#"
+        function view(x) {
+            switch (x) {
+                case 1:
+                    return <A />;
+                case 2: {
+                    const el = <div class="two">{x}</div>;
+                    return el;
+                }
+                default:
+                    return <span>none</span>;
+            }
+        }
+    "#;
+    let output = transform(source, DomExpressionsOptions::new("solid-js/web"));
+
+    assert!(
+        output.contains("case 1: return _$createComponent(A, {});"),
+        "{}",
+        output
+    );
+    assert!(output.contains("_$insert(_el$, x);"), "{}", output);
+    assert!(output.contains("default: return _tmpl$2();"), "{}", output);
+}
+
+#[test]
+fn test_head_elements() {
+    let source = r#"
+        const head = (
+            <head>
+                <base href={baseUrl()} />
+                <meta name="description" content={description()} />
+                <meta charset="utf-8" />
+                <link rel="stylesheet" href={theme()} />
+                <title>{title()}</title>
+            </head>
+        );
+    "#;
+    let output = transform(source, DomExpressionsOptions::new("solid-js/web"));
+
+    // Void head elements are never closed, so later siblings stay siblings
+    assert!(
+        output.contains(
+            "`<head><base><meta name=description><meta charset=utf-8><link rel=stylesheet><title>`"
+        ),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains(r#"_$effect(() => _$setAttribute(_el$1, "href", baseUrl()));"#),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains(r#"_$effect(() => _$setAttribute(_el$2, "content", description()));"#),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains(r#"_$effect(() => _$setAttribute(_el$4, "href", theme()));"#),
+        "{}",
+        output
+    );
+    assert!(output.contains("_$insert(_el$5, title);"), "{}", output);
+}
+
+#[test]
+fn test_ref_is_bound_first() {
+    let source = r#"
+        const el = <input value={v()} ref={r} onInput={h} />;
+        const nested = <div onClick={() => go()}><span title={t()} ref={inner} /></div>;
+    "#;
+    let output = transform(source, DomExpressionsOptions::new("solid-js/web"));
+
+    let position = |needle: &str| {
+        output
+            .find(needle)
+            .unwrap_or_else(|| panic!("missing {}: {}", needle, output))
+    };
+    assert!(position("var _ref$ = r;") < position("_el$.value = v()"));
+    assert!(position("_el$.value = v()") < position(r#""input", h, true"#));
+    // Ordering applies per element; the parent's handler isn't moved
+    assert!(position("_el$1.$$click") < position("var _ref$2 = inner;"));
+    assert!(position(": inner = _el$2;") < position(r#""title", t()"#));
+}
+
+#[test]
+fn test_mixed_class_list_stays_dynamic() {
+    let source = r#"
+        const mixed = <div class="base" classList={{ on: true, off: false, live: live() }} />;
+        const computed = <div classList={{ ["a" + "b"]: true }} />;
+    "#;
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+    let normalized: String = output.split_whitespace().collect();
+
+    // One unknown value keeps the whole object at runtime, so classList can
+    // still toggle the known keys later
+    assert!(
+        output.contains("_$template(`<div class=base>`)"),
+        "{}",
+        output
+    );
+    assert!(
+        normalized.contains("_$classList(_el$,{on:true,off:false,live:live()});"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains(r#"_$classList(_el$1, { ["a" + "b"]: true });"#),
+        "{}",
+        output
+    );
+}
+
+#[test]
+fn test_passive_events() {
+    let source = r#"
+        const el = <div onScroll={onScroll} onTouchMove={(e) => move(e)} onClick={go} />;
+    "#;
+
+    let default = transform(source, DomExpressionsOptions::new("solid-js/web"));
+    assert!(!default.contains("passive"), "{}", default);
+    assert!(
+        default.contains("_el$.$$touchmove = (e) => move(e);"),
+        "{}",
+        default
+    );
+
+    let passive = transform(
+        source,
+        DomExpressionsOptions::new("solid-js/web").with_passive_events(true),
+    );
+    assert!(
+        passive.contains(r#"_el$.addEventListener("scroll", onScroll, { passive: true });"#),
+        "{}",
+        passive
+    );
+    assert!(
+        passive
+            .contains(r#"_el$.addEventListener("touchmove", (e) => move(e), { passive: true });"#),
+        "{}",
+        passive
+    );
+    // Other events are still delegated
+    assert!(
+        passive.contains(r#"_$addEventListener(_el$, "click", go, true);"#),
+        "{}",
+        passive
+    );
+    assert!(
+        passive.contains(r#"_$delegateEvents(["click"]);"#),
+        "{}",
+        passive
+    );
+}
+
+#[test]
+fn test_jsx_looking_strings_are_untouched() {
+    let source = r#"
+        const markup = "<div class={x}>{count()}</div>";
+        const tpl = html`<button onClick=${go}>Go</button>`;
+        const raw = `<span>${name}</span>`;
+        // <div>{commented()}</div>
+        const plain = '<Comp prop={value} />';
+    "#;
+    let output = transform(source, DomExpressionsOptions::new("solid-js/web"));
+
+    assert!(
+        output.contains(r#"const markup = "<div class={x}>{count()}</div>";"#),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("const tpl = html`<button onClick=${go}>Go</button>`;"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("const raw = `<span>${name}</span>`;"),
+        "{}",
+        output
+    );
+    assert!(output.contains("// <div>{commented()}</div>"), "{}", output);
+    assert!(output.contains(r#""<Comp prop={value} />""#), "{}", output);
+    assert!(!output.contains("_$template"), "{}", output);
+    assert!(!output.contains("import"), "{}", output);
+}
+
+#[test]
+fn test_ref_targets() {
+    let source = r#"
+        function refFn() {}
+        const refConst = null;
+        let target;
+        const a = <div ref={target} />;
+        const b = <div ref={refConst} />;
+        const c = <div ref={(node) => (target = node)} />;
+        const d = <div ref={obj?.el} />;
+        const e = <div ref={props.ref} />;
+        const f = <div ref={refFactory()} />;
+        const g = <div ref={refFn} />;
+    "#;
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+    let normalized: String = output.split_whitespace().collect();
+
+    // Assignable targets get the element unless they hold a callback
+    assert!(output.contains("var _ref$ = target;"), "{}", output);
+    assert!(
+        output.contains(r#"typeof _ref$ === "function" ? _$use(_ref$, _el$) : target = _el$;"#),
+        "{}",
+        output
+    );
+    // Const bindings and functions can only be callbacks
+    assert!(output.contains("_$use(refConst, _el$1);"), "{}", output);
+    assert!(
+        normalized.contains("_$use((node)=>target=node,_el$2);"),
+        "{}",
+        output
+    );
+    // Optional chains and calls can't be assigned to
+    assert!(output.contains("var _ref$2 = obj?.el;"), "{}", output);
+    assert!(
+        output.contains(r#"typeof _ref$2 === "function" && _$use(_ref$2, _el$3);"#),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains(
+            r#"typeof _ref$3 === "function" ? _$use(_ref$3, _el$4) : props.ref = _el$4;"#
+        ),
+        "{}",
+        output
+    );
+    assert!(output.contains("var _ref$4 = refFactory();"), "{}", output);
+    assert!(
+        output.contains(r#"typeof _ref$4 === "function" && _$use(_ref$4, _el$5);"#),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains(r#"typeof _ref$5 === "function" ? _$use(_ref$5, _el$6) : refFn = _el$6;"#),
+        "{}",
+        output
+    );
+}
+
+#[test]
+fn test_hidden_attribute_values() {
+    let source = r#"
+        const shown = <div hidden={true} />;
+        const found = <div hidden="until-found" />;
+        const visible = <div hidden={false} />;
+        const found2 = <div hidden={"until-found"} />;
+        const dynamic = <div hidden={isHidden()} />;
+    "#;
+    let output = transform(source, DomExpressionsOptions::new("solid-js/web"));
+
+    assert!(output.contains("_$template(`<div hidden>`)"), "{}", output);
+    assert!(
+        output.contains("_$template(`<div hidden=until-found>`)"),
+        "{}",
+        output
+    );
+    assert!(output.contains("_$template(`<div>`)"), "{}", output);
+    assert_eq!(output.matches("_$template(").count(), 3, "{}", output);
+    // The property accepts both booleans and "until-found"
+    assert!(
+        output.contains("_$effect(() => _el$.hidden = isHidden());"),
+        "{}",
+        output
+    );
+
+    let ssr = transform(
+        source,
+        DomExpressionsOptions::new("solid-js/web").with_generate(GenerateMode::Ssr),
+    );
+    assert!(ssr.contains(r#""<div hidden></div>""#), "{}", ssr);
+    assert!(
+        ssr.contains(r#""<div hidden=\"until-found\"></div>""#),
+        "{}",
+        ssr
+    );
+    assert!(ssr.contains(r#""<div></div>""#), "{}", ssr);
+}
+
+#[test]
+fn test_preserve_comments() {
+    let source = r#"
+        const view = <div>{/* note */}<span />{value()}{/* end */}</div>;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("solid-js/web"));
+    assert!(output.contains("_$template(`<div><span>`)"), "{}", output);
+    assert!(!output.contains("<!--"), "{}", output);
+
+    let output = transform(
+        source,
+        DomExpressionsOptions::new("solid-js/web").with_preserve_comments(true),
+    );
+    assert!(
+        output.contains("_$template(`<div><!--note--><span></span><!><!--end-->`)"),
+        "{}",
+        output
+    );
+    // The comment nodes count when walking to the insertion marker
+    assert!(output.contains("_el$3 = _el$2.nextSibling"), "{}", output);
+    assert!(
+        output.contains("_$insert(_el$, value, _el$3)"),
+        "{}",
+        output
+    );
+}
+
+#[test]
+fn test_static_attribute_values_are_escaped() {
+    let source = r#"
+        const view = (
+            <div
+                title={'say "hi"'}
+                alt="Tom &amp; Jerry"
+                data-note={"a & b"}
+                style={{ "font-family": '"Fira Sans"' }}
+            />
+        );
+    "#;
+    let output = transform(source, DomExpressionsOptions::new("solid-js/web"));
+    assert!(
+        output.contains(
+            r#"_$template(`<div title="say &quot;hi&quot;" alt="Tom &amp; Jerry" data-note="a &amp; b" style="font-family:&quot;Fira Sans&quot;">`)"#
+        ),
+        "{}",
+        output
+    );
+
+    let ssr = transform(
+        source,
+        DomExpressionsOptions::new("solid-js/web").with_generate(GenerateMode::Ssr),
+    );
+    assert!(
+        ssr.contains(
+            r#"title=\"say &quot;hi&quot;\" alt=\"Tom &amp; Jerry\" data-note=\"a &amp; b\""#
+        ),
+        "{}",
+        ssr
+    );
+}
+
+#[test]
+fn test_svg_namespaced_attribute_names() {
+    let source = r##"
+        const icon = (
+            <svg viewBox="0 0 10 10" xmlnsXlink="http://www.w3.org/1999/xlink">
+                <use xlinkHref="#icon" />
+                <use xlinkHref={href()} />
+                <text xmlLang="en">x</text>
+            </svg>
+        );
+        const custom = <my-el xlinkHref="a" />;
+    "##;
+    let output = transform(source, DomExpressionsOptions::new("solid-js/web"));
+
+    assert!(
+        output.contains(r#"<svg viewBox="0 0 10 10" "#),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("xmlns:xlink=http://www.w3.org/1999/xlink>"),
+        "{}",
+        output
+    );
+    assert!(output.contains("<use xlink:href=#icon>"), "{}", output);
+    assert!(output.contains("<text xml:lang=en>"), "{}", output);
+    assert!(
+        output
+            .contains(r#"_$setAttributeNS(_el$2, "http://www.w3.org/1999/xlink", "href", href())"#),
+        "{}",
+        output
+    );
+    // Only SVG elements get the mapping
+    assert!(output.contains("<my-el xlinkHref=a>"), "{}", output);
+
+    let ssr = transform(
+        source,
+        DomExpressionsOptions::new("solid-js/web").with_generate(GenerateMode::Ssr),
+    );
+    assert!(ssr.contains(r##"<use xlink:href=\"#icon\">"##), "{}", ssr);
+    assert!(
+        ssr.contains(r#"_$ssrAttribute("xlink:href", _$escape(href(), true), false)"#),
+        "{}",
+        ssr
+    );
+}
+
+#[test]
+fn test_table_header_attributes() {
+    let source = r#"
+        const head = (
+            <tr>
+                <th scope="col" colspan={2} rowspan="3">A</th>
+                <th scope={scope()} colspan={span()} rowspan={rows()}>B</th>
+            </tr>
+        );
+    "#;
+    let output = transform(source, DomExpressionsOptions::new("solid-js/web"));
+
+    assert!(
+        output.contains("_$template(`<tr><th scope=col colspan=2 rowspan=3>A</th><th>B`)"),
+        "{}",
+        output
+    );
+    // None of them are DOM properties, so dynamic values use setAttribute
+    assert!(
+        output.contains(r#"_$setAttribute(_el$2, "scope", scope())"#),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains(r#"_$setAttribute(_el$2, "colspan", span())"#),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains(r#"_$setAttribute(_el$2, "rowspan", rows())"#),
+        "{}",
+        output
+    );
+
+    let ssr = transform(
+        source,
+        DomExpressionsOptions::new("solid-js/web").with_generate(GenerateMode::Ssr),
+    );
+    assert!(
+        ssr.contains(r#"<th scope=\"col\" colspan=\"2\" rowspan=\"3\">A</th>"#),
+        "{}",
+        ssr
+    );
+    assert!(
+        ssr.contains(r#"_$ssrAttribute("colspan", _$escape(span(), true), false)"#),
+        "{}",
+        ssr
+    );
+}
+
+#[test]
+fn test_svg_inner_html_is_assigned_at_runtime() {
+    let source = r#"
+        const icon = <svg innerHTML={'<circle r="1"/><rect width="2"/>'} />;
+        const group = <svg><g innerHTML={markup()} /></svg>;
+        const html = <div innerHTML="<b>bold</b>" />;
+    "#;
+    let output = transform(source, DomExpressionsOptions::new("solid-js/web"));
+
+    // SVG markup is parsed in the element's namespace instead of the template's
+    assert!(output.contains("_$template(`<svg>`)"), "{}", output);
+    assert!(
+        output.contains(r#"_el$.innerHTML = "<circle r=\"1\"/><rect width=\"2\"/>";"#),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("_$effect(() => _el$2.innerHTML = markup());"),
+        "{}",
+        output
+    );
+    // HTML elements still fold known markup into the template
+    assert!(output.contains("_$template(`<div><b>bold`)"), "{}", output);
+}
+
+#[test]
+fn test_nullish_attributes_are_omitted() {
+    let source = r#"
+        const view = <div title={null} id={undefined} class={null} data-x={value()} />;
+    "#;
+    let output = transform(source, DomExpressionsOptions::new("solid-js/web"));
+
+    assert!(output.contains("_$template(`<div>`)"), "{}", output);
+    assert!(!output.contains("null"), "{}", output);
+    assert!(!output.contains("undefined"), "{}", output);
+    assert_eq!(output.matches("_$effect(").count(), 1, "{}", output);
+
+    let ssr = transform(
+        source,
+        DomExpressionsOptions::new("solid-js/web").with_generate(GenerateMode::Ssr),
+    );
+    assert!(!ssr.contains("title"), "{}", ssr);
+    assert!(!ssr.contains("id="), "{}", ssr);
+    assert!(!ssr.contains("class"), "{}", ssr);
+}
+
+#[test]
+fn test_unary_child_expressions() {
+    let source = r#"
+        const count = <div>{+count()}</div>;
+        const negated = <div>{-offset}</div>;
+        const folded = <div>{+5}</div>;
+    "#;
+    let output = transform(source, DomExpressionsOptions::new("solid-js/web"));
+
+    // A call under the operator is reactive, so the insert gets a thunk
+    assert!(
+        output.contains("_$insert(_el$, () => +count());"),
+        "{}",
+        output
+    );
+    assert!(output.contains("_$insert(_el$1, -offset);"), "{}", output);
+    assert!(output.contains("_$template(`<div>5`)"), "{}", output);
+}
+
+#[test]
+fn test_non_function_event_handlers() {
+    let source = r#"
+        const named = <button onClick={handler} />;
+        const text = <button onClick={"alert(1)"} />;
+        const cleared = <button onClick={null} />;
+    "#;
+    let output = transform(source, DomExpressionsOptions::new("solid-js/web"));
+
+    // Values that may not be functions are handed to the runtime unchanged
+    assert!(
+        output.contains(r#"_$addEventListener(_el$, "click", handler, true);"#),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains(r#"_$addEventListener(_el$1, "click", "alert(1)", true);"#),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains(r#"_$addEventListener(_el$2, "click", null, true);"#),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains(r#"_$delegateEvents(["click"]);"#),
+        "{}",
+        output
+    );
+}
+
+#[test]
+fn test_component_host_children_get_fresh_element_vars() {
+    let source = r#"
+        const single = <Comp><div>{x}</div></Comp>;
+        const nested = (
+            <section>
+                {a()}
+                {show() && <Comp><span>{y}</span><Other><p>{z}</p></Other></Comp>}
+                <b>{c}</b>
+            </section>
+        );
+    "#;
+    let output = transform(source, DomExpressionsOptions::new("solid-js/web"));
+
+    assert!(
+        output.contains("children: (() => {\n\tvar _el$ = _tmpl$();\n\t_$insert(_el$, x);"),
+        "{}",
+        output
+    );
+
+    // Every element variable is declared exactly once across the nested IIFEs
+    for name in ["_el$", "_el$1", "_el$2", "_el$3", "_el$4"] {
+        let declaration = format!("{} = ", name);
+        assert_eq!(output.matches(&declaration).count(), 1, "{}", output);
+    }
+    assert!(!output.contains("_el$5"), "{}", output);
+}
+
+#[test]
+fn test_unsupported_constructs_are_reported() {
+    let source = "const view = <div><>text</>{...items}</div>;";
+    let output = oxc_dom_expressions::transform(
+        source,
+        SourceType::jsx(),
+        DomExpressionsOptions::new("r-dom"),
+    );
+
+    // Spread children are inserted; only the dropped fragment is reported
+    assert!(output.code.contains("_$insert(_el$, items"));
+    assert_eq!(output.diagnostics.len(), 1);
+    let diagnostic = &output.diagnostics[0];
+    assert_eq!(diagnostic.severity, Severity::Warning);
+    assert_eq!(diagnostic.span.source_text(source), "<>text</>");
+
+    let source = "const view = <div {...props} class:on={on()} />;";
+    let options = DomExpressionsOptions::new("r-dom").with_generate(GenerateMode::Ssr);
+    let output = oxc_dom_expressions::transform(source, SourceType::jsx(), options);
+    let reported: Vec<_> = output
+        .diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.span.source_text(source))
+        .collect();
+    assert_eq!(reported, vec!["{...props}", "class:on={on()}"]);
+
+    let source = "const view = <ui.Button />;";
+    let output = oxc_dom_expressions::transform(
+        source,
+        SourceType::jsx(),
+        DomExpressionsOptions::new("r-dom"),
+    );
+    assert_eq!(output.diagnostics[0].severity, Severity::Error);
+    assert_eq!(output.diagnostics[0].span.source_text(source), "ui.Button");
+}
+
+#[test]
+fn test_open_is_a_boolean_attribute() {
+    let source = r#"
+        const a = <dialog open>Hi</dialog>;
+        const b = <details open={isOpen()}><summary>More</summary></details>;
+        const c = <dialog open={false} />;
+    "#;
+
+    let code = transform(source, DomExpressionsOptions::new("r-dom"));
+    assert!(code.contains("_$template(`<dialog open>Hi`)"), "{}", code);
+    assert!(
+        code.contains("_$template(`<details><summary>More`)"),
+        "{}",
+        code
+    );
+    assert!(
+        code.contains("_$effect(() => _el$.open = isOpen())"),
+        "{}",
+        code
+    );
+    assert!(code.contains("_$template(`<dialog>`)"), "{}", code);
+
+    let options = DomExpressionsOptions::new("r-dom").with_generate(GenerateMode::Ssr);
+    let code = transform(source, options);
+    assert!(code.contains(r#""<dialog open>Hi</dialog>""#), "{}", code);
+    assert!(
+        code.contains(r#"_$ssrAttribute("open", isOpen(), true)"#),
+        "{}",
+        code
+    );
+}
+
+#[test]
+fn test_data_and_time_attributes() {
+    let source = r#"
+        const data = <data value={v()}>x</data>;
+        const time = <time datetime={d()}>t</time>;
+        const fixed = <time datetime="2024-01-01" />;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    // `value` is reflected as a property, `datetime` is a plain attribute
+    assert!(output.contains("_$template(`<data>x`)"));
+    assert!(output.contains("_$effect(() => _el$.value = v());"));
+    assert!(output.contains("_$template(`<time>t`)"));
+    assert!(output.contains(r#"_$effect(() => _$setAttribute(_el$1, "datetime", d()));"#));
+    assert!(output.contains("_$template(`<time datetime=2024-01-01>`)"));
+}
+
+#[test]
+fn test_jsx_inside_user_iife() {
+    let source = r#"
+        const simple = (() => <div />)();
+        const nested = (function () {
+            const x = 1;
+            return <span>{x}</span>;
+        })();
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains("const simple = (() => _tmpl$())();"));
+    assert!(output.contains("const nested = (function() {"));
+    assert!(output.contains("const x = 1;"));
+    assert!(output.contains("return (() => {"));
+    assert!(output.contains("_$insert(_el$, x);"));
+}
+
+#[test]
+fn test_dynamic_component_with_spread() {
+    let source = r#"const view = <Dynamic component={Comp} value={v} {...rest} />;"#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains(r#"import { mergeProps as _$mergeProps } from "r-dom";"#));
+    assert!(output.contains("_$createComponent(Dynamic, _$mergeProps({"));
+    assert!(output.contains("component: Comp,"));
+    assert!(output.contains("value: v\n}, rest))"));
+}
+
+#[test]
+fn test_component_spread_order() {
+    let source = r#"
+        const leading = <Comp {...b} c={2} />;
+        const trailing = <Comp a={1} {...b} />;
+        const interleaved = <Comp a={1} {...b} c={2} {...d}>hi</Comp>;
+        const only = <Comp {...b} />;
+        const none = <Comp a={1} />;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+
+    assert!(output.contains("const leading = _$createComponent(Comp, _$mergeProps(b, { c: 2 }));"));
+    assert!(output.contains("const trailing = _$createComponent(Comp, _$mergeProps({ a: 1 }, b));"));
+    assert!(output.contains(
+        r#"const interleaved = _$createComponent(Comp, _$mergeProps({ a: 1 }, b, { c: 2 }, d, { children: "hi" }));"#
+    ));
+    assert!(output.contains("const only = _$createComponent(Comp, b);"));
+    assert!(output.contains("const none = _$createComponent(Comp, { a: 1 });"));
+}
+
+#[test]
+fn test_awaited_expressions() {
+    let source = r#"
+        async function loadData() {
+            return <div title={await fetchTitle()} aria-label={label()}>{await fetchData()}</div>;
+        }
+        const loadFragment = async () => <>{await fetchData()}{format(await fetchData())}</>;
+        const loadComponent = async () => <Card value={await fetchData()}>{await fetchBody()}</Card>;
+        const list = <ul>{items().map(async (item) => <li>{await item.load()}</li>)}</ul>;
+    "#;
+
+    let output = transform(source, DomExpressionsOptions::new("r-dom"));
+    let normalized: String = output.split_whitespace().collect();
+
+    // An element that awaits is built in an async IIFE that is awaited in turn,
+    // and awaited values are read once instead of inside an effect or getter
+    assert!(
+        normalized.contains("returnawait(async()=>{var_el$=_tmpl$();"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains(r#"_$setAttribute(_el$, "title", await fetchTitle());"#),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains(r#"_$effect(() => _$setAttribute(_el$, "aria-label", label()));"#),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("_$insert(_el$, await fetchData());"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("async () => [await fetchData(), format(await fetchData())]"),
+        "{}",
+        output
+    );
+    assert!(
+        normalized
+            .contains("_$createComponent(Card,{value:awaitfetchData(),children:awaitfetchBody()})"),
+        "{}",
+        output
+    );
+    // The await stays inside the async callback, not the outer insert
+    assert!(
+        normalized.contains("items().map(async(item)=>await(async()=>{"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("_$insert(_el$1, await item.load());"),
+        "{}",
+        output
+    );
+}
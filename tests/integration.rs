@@ -101,3 +101,40 @@ fn test_transform_with_dynamic_content() {
     // Input:  <div>{count()}</div>
     // Output: insert(_el$, count);
 }
+
+#[test]
+fn test_transform_reports_delegated_events() {
+    let source = r#"
+        const view = <div onClick={() => select()} onKeyDown={update}>
+            <button onMouseDown={() => press()} onClick={() => click()} />
+            <input onChange={() => change()} />
+        </div>;
+    "#;
+
+    let output = oxc_dom_expressions::transform(
+        source,
+        oxc_span::SourceType::jsx(),
+        DomExpressionsOptions::new("solid-js/web"),
+    );
+
+    assert!(output.errors.is_empty());
+    // `change` is not delegated, and each event is listed once
+    assert_eq!(
+        output.delegated_events,
+        vec!["click", "keydown", "mousedown"]
+    );
+    assert!(output.code.contains("_$delegateEvents(["));
+}
+
+#[test]
+fn test_transform_reports_parse_errors() {
+    let output = oxc_dom_expressions::transform(
+        "const view = <div>;",
+        oxc_span::SourceType::jsx(),
+        DomExpressionsOptions::new("solid-js/web"),
+    );
+
+    assert!(!output.errors.is_empty());
+    assert!(output.code.is_empty());
+    assert!(output.delegated_events.is_empty());
+}
@@ -2,7 +2,7 @@
 
 use oxc_allocator::Allocator;
 use oxc_codegen::Codegen;
-use oxc_dom_expressions::{DomExpressions, DomExpressionsOptions};
+use oxc_dom_expressions::{DomExpressions, DomExpressionsOptions, TemplateRegistry};
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
 use oxc_span::SourceType;
@@ -54,3 +54,145 @@ fn test_modern_transform_with_attributes() {
     assert!(output.contains("import"));
     assert!(output.contains("solid-runtime/polyfill"));
 }
+
+#[test]
+fn test_template_registry_shared_between_files() {
+    let registry = TemplateRegistry::new("virtual:templates");
+
+    let transform_file = |source_text: &str| {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::jsx()).parse();
+        let mut program = ret.program;
+
+        let semantic = SemanticBuilder::new().build(&program).semantic;
+        let scoping = semantic.into_scoping();
+
+        let options = DomExpressionsOptions::new("solid-js/web");
+        let mut transformer =
+            DomExpressions::new(&allocator, options).with_template_registry(registry.clone());
+        traverse_mut(&mut transformer, &allocator, &mut program, scoping, ());
+
+        Codegen::new().build(&program).code
+    };
+
+    let first = transform_file(r#"const a = <div class="card">Shared</div>;"#);
+    let second = transform_file(
+        r#"
+        const b = <p>Only here</p>;
+        const c = <div class="card">Shared</div>;
+        "#,
+    );
+
+    assert!(
+        first.contains(r#"import { _tmpl$ } from "virtual:templates";"#),
+        "{}",
+        first
+    );
+    assert!(
+        second.contains(r#"import { _tmpl$2, _tmpl$ } from "virtual:templates";"#),
+        "{}",
+        second
+    );
+    assert!(!second.contains("_$template("), "{}", second);
+
+    let module = registry.module_code(&DomExpressionsOptions::new("solid-js/web"));
+    assert_eq!(registry.len(), 2);
+    assert_eq!(module.matches("export const").count(), 2, "{}", module);
+    assert!(module.contains("export const _tmpl$ = "), "{}", module);
+    assert!(module.contains("`<p>Only here`"), "{}", module);
+}
+
+#[test]
+fn test_template_registry_reports_shared_templates() {
+    let registry = TemplateRegistry::default();
+
+    let transform_file = |origin: &str, source_text: &str| {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::jsx()).parse();
+        let mut program = ret.program;
+
+        let semantic = SemanticBuilder::new().build(&program).semantic;
+        let scoping = semantic.into_scoping();
+
+        let options = DomExpressionsOptions::new("solid-js/web");
+        let mut transformer =
+            DomExpressions::new_with_registry(&allocator, options, registry.clone(), origin);
+        traverse_mut(&mut transformer, &allocator, &mut program, scoping, ());
+
+        Codegen::new().build(&program).code
+    };
+
+    let first = transform_file("a.jsx", "const a = <div>x</div>;");
+    let second = transform_file(
+        "b.jsx",
+        "const b = <span>only b</span>;\nconst c = <div>x</div>;",
+    );
+
+    // Each file keeps its own template declarations and names
+    assert!(
+        first.contains("const _tmpl$ = _$template(`<div>x`)"),
+        "{}",
+        first
+    );
+    assert!(
+        second.contains("_tmpl$2 = _$template(`<div>x`)"),
+        "{}",
+        second
+    );
+
+    assert_eq!(
+        registry.shared_templates(),
+        vec![(
+            "<div>x".to_string(),
+            vec!["a.jsx".to_string(), "b.jsx".to_string()]
+        )]
+    );
+}
+
+#[test]
+fn test_template_declarations_are_deterministic() {
+    let source_text = r#"
+        const a = <div>one</div>;
+        const b = <span>two</span>;
+        const c = <p>three</p>;
+        const d = <section>four</section>;
+        const e = <a>five</a>;
+    "#;
+
+    let transform_source = || {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::jsx()).parse();
+        let mut program = ret.program;
+
+        let semantic = SemanticBuilder::new().build(&program).semantic;
+        let scoping = semantic.into_scoping();
+
+        let options = DomExpressionsOptions::new("solid-js/web");
+        let mut transformer = DomExpressions::new(&allocator, options);
+        traverse_mut(&mut transformer, &allocator, &mut program, scoping, ());
+
+        Codegen::new().build(&program).code
+    };
+
+    let first = transform_source();
+    for _ in 0..10 {
+        assert_eq!(transform_source(), first);
+    }
+
+    // Templates are declared in the order they were created
+    let positions: Vec<usize> = [
+        "_tmpl$ =",
+        "_tmpl$2 =",
+        "_tmpl$3 =",
+        "_tmpl$4 =",
+        "_tmpl$5 =",
+    ]
+    .iter()
+    .map(|declaration| first.find(declaration).expect(declaration))
+    .collect();
+    assert!(
+        positions.windows(2).all(|pair| pair[0] < pair[1]),
+        "{}",
+        first
+    );
+}
@@ -233,6 +233,7 @@ fn test_template_stats_calculations() {
         deduplicated_html_size: 400,
         static_templates: 2,
         dynamic_templates: 2,
+        ..Default::default()
     };
 
     assert_eq!(stats.space_saved(), 600);
@@ -350,3 +351,45 @@ fn test_ssr_mode_optimization() {
     assert_eq!(stats.unique_templates, 1);
     assert_eq!(stats.total_templates, 2);
 }
+
+#[test]
+fn test_template_dedup_can_be_disabled() {
+    use oxc_codegen::Codegen;
+    use oxc_dom_expressions::DomExpressionsCompat2;
+
+    let source = r#"
+        const a = <div>x</div>;
+        const b = <div>x</div>;
+    "#;
+
+    let transform = |dedup: bool| {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, SourceType::jsx()).parse();
+        let mut program = ret.program;
+
+        let semantic = SemanticBuilder::new().build(&program).semantic;
+        let scoping = semantic.into_scoping();
+
+        let options = DomExpressionsOptions::new("r-dom").with_template_dedup(dedup);
+        let mut transformer = DomExpressionsCompat2::new(&allocator, options);
+        traverse_mut(&mut transformer, &allocator, &mut program, scoping, ());
+
+        let stats = transformer.get_template_stats();
+        (Codegen::new().build(&program).code, stats)
+    };
+
+    let (deduped, deduped_stats) = transform(true);
+    assert!(deduped.contains("const a = _tmpl$();"));
+    assert!(deduped.contains("const b = _tmpl$();"));
+    assert!(!deduped.contains("_tmpl$2"));
+
+    let (separate, separate_stats) = transform(false);
+    assert!(separate.contains("const a = _tmpl$();"));
+    assert!(separate.contains("const b = _tmpl$2();"));
+    assert_eq!(separate.matches("_$template(`<div>x`)").count(), 2);
+
+    // The statistics still report the reuse opportunity
+    assert_eq!(deduped_stats.reused_templates, 1);
+    assert_eq!(separate_stats.reused_templates, 1);
+    assert_eq!(separate_stats.unique_templates, 1);
+}
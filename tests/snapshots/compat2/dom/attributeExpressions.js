@@ -0,0 +1,527 @@
+import { template as _$template } from "r-dom";
+import { delegateEvents as _$delegateEvents } from "r-dom";
+import { createComponent as _$createComponent } from "r-dom";
+import { setBoolAttribute as _$setBoolAttribute } from "r-dom";
+import { insert as _$insert } from "r-dom";
+import { addEventListener as _$addEventListener } from "r-dom";
+import { style as _$style } from "r-dom";
+import { className as _$className } from "r-dom";
+import { setStyleProperty as _$setStyleProperty } from "r-dom";
+import { setAttribute as _$setAttribute } from "r-dom";
+import { effect as _$effect } from "r-dom";
+import { classList as _$classList } from "r-dom";
+import { use as _$use } from "r-dom";
+import { spread as _$spread } from "r-dom";
+import { mergeProps as _$mergeProps } from "r-dom";
+var _tmpl$ = /*#__PURE__*/ _$template(`<div id=main><h1 class=base><a href=/ class="ccc ddd">Welcome`),
+  _tmpl$2 = /*#__PURE__*/ _$template(`<div><div></div><div></div><div><div>`),
+  _tmpl$3 = /*#__PURE__*/ _$template(`<div foo>`),
+  _tmpl$4 = /*#__PURE__*/ _$template(`<div class=hi>`),
+  _tmpl$5 = /*#__PURE__*/ _$template(`<div class=a class=b>`),
+  _tmpl$6 = /*#__PURE__*/ _$template(`<div>Hi`),
+  _tmpl$7 = /*#__PURE__*/ _$template(`<div>`),
+  _tmpl$8 = /*#__PURE__*/ _$template(`<input type=checkbox checked>`),
+  _tmpl$9 = /*#__PURE__*/ _$template(`<input type=checkbox>`),
+  _tmpl$10 = /*#__PURE__*/ _$template(`<div class="\`a">\`$\``),
+  _tmpl$11 = /*#__PURE__*/ _$template(`<button class="static hi" type=button>Write`),
+  _tmpl$12 = /*#__PURE__*/ _$template(`<button class="a b c">Hi`),
+  _tmpl$13 = /*#__PURE__*/ _$template(`<div class="flex flex-col bg-red-500">`),
+  _tmpl$14 = /*#__PURE__*/ _$template(`<div><input readonly><input>`),
+  _tmpl$15 = /*#__PURE__*/ _$template(`<div data="&quot;hi&quot;" data2="&quot;">`),
+  _tmpl$16 = /*#__PURE__*/ _$template(`<a>`),
+  _tmpl$17 = /*#__PURE__*/ _$template(`<div><a>`),
+  _tmpl$18 = /*#__PURE__*/ _$template(`<div start=Hi>Hi`),
+  _tmpl$19 = /*#__PURE__*/ _$template(`<label><span>Input is </span><input><div>`),
+  _tmpl$20 = /*#__PURE__*/ _$template(`<div class="class1 class2
+    class3 class4
+    class5 class6" style="color:red;background-color:blue !important;border:1px solid black;font-size:12px" random="random1 random2
+    random3 random4">`),
+  _tmpl$21 = /*#__PURE__*/ _$template(`<button>`),
+  _tmpl$22 = /*#__PURE__*/ _$template(`<input value=10>`),
+  _tmpl$23 = /*#__PURE__*/ _$template(`<select><option>Red</option><option>Blue`),
+  _tmpl$24 = /*#__PURE__*/ _$template(`<div>empty string`),
+  _tmpl$25 = /*#__PURE__*/ _$template(`<div>js empty`),
+  _tmpl$26 = /*#__PURE__*/ _$template(`<div quack>hola`),
+  _tmpl$27 = /*#__PURE__*/ _$template(`<div quack>"hola js"`),
+  _tmpl$28 = /*#__PURE__*/ _$template(`<div quack>true`),
+  _tmpl$29 = /*#__PURE__*/ _$template(`<div>false`),
+  _tmpl$30 = /*#__PURE__*/ _$template(`<div quack>1`),
+  _tmpl$31 = /*#__PURE__*/ _$template(`<div>0`),
+  _tmpl$32 = /*#__PURE__*/ _$template(`<div quack>"1"`),
+  _tmpl$33 = /*#__PURE__*/ _$template(`<div>"0"`),
+  _tmpl$34 = /*#__PURE__*/ _$template(`<div>undefined`),
+  _tmpl$35 = /*#__PURE__*/ _$template(`<div>null`),
+  _tmpl$36 = /*#__PURE__*/ _$template(`<div>boolTest()`),
+  _tmpl$37 = /*#__PURE__*/ _$template(`<div>boolTest`),
+  _tmpl$38 = /*#__PURE__*/ _$template(`<div>boolTestBinding`),
+  _tmpl$39 = /*#__PURE__*/ _$template(`<div>boolTestObjBinding.value`),
+  _tmpl$40 = /*#__PURE__*/ _$template(`<div>fn`),
+  _tmpl$41 = /*#__PURE__*/ _$template(`<div before quack>should have space before`),
+  _tmpl$42 = /*#__PURE__*/ _$template(`<div before quack after>should have space before/after`),
+  _tmpl$43 = /*#__PURE__*/ _$template(`<div quack after>should have space before/after`),
+  _tmpl$44 = /*#__PURE__*/ _$template(`<img src>`),
+  _tmpl$45 = /*#__PURE__*/ _$template(`<div><img src>`),
+  _tmpl$46 = /*#__PURE__*/ _$template(`<img src loading=lazy>`),
+  _tmpl$47 = /*#__PURE__*/ _$template(`<div><img src loading=lazy>`),
+  _tmpl$48 = /*#__PURE__*/ _$template(`<iframe src>`),
+  _tmpl$49 = /*#__PURE__*/ _$template(`<div><iframe src>`),
+  _tmpl$50 = /*#__PURE__*/ _$template(`<iframe src loading=lazy>`),
+  _tmpl$51 = /*#__PURE__*/ _$template(`<div><iframe src loading=lazy>`),
+  _tmpl$52 = /*#__PURE__*/ _$template(`<div title="<u>data</u>">`),
+  _tmpl$53 = /*#__PURE__*/ _$template(`<div true truestr=true truestrjs=true>`),
+  _tmpl$54 = /*#__PURE__*/ _$template(`<div falsestr=false falsestrjs=false>`),
+  _tmpl$55 = /*#__PURE__*/ _$template(`<math display=block><mrow>`),
+  _tmpl$56 = /*#__PURE__*/ _$template(`<mrow><mi>x</mi><mo>=`),
+  _tmpl$57 = /*#__PURE__*/ _$template(`<div style=background:red>`),
+  _tmpl$58 = /*#__PURE__*/ _$template(`<div style="background:red;color:green">`),
+  _tmpl$59 = /*#__PURE__*/ _$template(`<div data-hk="should warn data-hk is present on template">`);
+import * as styles from "./styles.module.css";
+import { binding } from "somewhere";
+function refFn() {}
+const refConst = null;
+const selected = true;
+let id = "my-h1";
+let link;
+const template = (() => {
+  var _el$ = _tmpl$(), _el$1 = _el$.firstChild, _el$2 = _el$1.firstChild;
+  _$spread(_el$, _$mergeProps(results, {
+    classList: { selected: unknown },
+    style: { color }
+  }), false, true);
+  _$effect(() => _$setAttribute(_el$1, "id", id));
+  _$spread(_el$1, _$mergeProps(results(), {
+    foo: "",
+    disabled: true,
+    get title() {
+      return welcoming();
+    },
+    get style() {
+      return {
+        "background-color": color(),
+        "margin-right": "40px"
+      };
+    },
+    get classList() {
+      return {
+        dynamic: dynamic(),
+        selected
+      };
+    }
+  }), false, true);
+  var _ref$ = link;
+  typeof _ref$ === "function" ? _$use(_ref$, _el$2) : link = _el$2;
+  return _el$;
+})();
+const template2 = (() => {
+  var _el$3 = _tmpl$2(), _el$4 = _el$3.firstChild, _el$5 = _el$4.nextSibling;
+  _$spread(_el$3, getProps("test"), false, true);
+  _el$4.textContent = rowId;
+  _$effect(() => _el$5.textContent = row.label);
+  return _el$3;
+})();
+const template3 = (() => {
+  var _el$6 = _tmpl$3();
+  _$effect(() => _el$6.textContent = state.content);
+  /*@once*/ _$effect(() => _$setAttribute(_el$6, "id", state.id));
+  _$style(
+    _el$6,
+    /*@once*/
+    { "background-color": state.color }
+  );
+  _$effect(() => _$setAttribute(_el$6, "name", state.name));
+  return _el$6;
+})();
+const template4 = (() => {
+  var _el$7 = _tmpl$4();
+  _$effect(() => _$setAttribute(_el$7, "class", state.class));
+  _$classList(_el$7, { "ccc:ddd": true });
+  return _el$7;
+})();
+const template5 = _tmpl$5();
+const template6 = (() => {
+  var _el$8 = _tmpl$6();
+  _$style(_el$8, someStyle());
+  return _el$8;
+})();
+let undefVar;
+const template7 = (() => {
+  var _el$9 = _tmpl$7();
+  _$style(_el$9, {
+    "background-color": color(),
+    "margin-right": "40px",
+    ...props.style
+  });
+  _$effect(() => _$setStyleProperty(_el$9, "padding-top", props.top));
+  _$effect(() => _$className(_el$9, "my-class", props.active));
+  _$className(_el$9, "other-class", undefVar);
+  _$classList(_el$9, { "other-class2": undefVar });
+  return _el$9;
+})();
+let refTarget;
+const template8 = (() => {
+  var _el$10 = _tmpl$7();
+  var _ref$2 = refTarget;
+  typeof _ref$2 === "function" ? _$use(_ref$2, _el$10) : refTarget = _el$10;
+  return _el$10;
+})();
+const template9 = (() => {
+  var _el$11 = _tmpl$7();
+  _$use((e) => console.log(e), _el$11);
+  return _el$11;
+})();
+const template10 = (() => {
+  var _el$12 = _tmpl$7();
+  var _ref$3 = refFactory();
+  typeof _ref$3 === "function" && _$use(_ref$3, _el$12);
+  return _el$12;
+})();
+const template11 = (() => {
+  var _el$13 = _tmpl$7();
+  _$use(something, _el$13, () => true);
+  _$use(another, _el$13, () => thing);
+  _$use(zero, _el$13, () => 0);
+  return _el$13;
+})();
+const template12 = (() => {
+  var _el$14 = _tmpl$7();
+  _el$14.htmlFor = thing;
+  _el$14.number = 123;
+  _$setAttribute(_el$14, "onclick", "console.log('hi')");
+  return _el$14;
+})();
+const template13 = _tmpl$8();
+const template14 = (() => {
+  var _el$15 = _tmpl$9();
+  _$effect(() => _el$15.checked = state.visible);
+  return _el$15;
+})();
+const template15 = _tmpl$10();
+const template16 = _tmpl$11();
+const template17 = (() => {
+  var _el$16 = _tmpl$12();
+  _$addEventListener(_el$16, "click", increment, true);
+  return _el$16;
+})();
+const template18 = (() => {
+  var _el$17 = _tmpl$7();
+  _$spread(_el$17, { get [key()]() {
+    return props.value;
+  } }, false, false);
+  return _el$17;
+})();
+const template19 = _tmpl$13();
+const template20 = (() => {
+  var _el$18 = _tmpl$14(), _el$19 = _el$18.firstChild, _el$20 = _el$19.nextSibling;
+  _$effect(() => _el$19.value = s());
+  _$effect(() => _$setAttribute(_el$19, "min", min()));
+  _$effect(() => _$setAttribute(_el$19, "max", max()));
+  _$addEventListener(_el$19, "input", doSomething, true);
+  _$effect(() => _el$20.checked = s2());
+  _el$20.readOnly = value;
+  _$effect(() => _$setAttribute(_el$20, "min", min()));
+  _$effect(() => _$setAttribute(_el$20, "max", max()));
+  _$addEventListener(_el$20, "input", doSomethingElse, true);
+  return _el$18;
+})();
+const template21 = (() => {
+  var _el$21 = _tmpl$7();
+  _$style(_el$21, {
+    b: "static",
+    ...rest
+  });
+  return _el$21;
+})();
+const template22 = _tmpl$15();
+const template23 = (() => {
+  var _el$22 = _tmpl$7();
+  _el$22.disabled = "t" in test;
+  _$insert(_el$22, "t" in test && "true");
+  return _el$22;
+})();
+const template24 = (() => {
+  var _el$23 = _tmpl$16();
+  _$spread(_el$23, _$mergeProps(props, { something: "" }), false, false);
+  return _el$23;
+})();
+const template25 = (() => {
+  var _el$24 = _tmpl$17(), _el$25 = _el$24.firstChild;
+  _$insert(_el$24, () => props.children, _el$25);
+  _$spread(_el$25, _$mergeProps(props, { something: "" }), false, false);
+  return _el$24;
+})();
+const template26 = (() => {
+  var _el$26 = _tmpl$18();
+  _$effect(() => _$setAttribute(_el$26, "middle", middle));
+  _$spread(_el$26, spread, false, true);
+  return _el$26;
+})();
+const template27 = (() => {
+  var _el$27 = _tmpl$18();
+  _$spread(_el$27, _$mergeProps(first, { middle }, second), false, true);
+  return _el$27;
+})();
+const template28 = (() => {
+  var _el$28 = _tmpl$19(), _el$29 = _el$28.firstChild, _el$30 = _el$29.nextSibling, _el$31 = _el$30.nextSibling;
+  _$spread(_el$28, api(), false, true);
+  _$spread(_el$29, api(), false, true);
+  _$insert(_el$29, () => api() ? "checked" : "unchecked", null);
+  _$spread(_el$30, api(), false, false);
+  _$spread(_el$31, api(), false, false);
+  return _el$28;
+})();
+const template29 = (() => {
+  var _el$32 = _tmpl$7();
+  _$effect(() => _$setAttribute(_el$32, "attribute", !!someValue));
+  _$insert(_el$32, !!someValue);
+  return _el$32;
+})();
+const template30 = _tmpl$20();
+const template31 = (() => {
+  var _el$33 = _tmpl$7();
+  _$style(_el$33, { "background-color": getStore.itemProperties.color });
+  return _el$33;
+})();
+const template32 = (() => {
+  var _el$34 = _tmpl$7();
+  _$style(_el$34, { "background-color": undefined });
+  return _el$34;
+})();
+const template33 = [
+  (() => {
+    var _el$35 = _tmpl$21();
+    _$effect(() => _$setAttribute(_el$35, "class", styles.button));
+    return _el$35;
+  })(),
+  (() => {
+    var _el$36 = _tmpl$21();
+    _$effect(() => _$setAttribute(_el$36, "class", styles["foo--bar"]));
+    return _el$36;
+  })(),
+  (() => {
+    var _el$37 = _tmpl$21();
+    _$effect(() => _$setAttribute(_el$37, "class", styles.foo.bar));
+    return _el$37;
+  })(),
+  (() => {
+    var _el$38 = _tmpl$21();
+    _$effect(() => _$setAttribute(_el$38, "class", styles[foo()]));
+    return _el$38;
+  })()
+];
+const template34 = (() => {
+  var _el$39 = _tmpl$7();
+  _$use(something, _el$39, () => true);
+  _$use(zero, _el$39, () => 0);
+  _$spread(_el$39, somethingElse, false, false);
+  return _el$39;
+})();
+const template35 = (() => {
+  var _el$40 = _tmpl$7();
+  var _ref$4 = a().b.c;
+  typeof _ref$4 === "function" ? _$use(_ref$4, _el$40) : a().b.c = _el$40;
+  return _el$40;
+})();
+const template36 = (() => {
+  var _el$41 = _tmpl$7();
+  var _ref$5 = a().b?.c;
+  typeof _ref$5 === "function" && _$use(_ref$5, _el$41);
+  return _el$41;
+})();
+const template37 = (() => {
+  var _el$42 = _tmpl$7();
+  var _ref$6 = a() ? b : c;
+  typeof _ref$6 === "function" && _$use(_ref$6, _el$42);
+  return _el$42;
+})();
+const template38 = (() => {
+  var _el$43 = _tmpl$7();
+  var _ref$7 = a() ?? b;
+  typeof _ref$7 === "function" && _$use(_ref$7, _el$43);
+  return _el$43;
+})();
+const template39 = _tmpl$22();
+const template40 = (() => {
+  var _el$44 = _tmpl$7();
+  _$style(_el$44, { color: a() });
+  return _el$44;
+})();
+const template41 = (() => {
+  var _el$45 = _tmpl$23(), _el$46 = _el$45.firstChild, _el$47 = _el$46.nextSibling;
+  _$effect(() => _el$45.value = state.color);
+  _$effect(() => _el$46.value = Color.Red);
+  _$effect(() => _el$47.value = Color.Blue);
+  return _el$45;
+})();
+// bool:
+function boolTest() {
+  return true;
+}
+const boolTestBinding = false;
+const boolTestObjBinding = { value: false };
+const template42 = _tmpl$24();
+const template43 = _tmpl$25();
+const template44 = _tmpl$26();
+const template45 = _tmpl$27();
+const template46 = _tmpl$28();
+const template47 = _tmpl$29();
+const template48 = _tmpl$30();
+const template49 = _tmpl$31();
+const template50 = _tmpl$32();
+const template51 = _tmpl$33();
+const template52 = _tmpl$34();
+const template53 = _tmpl$35();
+const template54 = (() => {
+  var _el$48 = _tmpl$36();
+  _$effect(() => _$setBoolAttribute(_el$48, "quack", boolTest()));
+  return _el$48;
+})();
+const template55 = (() => {
+  var _el$49 = _tmpl$37();
+  _$setBoolAttribute(_el$49, "quack", boolTest);
+  return _el$49;
+})();
+const template56 = (() => {
+  var _el$50 = _tmpl$38();
+  _$setBoolAttribute(_el$50, "quack", boolTestBinding);
+  return _el$50;
+})();
+const template57 = (() => {
+  var _el$51 = _tmpl$39();
+  _$setBoolAttribute(_el$51, "quack", boolTestObjBinding.value);
+  return _el$51;
+})();
+const template58 = (() => {
+  var _el$52 = _tmpl$40();
+  _$setBoolAttribute(_el$52, "quack", () => false);
+  return _el$52;
+})();
+const template59 = _tmpl$41();
+const template60 = _tmpl$42();
+const template61 = _tmpl$43();
+// this crash it for some reason- */ const template62 = <div bool:quack>really empty</div>;
+const template63 = _tmpl$44();
+const template64 = _tmpl$45();
+const template65 = _tmpl$46();
+const template66 = _tmpl$47();
+const template67 = _tmpl$48();
+const template68 = _tmpl$49();
+const template69 = _tmpl$50();
+const template70 = _tmpl$51();
+const template71 = _tmpl$52();
+const template72 = (() => {
+  var _el$53 = _tmpl$7();
+  var _ref$8 = binding;
+  typeof _ref$8 === "function" ? _$use(_ref$8, _el$53) : binding = _el$53;
+  return _el$53;
+})();
+const template73 = (() => {
+  var _el$54 = _tmpl$7();
+  var _ref$9 = binding.prop;
+  typeof _ref$9 === "function" ? _$use(_ref$9, _el$54) : binding.prop = _el$54;
+  return _el$54;
+})();
+const template74 = (() => {
+  var _el$55 = _tmpl$7();
+  var _ref$10 = refFn;
+  typeof _ref$10 === "function" ? _$use(_ref$10, _el$55) : refFn = _el$55;
+  return _el$55;
+})();
+const template75 = (() => {
+  var _el$56 = _tmpl$7();
+  _$use(refConst, _el$56);
+  return _el$56;
+})();
+const template76 = (() => {
+  var _el$57 = _tmpl$7();
+  var _ref$11 = refUnknown;
+  typeof _ref$11 === "function" ? _$use(_ref$11, _el$57) : refUnknown = _el$57;
+  return _el$57;
+})();
+const template77 = _tmpl$53();
+const template78 = _tmpl$54();
+const template79 = (() => {
+  var _el$58 = _tmpl$7();
+  _el$58.true = true;
+  _el$58.false = false;
+  return _el$58;
+})();
+const template80 = (() => {
+  var _el$59 = _tmpl$7();
+  _$setAttribute(_el$59, "true", true);
+  _$setAttribute(_el$59, "false", false);
+  return _el$59;
+})();
+const template81 = _tmpl$55();
+const template82 = _tmpl$56();
+const template83 = _tmpl$57();
+const template84 = _tmpl$7();
+const template85 = (() => {
+  var _el$60 = _tmpl$7();
+  _$style(_el$60, {
+    "background": "red",
+    "color": "green",
+    "border": undefined
+  });
+  return _el$60;
+})();
+const template86 = (() => {
+  var _el$61 = _tmpl$7();
+  _$style(_el$61, {
+    "background": "red",
+    "color": "green",
+    "border": signal()
+  });
+  return _el$61;
+})();
+const template87 = (() => {
+  var _el$62 = _tmpl$7();
+  _$style(_el$62, {
+    "background": "red",
+    "color": "green",
+    "border": somevalue
+  });
+  return _el$62;
+})();
+const template88 = (() => {
+  var _el$63 = _tmpl$7();
+  _$style(_el$63, {
+    "background": "red",
+    "color": "green",
+    "border": some.access
+  });
+  return _el$63;
+})();
+const template89 = _tmpl$58();
+{
+  (() => {
+    var _el$64 = _tmpl$7();
+    _$style(_el$64, { "padding-left": `clamp(${1 + 1}px, ${1 + 1}px, ${1 + 1}px)` });
+    return _el$64;
+  })();
+}
+{
+  (() => {
+    var _el$65 = _tmpl$7();
+    _$style(_el$65, { a: `clamp(${1 + 1}px, ${1 + 1}px, ${1 + 1}px)` });
+    return _el$65;
+  })();
+}
+{
+  (() => {
+    var _el$66 = _tmpl$7();
+    _$style(_el$66, { [computedkey]: `clamp(${1 + 1}px, ${1 + 1}px, ${1 + 1}px)` });
+    return _el$66;
+  })();
+}
+{
+  const o = { ref: null };
+  const Div = (_) => [];
+  const valid = _$createComponent(Div, { get ref() {
+    return o.ref;
+  } });
+  const invalid = _$createComponent(Div, { get ref() {
+    return o?.ref;
+  } });
+}
+const template90 = _tmpl$59();
+_$delegateEvents(["click", "input"]);
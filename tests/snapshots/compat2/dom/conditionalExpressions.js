@@ -0,0 +1,219 @@
+import { template as _$template } from "r-dom";
+import { createComponent as _$createComponent } from "r-dom";
+import { insert as _$insert } from "r-dom";
+import { memo as _$memo } from "r-dom";
+import { effect as _$effect } from "r-dom";
+var _tmpl$ = /*#__PURE__*/ _$template(`<div>`),
+  _tmpl$2 = /*#__PURE__*/ _$template(`<div>Output`);
+const template1 = (() => {
+  var _el$ = _tmpl$();
+  _$insert(_el$, simple);
+  return _el$;
+})();
+const template2 = (() => {
+  var _el$1 = _tmpl$();
+  _$insert(_el$1, () => state.dynamic);
+  return _el$1;
+})();
+const template3 = (() => {
+  var _el$2 = _tmpl$();
+  _$insert(_el$2, simple ? good : bad);
+  return _el$2;
+})();
+const template4 = (() => {
+  var _el$3 = _tmpl$();
+  _$insert(_el$3, () => simple ? good() : bad);
+  return _el$3;
+})();
+const template4a = (() => {
+  var _el$4 = _tmpl$();
+  _$insert(_el$4, () => simple ? good.good : bad);
+  return _el$4;
+})();
+const template5 = (() => {
+  var _el$5 = _tmpl$();
+  _$insert(_el$5, () => state.dynamic ? good() : bad);
+  return _el$5;
+})();
+const template5a = (() => {
+  var _el$6 = _tmpl$();
+  _$insert(_el$6, () => state.dynamic ? good.good : bad);
+  return _el$6;
+})();
+const template6 = (() => {
+  var _el$7 = _tmpl$();
+  _$insert(_el$7, () => state.dynamic && good());
+  return _el$7;
+})();
+const template6a = (() => {
+  var _el$8 = _tmpl$();
+  _$insert(_el$8, () => state.dynamic && good.good);
+  return _el$8;
+})();
+const template7 = (() => {
+  var _el$9 = _tmpl$();
+  _$insert(_el$9, () => state.count > 5 ? state.dynamic ? best : good() : bad);
+  return _el$9;
+})();
+const template7a = (() => {
+  var _el$10 = _tmpl$();
+  _$insert(_el$10, () => state.count > 5 ? state.dynamic ? best : good.good : bad);
+  return _el$10;
+})();
+const template8 = (() => {
+  var _el$11 = _tmpl$();
+  _$insert(_el$11, () => state.dynamic && state.something && good());
+  return _el$11;
+})();
+const template8a = (() => {
+  var _el$12 = _tmpl$();
+  _$insert(_el$12, () => state.dynamic && state.something && good.good);
+  return _el$12;
+})();
+const template9 = (() => {
+  var _el$13 = _tmpl$();
+  _$insert(_el$13, () => state.dynamic && good() || bad);
+  return _el$13;
+})();
+const template9a = (() => {
+  var _el$14 = _tmpl$();
+  _$insert(_el$14, () => state.dynamic && good.good || bad);
+  return _el$14;
+})();
+const template10 = (() => {
+  var _el$15 = _tmpl$();
+  _$insert(_el$15, () => state.a ? "a" : state.b ? "b" : state.c ? "c" : "fallback");
+  return _el$15;
+})();
+const template11 = (() => {
+  var _el$16 = _tmpl$();
+  _$insert(_el$16, () => state.a ? a() : state.b ? b() : state.c ? "c" : "fallback");
+  return _el$16;
+})();
+const template11a = (() => {
+  var _el$17 = _tmpl$();
+  _$insert(_el$17, () => state.a ? a.a : state.b ? b.b : state.c ? "c" : "fallback");
+  return _el$17;
+})();
+const template12 = _$createComponent(Comp, { get render() {
+  return state.dynamic ? good() : bad;
+} });
+const template12a = _$createComponent(Comp, { get render() {
+  return state.dynamic ? good.good : bad;
+} });
+// no dynamic predicate
+const template13 = _$createComponent(Comp, { get render() {
+  return state.dynamic ? good : bad;
+} });
+const template14 = _$createComponent(Comp, { get render() {
+  return state.dynamic && good();
+} });
+const template14a = _$createComponent(Comp, { get render() {
+  return state.dynamic && good.good;
+} });
+// no dynamic predicate
+const template15 = _$createComponent(Comp, { get render() {
+  return state.dynamic && good;
+} });
+const template16 = _$createComponent(Comp, { get render() {
+  return state.dynamic || good();
+} });
+const template16a = _$createComponent(Comp, { get render() {
+  return state.dynamic || good.good;
+} });
+const template17 = _$createComponent(Comp, { get render() {
+  return state.dynamic ? _$createComponent(Comp, {}) : _$createComponent(Comp, {});
+} });
+const template18 = _$createComponent(Comp, { children: state.dynamic ? _$createComponent(Comp, {}) : _$createComponent(Comp, {}) });
+const template19 = (() => {
+  var _el$18 = _tmpl$();
+  _$effect(() => _el$18.innerHTML = state.dynamic ? _$createComponent(Comp, {}) : _$createComponent(Comp, {}));
+  return _el$18;
+})();
+const template20 = (() => {
+  var _el$19 = _tmpl$();
+  _$insert(_el$19, () => state.dynamic ? _$createComponent(Comp, {}) : _$createComponent(Comp, {}));
+  return _el$19;
+})();
+const template21 = _$createComponent(Comp, { get render() {
+  return state?.dynamic ? "a" : "b";
+} });
+const template22 = _$createComponent(Comp, { children: state?.dynamic ? "a" : "b" });
+const template23 = (() => {
+  var _el$20 = _tmpl$();
+  _$effect(() => _el$20.innerHTML = state?.dynamic ? "a" : "b");
+  return _el$20;
+})();
+const template24 = (() => {
+  var _el$21 = _tmpl$();
+  _$insert(_el$21, () => state?.dynamic ? "a" : "b");
+  return _el$21;
+})();
+const template25 = _$createComponent(Comp, { get render() {
+  return state.dynamic ?? _$createComponent(Comp, {});
+} });
+const template26 = _$createComponent(Comp, { children: state.dynamic ?? _$createComponent(Comp, {}) });
+const template27 = (() => {
+  var _el$22 = _tmpl$();
+  _$effect(() => _el$22.innerHTML = state.dynamic ?? _$createComponent(Comp, {}));
+  return _el$22;
+})();
+const template28 = (() => {
+  var _el$23 = _tmpl$();
+  _$insert(_el$23, () => state.dynamic ?? _$createComponent(Comp, {}));
+  return _el$23;
+})();
+const template29 = (() => {
+  var _el$24 = _tmpl$();
+  _$insert(_el$24, () => (thing() && thing1()) ?? thing2() ?? thing3());
+  return _el$24;
+})();
+const template29a = (() => {
+  var _el$25 = _tmpl$();
+  _$insert(_el$25, () => (thing.thing && thing1.thing1) ?? thing2.thing2 ?? thing3.thing3);
+  return _el$25;
+})();
+const template30 = (() => {
+  var _el$26 = _tmpl$();
+  _$insert(_el$26, () => thing() || thing1() || thing2());
+  return _el$26;
+})();
+const template30a = (() => {
+  var _el$27 = _tmpl$();
+  _$insert(_el$27, () => thing.thing || thing1.thing1 || thing2.thing2);
+  return _el$27;
+})();
+const template31 = _$createComponent(Comp, { get value() {
+  return count() ? count() ? count() : count() : count();
+} });
+const template31a = _$createComponent(Comp, { get value() {
+  return count.count ? count.count ? count.count : count.count : count.count;
+} });
+const template32 = (() => {
+  var _el$28 = _tmpl$();
+  _$insert(_el$28, () => something?.());
+  return _el$28;
+})();
+const template32a = (() => {
+  var _el$29 = _tmpl$();
+  _$insert(_el$29, () => something?.something);
+  return _el$29;
+})();
+const template33 = _$createComponent(Comp, { children: something?.() });
+const template33a = _$createComponent(Comp, { children: something?.something });
+const template34 = simple ? good : bad;
+const template35 = _$memo(() => simple ? good() : bad);
+const template35a = _$memo(() => simple ? good.good : bad);
+const template36 = _$memo(() => state.dynamic ? good() : bad);
+const template36a = _$memo(() => state.dynamic ? good.good : bad);
+const template37 = _$memo(() => state.dynamic && good());
+const template37a = _$memo(() => state.dynamic && good.good);
+const template38 = _$memo(() => state.count > 5 ? state.dynamic ? best : good() : bad);
+const template38a = _$memo(() => state.count > 5 ? state.dynamic ? best : good.good : bad.bad);
+const template39 = _$memo(() => state.dynamic && state.something && good());
+const template40 = _$memo(() => state.dynamic && good() || bad);
+const template40a = _$memo(() => state.dynamic && good.good || bad);
+const template41 = _$memo(() => state.a ? "a" : state.b ? "b" : state.c ? "c" : "fallback");
+const template42 = _$memo(() => state.a ? a() : state.b ? b() : state.c ? "c" : "fallback");
+const template42a = _$memo(() => state.a ? a.a : state.b ? b.b : state.c ? "c" : "fallback");
+const template43 = _$memo(() => obj1.prop ? obj2.prop ? _tmpl$2() : [] : []);
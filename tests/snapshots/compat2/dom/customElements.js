@@ -0,0 +1,94 @@
+import { template as _$template } from "r-dom";
+import { setBoolAttribute as _$setBoolAttribute } from "r-dom";
+import { setAttribute as _$setAttribute } from "r-dom";
+import { effect as _$effect } from "r-dom";
+var _tmpl$ = /*#__PURE__*/ _$template(`<my-element>`),
+  _tmpl$2 = /*#__PURE__*/ _$template(`<my-element><header slot=head>Title`),
+  _tmpl$3 = /*#__PURE__*/ _$template(`<slot name=head>`),
+  _tmpl$4 = /*#__PURE__*/ _$template(`<a is=my-element>`),
+  _tmpl$5 = /*#__PURE__*/ _$template(`<my-el>empty string`),
+  _tmpl$6 = /*#__PURE__*/ _$template(`<my-el>js empty`),
+  _tmpl$7 = /*#__PURE__*/ _$template(`<my-el quack>hola`),
+  _tmpl$8 = /*#__PURE__*/ _$template(`<my-el quack>"hola js"`),
+  _tmpl$9 = /*#__PURE__*/ _$template(`<my-el quack>true`),
+  _tmpl$10 = /*#__PURE__*/ _$template(`<my-el>false`),
+  _tmpl$11 = /*#__PURE__*/ _$template(`<my-el quack>1`),
+  _tmpl$12 = /*#__PURE__*/ _$template(`<my-el>0`),
+  _tmpl$13 = /*#__PURE__*/ _$template(`<my-el quack>"1"`),
+  _tmpl$14 = /*#__PURE__*/ _$template(`<my-el>"0"`),
+  _tmpl$15 = /*#__PURE__*/ _$template(`<my-el>undefined`),
+  _tmpl$16 = /*#__PURE__*/ _$template(`<my-el>null`),
+  _tmpl$17 = /*#__PURE__*/ _$template(`<my-el>boolTest()`),
+  _tmpl$18 = /*#__PURE__*/ _$template(`<my-el>boolTest`),
+  _tmpl$19 = /*#__PURE__*/ _$template(`<my-el>boolTestBinding`),
+  _tmpl$20 = /*#__PURE__*/ _$template(`<my-el>boolTestObjBinding.value`),
+  _tmpl$21 = /*#__PURE__*/ _$template(`<my-el>fn`),
+  _tmpl$22 = /*#__PURE__*/ _$template(`<my-el before quack>should have space before`),
+  _tmpl$23 = /*#__PURE__*/ _$template(`<my-el before quack after>should have space before/after`),
+  _tmpl$24 = /*#__PURE__*/ _$template(`<my-el quack after>should have space before/after`);
+const template = (() => {
+  var _el$ = _tmpl$();
+  _el$.someProp = data;
+  _$effect(() => _$setAttribute(_el$, "some-attr", name));
+  _$effect(() => _$setAttribute(_el$, "notProp", data));
+  _$setAttribute(_el$, "my-attr", data);
+  return _el$;
+})();
+const template2 = (() => {
+  var _el$1 = _tmpl$();
+  _$effect(() => _el$1.someProp = state.data);
+  _$effect(() => _$setAttribute(_el$1, "some-attr", state.name));
+  _$effect(() => _$setAttribute(_el$1, "notProp", state.data));
+  _$setAttribute(_el$1, "my-attr", state.data);
+  return _el$1;
+})();
+const template3 = _tmpl$2();
+const template4 = _tmpl$3();
+const template5 = _tmpl$4();
+// bool:
+function boolTest() {
+  return true;
+}
+const boolTestBinding = false;
+const boolTestObjBinding = { value: false };
+const template42 = _tmpl$5();
+const template43 = _tmpl$6();
+const template44 = _tmpl$7();
+const template45 = _tmpl$8();
+const template46 = _tmpl$9();
+const template47 = _tmpl$10();
+const template48 = _tmpl$11();
+const template49 = _tmpl$12();
+const template50 = _tmpl$13();
+const template51 = _tmpl$14();
+const template52 = _tmpl$15();
+const template53 = _tmpl$16();
+const template54 = (() => {
+  var _el$2 = _tmpl$17();
+  _$effect(() => _$setBoolAttribute(_el$2, "quack", boolTest()));
+  return _el$2;
+})();
+const template55 = (() => {
+  var _el$3 = _tmpl$18();
+  _$setBoolAttribute(_el$3, "quack", boolTest);
+  return _el$3;
+})();
+const template56 = (() => {
+  var _el$4 = _tmpl$19();
+  _$setBoolAttribute(_el$4, "quack", boolTestBinding);
+  return _el$4;
+})();
+const template57 = (() => {
+  var _el$5 = _tmpl$20();
+  _$setBoolAttribute(_el$5, "quack", boolTestObjBinding.value);
+  return _el$5;
+})();
+const template58 = (() => {
+  var _el$6 = _tmpl$21();
+  _$setBoolAttribute(_el$6, "quack", () => false);
+  return _el$6;
+})();
+const template59 = _tmpl$22();
+const template60 = _tmpl$23();
+const template61 = _tmpl$24();
+// this crash it for some reason- */ const template62 = <div bool:quack>really empty</div>;
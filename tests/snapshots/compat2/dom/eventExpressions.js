@@ -0,0 +1,41 @@
+import { template as _$template } from "r-dom";
+import { delegateEvents as _$delegateEvents } from "r-dom";
+import { addEventListener as _$addEventListener } from "r-dom";
+var _tmpl$ = /*#__PURE__*/ _$template(`<div id=main><button>Change Bound</button><button>Change Bound</button><button>Change Bound</button><button>Change Bound</button><button>Change Bound</button><button>Click Delegated</button><button>Click Delegated</button><button>Click Delegated</button><button>Click Delegated</button><button>Click Delegated</button><button>Click Listener</button><button>Click Capture`);
+function hoisted1() {
+  console.log("hoisted");
+}
+const hoisted2 = () => console.log("hoisted delegated");
+function hoistedCustomEvent1() {
+  console.log("hoisted");
+}
+const hoistedCustomEvent2 = () => console.log("hoisted");
+const template = (() => {
+  var _el$ = _tmpl$(), _el$1 = _el$.firstChild, _el$2 = _el$1.nextSibling, _el$3 = _el$2.nextSibling, _el$4 = _el$3.nextSibling, _el$5 = _el$4.nextSibling, _el$6 = _el$5.nextSibling, _el$7 = _el$6.nextSibling, _el$8 = _el$7.nextSibling, _el$9 = _el$8.nextSibling, _el$10 = _el$9.nextSibling, _el$11 = _el$10.nextSibling, _el$12 = _el$11.nextSibling;
+  _el$1.addEventListener("change", () => console.log("bound"));
+  _el$2.addEventListener("change", (e) => ((id) => console.log("bound", id))(id, e));
+  _$addEventListener(_el$3, "change", handler);
+  _el$4.addEventListener("change", handler);
+  _$addEventListener(_el$5, "change", hoisted1);
+  _el$6.$$click = () => console.log("delegated");
+  _el$7.$$click = (id) => console.log("delegated", id);
+  _el$7.$$clickData = rowId;
+  _$addEventListener(_el$8, "click", handler, true);
+  _el$9.$$click = handler;
+  _$addEventListener(_el$10, "click", hoisted2, true);
+  _$addEventListener(_el$11, "click", () => console.log("listener"));
+  _$addEventListener(_el$11, "CAPS-ev", () => console.log("custom"));
+  _$addEventListener(_el$11, "hoisted-custom-event1", hoistedCustomEvent1);
+  _$addEventListener(_el$11, "hoisted-custom-event2", hoistedCustomEvent2);
+  _$addEventListener(_el$11, "inlined", () => console.log("listener"));
+  var _listener$ = {
+    handleEvent: () => console.log("listener"),
+    once: false
+  };
+  _el$11.addEventListener("inlined-with-options", _listener$, _listener$);
+  _el$11.addEventListener("inlined-to-hoisted1", { handleEvent: hoistedCustomEvent1 });
+  _el$11.addEventListener("inlined-to-hoisted2", { handleEvent: hoistedCustomEvent2 });
+  _el$12.addEventListener("camelClick", () => console.log("listener"), true);
+  return _el$;
+})();
+_$delegateEvents(["click"]);
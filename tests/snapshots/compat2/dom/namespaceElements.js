@@ -0,0 +1 @@
+// Unexpected token
@@ -0,0 +1,48 @@
+import { template as _$template } from "r-dom";
+import { createComponent as _$createComponent } from "r-dom";
+import { memo as _$memo } from "r-dom";
+import { style as _$style } from "r-dom";
+import { setAttribute as _$setAttribute } from "r-dom";
+import { effect as _$effect } from "r-dom";
+import { getNextElement as _$getNextElement } from "r-dom";
+import { spread as _$spread } from "r-dom";
+import { setAttributeNS as _$setAttributeNS } from "r-dom";
+var _tmpl$ = /*#__PURE__*/ _$template(`<svg width=400 height=180><rect stroke-width=2 x=50 y=20 rx=20 ry=20 width=150 height=150 style="fill:red;stroke:black;stroke-width:5;opacity:0.5"></rect><linearGradient gradientTransform="rotate(25)"><stop offset="0%">`),
+  _tmpl$2 = /*#__PURE__*/ _$template(`<svg width=400 height=180><rect rx=20 ry=20 width=150 height=150>`),
+  _tmpl$3 = /*#__PURE__*/ _$template(`<svg width=400 height=180><rect>`),
+  _tmpl$4 = /*#__PURE__*/ _$template(`<svg><rect x=50 y=20 width=150 height=150></svg>`, false, true, false),
+  _tmpl$5 = /*#__PURE__*/ _$template(`<svg viewBox="0 0 160 40" xmlns=http://www.w3.org/2000/svg><a><text x=10 y=25>MDN Web Docs`),
+  _tmpl$6 = /*#__PURE__*/ _$template(`<svg viewBox="0 0 160 40" xmlns=http://www.w3.org/2000/svg><text x=10 y=25>`);
+const template = _$getNextElement(_tmpl$);
+const template2 = (() => {
+  var _el$ = _$getNextElement(_tmpl$2), _el$1 = _el$.firstChild;
+  _$effect(() => _$setAttribute(_el$1, "class", state.name));
+  _$effect(() => _$setAttribute(_el$1, "stroke-width", state.width));
+  _$effect(() => _$setAttribute(_el$1, "x", state.x));
+  _$effect(() => _$setAttribute(_el$1, "y", state.y));
+  _$style(_el$1, {
+    fill: "red",
+    stroke: "black",
+    "stroke-width": props.stroke,
+    opacity: .5
+  });
+  return _el$;
+})();
+const template3 = (() => {
+  var _el$2 = _$getNextElement(_tmpl$3), _el$3 = _el$2.firstChild;
+  _$spread(_el$3, props, true, false);
+  return _el$2;
+})();
+const template4 = _$getNextElement(_tmpl$4);
+const template5 = _$memo(_$getNextElement(_tmpl$4));
+const template6 = _$createComponent(Component, { children: _$getNextElement(_tmpl$4) });
+const template7 = (() => {
+  var _el$4 = _$getNextElement(_tmpl$5), _el$5 = _el$4.firstChild;
+  _$effect(() => _$setAttributeNS(_el$5, "http://www.w3.org/1999/xlink", "href", url));
+  return _el$4;
+})();
+const template8 = (() => {
+  var _el$6 = _$getNextElement(_tmpl$6), _el$7 = _el$6.firstChild;
+  _el$7.textContent = text;
+  return _el$6;
+})();
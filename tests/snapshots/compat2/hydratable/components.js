@@ -0,0 +1,154 @@
+import { template as _$template } from "r-dom";
+import { createComponent as _$createComponent } from "r-dom";
+import { insert as _$insert } from "r-dom";
+import { getNextElement as _$getNextElement } from "r-dom";
+import { use as _$use } from "r-dom";
+import { spread as _$spread } from "r-dom";
+import { mergeProps as _$mergeProps } from "r-dom";
+import { For as _$For } from "r-dom";
+import { Show as _$Show } from "r-dom";
+var _tmpl$ = /*#__PURE__*/ _$template(`<div>Hello `),
+  _tmpl$2 = /*#__PURE__*/ _$template(`<div>`),
+  _tmpl$3 = /*#__PURE__*/ _$template(`<div><Child name=John><div>From Parent</div></Child><Child name=Jason><div></div></Child><div>`),
+  _tmpl$4 = /*#__PURE__*/ _$template(`<_garbage>Hi`),
+  _tmpl$5 = /*#__PURE__*/ _$template(`<div><Link> | <Link> | <Link> | <Link> | <Link> | <Link>`),
+  _tmpl$6 = /*#__PURE__*/ _$template(`<div><Link> | <Link><Link> | <Link><Link> | <Link>`),
+  _tmpl$7 = /*#__PURE__*/ _$template(`<div> | <Link> |  |  | <Link> | `),
+  _tmpl$8 = /*#__PURE__*/ _$template(`<span>1`),
+  _tmpl$9 = /*#__PURE__*/ _$template(`<span>2`),
+  _tmpl$10 = /*#__PURE__*/ _$template(`<span>3`);
+import { Show } from "somewhere";
+const Child = (props) => {
+  const [s, set] = createSignal();
+  return [(() => {
+    var _el$ = _$getNextElement(_tmpl$), _el$1 = _el$.firstChild;
+    var _ref$ = props.ref;
+    typeof _ref$ === "function" ? _$use(_ref$, _el$) : props.ref = _el$;
+    _$insert(_el$, () => props.name, null);
+    return _el$;
+  })(), (() => {
+    var _el$2 = _$getNextElement(_tmpl$2);
+    _$use(set, _el$2);
+    _$insert(_el$2, () => props.children);
+    return _el$2;
+  })()];
+};
+const template = (props) => {
+  let childRef;
+  const { content } = props;
+  return (() => {
+    var _el$3 = _$getNextElement(_tmpl$3), _el$4 = _el$3.firstChild, _el$5 = _el$4.nextSibling, _el$6 = _el$5.firstChild, _el$7 = _el$5.nextSibling;
+    var _ref$2 = childRef;
+    typeof _ref$2 === "function" ? _$use(_ref$2, _el$4) : childRef = _el$4;
+    _$spread(_el$4, _$mergeProps(props, { booleanProperty: "" }), false, true);
+    var _ref$3 = props.ref;
+    typeof _ref$3 === "function" ? _$use(_ref$3, _el$5) : props.ref = _el$5;
+    _$spread(_el$5, dynamicSpread(), false, true);
+    _$insert(_el$6, content);
+    var _ref$4 = props.consumerRef();
+    typeof _ref$4 === "function" && _$use(_ref$4, _el$7);
+    _$insert(_el$7, (context) => context);
+    return _el$3;
+  })();
+};
+const template2 = _$createComponent(Child, {
+  name: "Jake",
+  get dynamic() {
+    return state.data;
+  },
+  get stale() {
+    return state.data;
+  },
+  handleClick: clickHandler,
+  get "hyphen-ated"() {
+    return state.data;
+  },
+  ref: (el) => e = el
+});
+const template3 = _$createComponent(Child, { get children() {
+  return [
+    _$getNextElement(_tmpl$2),
+    _$getNextElement(_tmpl$2),
+    _$getNextElement(_tmpl$2),
+    "After"
+  ];
+} });
+const [s, set] = createSignal();
+const template4 = _$createComponent(Child, {
+  ref: set,
+  children: _$getNextElement(_tmpl$2)
+});
+const template5 = _$createComponent(Child, {
+  get dynamic() {
+    return state.dynamic;
+  },
+  children: state.dynamic
+});
+// builtIns
+const template6 = _$createComponent(_$For, {
+  get each() {
+    return state.list;
+  },
+  get fallback() {
+    return _$createComponent(Loading, {});
+  },
+  children: (item) => _$createComponent(_$Show, {
+    get when() {
+      return state.condition;
+    },
+    children: item
+  })
+});
+const template7 = _$createComponent(Child, { children: [_$getNextElement(_tmpl$2), state.dynamic] });
+const template8 = _$createComponent(Child, { children: [(item) => item, (item) => item] });
+const template9 = _$getNextElement(_tmpl$4);
+const template10 = _$getNextElement(_tmpl$5);
+const template11 = _$getNextElement(_tmpl$6);
+const template12 = _$getNextElement(_tmpl$7);
+class Template13 {
+  render() {
+    _$createComponent(Component, {
+      get prop() {
+        return this.something;
+      },
+      onClick: () => this.shouldStay,
+      children: _$createComponent(Nested, {
+        get prop() {
+          return this.data;
+        },
+        children: this.content
+      })
+    });
+  }
+}
+const Template14 = _$createComponent(Component, { children: data() });
+const Template15 = _$createComponent(Component, props);
+const Template16 = _$createComponent(Component, _$mergeProps({ something }, props));
+const Template17 = _$createComponent(Pre, { get children() {
+  return [
+    _$getNextElement(_tmpl$8),
+    " ",
+    _$getNextElement(_tmpl$9),
+    " ",
+    _$getNextElement(_tmpl$10)
+  ];
+} });
+const Template18 = _$createComponent(Pre, { children: [
+  _$getNextElement(_tmpl$8),
+  _$getNextElement(_tmpl$9),
+  _$getNextElement(_tmpl$10)
+] });
+const Template19 = _$createComponent(Component, s.dynamic());
+const Template20 = _$createComponent(Component, { get class() {
+  return prop.red ? "red" : "green";
+} });
+const template21 = _$createComponent(Component, { get [key()]() {
+  return props.value;
+} });
+const template22 = _$createComponent(Component, { passObject: { ...a } });
+const template23 = _$createComponent(Component, {
+  disabled: "t" in test,
+  children: "t" in test && "true"
+});
+const template24 = _$createComponent(Component, { children: state.dynamic });
+const template25 = _$createComponent(Component, { children: _$getNextElement(_tmpl$2) });
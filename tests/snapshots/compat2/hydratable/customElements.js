@@ -0,0 +1,28 @@
+import { template as _$template } from "r-dom";
+import { memo as _$memo } from "r-dom";
+import { setAttribute as _$setAttribute } from "r-dom";
+import { effect as _$effect } from "r-dom";
+import { getNextElement as _$getNextElement } from "r-dom";
+var _tmpl$ = /*#__PURE__*/ _$template(`<my-element>`),
+  _tmpl$2 = /*#__PURE__*/ _$template(`<my-element><header slot=head>Title`),
+  _tmpl$3 = /*#__PURE__*/ _$template(`<slot name=head>`),
+  _tmpl$4 = /*#__PURE__*/ _$template(`<a is=my-element>`);
+const template = (() => {
+  var _el$ = _$getNextElement(_tmpl$);
+  _el$.someProp = data;
+  _$effect(() => _$setAttribute(_el$, "some-attr", name));
+  _$effect(() => _$setAttribute(_el$, "notProp", data));
+  _$setAttribute(_el$, "my-attr", data);
+  return _el$;
+})();
+const template2 = (() => {
+  var _el$1 = _$getNextElement(_tmpl$);
+  _$effect(() => _el$1.someProp = state.data);
+  _$effect(() => _$setAttribute(_el$1, "some-attr", state.name));
+  _$effect(() => _$setAttribute(_el$1, "notProp", state.data));
+  _$setAttribute(_el$1, "my-attr", state.data);
+  return _el$1;
+})();
+const template3 = _$getNextElement(_tmpl$2);
+const template4 = _$memo(_$getNextElement(_tmpl$3));
+const template5 = _$getNextElement(_tmpl$4);
@@ -0,0 +1,10 @@
+import { template as _$template } from "r-dom";
+import { getNextElement as _$getNextElement } from "r-dom";
+var _tmpl$ = /*#__PURE__*/ _$template(`<html><head><title>🔥 Blazing 🔥</title><meta charset=UTF-8><meta name=viewport content="width=device-width, initial-scale=1.0"><link rel=stylesheet href=/styles.css><Assets></Assets></head><body><header><h1>Welcome to the Jungle</h1></header><App></App><footer>The Bottom`),
+  _tmpl$2 = /*#__PURE__*/ _$template(`<head><title>🔥 Blazing 🔥</title><meta charset=UTF-8><meta name=viewport content="width=device-width, initial-scale=1.0"><link rel=stylesheet href=/styles.css><Assets>`),
+  _tmpl$3 = /*#__PURE__*/ _$template(`<body><header><h1>Welcome to the Jungle</h1></header><App></App><footer>The Bottom`),
+  _tmpl$4 = /*#__PURE__*/ _$template(`<html><Head></Head><Body>`);
+const template = _$getNextElement(_tmpl$);
+const templateHead = _$getNextElement(_tmpl$2);
+const templateBody = _$getNextElement(_tmpl$3);
+const templateEmptied = _$getNextElement(_tmpl$4);
@@ -0,0 +1,32 @@
+import { template as _$template } from "r-dom";
+import { delegateEvents as _$delegateEvents } from "r-dom";
+import { addEventListener as _$addEventListener } from "r-dom";
+import { getNextElement as _$getNextElement } from "r-dom";
+var _tmpl$ = /*#__PURE__*/ _$template(`<div id=main><button>Change Bound</button><button>Change Bound</button><button>Click Delegated</button><button>Click Delegated</button><button>Click Listener</button><button>Click Capture`);
+function hoistedCustomEvent1() {
+  console.log("hoisted");
+}
+const hoistedcustomevent2 = () => console.log("hoisted");
+const template = (() => {
+  var _el$ = _$getNextElement(_tmpl$), _el$1 = _el$.firstChild, _el$2 = _el$1.nextSibling, _el$3 = _el$2.nextSibling, _el$4 = _el$3.nextSibling, _el$5 = _el$4.nextSibling, _el$6 = _el$5.nextSibling;
+  _el$1.addEventListener("change", () => console.log("bound"));
+  _el$2.addEventListener("change", (e) => ((id) => console.log("bound", id))(id, e));
+  _el$3.$$click = () => console.log("delegated");
+  _el$4.$$click = (id) => console.log("delegated", id);
+  _el$4.$$clickData = rowId;
+  _$addEventListener(_el$5, "click", () => console.log("listener"));
+  _$addEventListener(_el$5, "CAPS-ev2", () => console.log("custom"));
+  _$addEventListener(_el$5, "hoisted-custom-event1", hoistedCustomEvent1);
+  _$addEventListener(_el$5, "hoisted-custom-event2", hoistedCustomEvent2);
+  _$addEventListener(_el$5, "inlined", () => console.log("listener"));
+  var _listener$ = {
+    handleEvent: () => console.log("listener"),
+    once: false
+  };
+  _el$5.addEventListener("inlined-with-options", _listener$, _listener$);
+  _el$5.addEventListener("inlined-to-hoisted1", { handleEvent: hoistedCustomEvent1 });
+  _el$5.addEventListener("inlined-to-hoisted2", { handleEvent: hoistedcustomevent2 });
+  _el$6.addEventListener("camelClick", () => console.log("listener"), true);
+  return _el$;
+})();
+_$delegateEvents(["click"]);
@@ -0,0 +1,16 @@
+import { template as _$template } from "r-dom";
+import { createComponent as _$createComponent } from "r-dom";
+import { insert as _$insert } from "r-dom";
+import { memo as _$memo } from "r-dom";
+import { getNextElement as _$getNextElement } from "r-dom";
+var _tmpl$ = /*#__PURE__*/ _$template(`<div $ServerOnly><h1>Hello</h1><Component></Component><!><span>More Text`),
+  _tmpl$2 = /*#__PURE__*/ _$template(`<div $ServerOnly>`),
+  _tmpl$3 = /*#__PURE__*/ _$template(`<span $ServerOnly>`);
+const template = (() => {
+  var _el$ = _$getNextElement(_tmpl$), _el$1 = _el$.firstChild, _el$2 = _el$1.nextSibling, _el$3 = _el$2.nextSibling;
+  _$insert(_el$, () => state.interpolation, _el$3);
+  return _el$;
+})();
+const template2 = _$createComponent(Component, { children: _$getNextElement(_tmpl$2) });
+const template3 = _$createComponent(Component, { children: [_$getNextElement(_tmpl$2), _$getNextElement(_tmpl$3)] });
+const template4 = _$memo(_$getNextElement(_tmpl$2));
@@ -0,0 +1,53 @@
+import { template as _$template } from "r-dom";
+import { createComponent as _$createComponent } from "r-dom";
+import { memo as _$memo } from "r-dom";
+import { setAttribute as _$setAttribute } from "r-dom";
+import { effect as _$effect } from "r-dom";
+import { getNextElement as _$getNextElement } from "r-dom";
+var _tmpl$ = /*#__PURE__*/ _$template(`<div>First`),
+  _tmpl$2 = /*#__PURE__*/ _$template(`<div>Last`),
+  _tmpl$3 = /*#__PURE__*/ _$template(`<div>`),
+  _tmpl$4 = /*#__PURE__*/ _$template(`<span>1`),
+  _tmpl$5 = /*#__PURE__*/ _$template(`<span>2`),
+  _tmpl$6 = /*#__PURE__*/ _$template(`<span>3`);
+const multiStatic = [_$memo(_$getNextElement(_tmpl$)), _$memo(_$getNextElement(_tmpl$2))];
+const multiExpression = [
+  _$memo(_$getNextElement(_tmpl$)),
+  inserted,
+  _$memo(_$getNextElement(_tmpl$2)),
+  "After"
+];
+const multiDynamic = [
+  (() => {
+    var _el$ = _$getNextElement(_tmpl$);
+    _$effect(() => _$setAttribute(_el$, "id", state.first));
+    return _el$;
+  })(),
+  _$memo(() => state.inserted),
+  (() => {
+    var _el$1 = _$getNextElement(_tmpl$2);
+    _$effect(() => _$setAttribute(_el$1, "id", state.last));
+    return _el$1;
+  })(),
+  "After"
+];
+const singleExpression = inserted;
+const singleDynamic = _$memo(inserted);
+const firstStatic = [inserted, _$memo(_$getNextElement(_tmpl$3))];
+const firstDynamic = [_$memo(inserted), _$memo(_$getNextElement(_tmpl$3))];
+const firstComponent = [_$createComponent(Component, {}), _$memo(_$getNextElement(_tmpl$3))];
+const lastStatic = [_$memo(_$getNextElement(_tmpl$3)), inserted];
+const lastDynamic = [_$memo(_$getNextElement(_tmpl$3)), _$memo(inserted)];
+const lastComponent = [_$memo(_$getNextElement(_tmpl$3)), _$createComponent(Component, {})];
+const spaces = [
+  _$memo(_$getNextElement(_tmpl$4)),
+  " ",
+  _$memo(_$getNextElement(_tmpl$5)),
+  " ",
+  _$memo(_$getNextElement(_tmpl$6))
+];
+const multiLineTrailing = [
+  _$memo(_$getNextElement(_tmpl$4)),
+  _$memo(_$getNextElement(_tmpl$5)),
+  _$memo(_$getNextElement(_tmpl$6))
+];
@@ -0,0 +1,119 @@
+import { template as _$template } from "r-dom";
+import { createComponent as _$createComponent } from "r-dom";
+import { insert as _$insert } from "r-dom";
+import { getNextElement as _$getNextElement } from "r-dom";
+var _tmpl$ = /*#__PURE__*/ _$template(`<span>Hello `),
+  _tmpl$2 = /*#__PURE__*/ _$template(`<span> John`),
+  _tmpl$3 = /*#__PURE__*/ _$template(`<span>Hello John`),
+  _tmpl$4 = /*#__PURE__*/ _$template(`<span> `),
+  _tmpl$5 = /*#__PURE__*/ _$template(`<span> <!> <!> `),
+  _tmpl$6 = /*#__PURE__*/ _$template(`<span> <!> `),
+  _tmpl$7 = /*#__PURE__*/ _$template(`<span>Hello`),
+  _tmpl$8 = /*#__PURE__*/ _$template(`<span>&nbsp;&lt;Hi>&nbsp;`),
+  _tmpl$9 = /*#__PURE__*/ _$template(`<span>Hi&lt;script>alert();&lt;/script>`),
+  _tmpl$10 = /*#__PURE__*/ _$template(`<span>4 + 5 = `),
+  _tmpl$11 = /*#__PURE__*/ _$template(`<div>
+d`),
+  _tmpl$12 = /*#__PURE__*/ _$template(`<div>`),
+  _tmpl$13 = /*#__PURE__*/ _$template(`<div normal="Search…" title="Search&amp;hellip;">`),
+  _tmpl$14 = /*#__PURE__*/ _$template(`<div><div>`);
+const trailing = _$getNextElement(_tmpl$);
+const leading = _$getNextElement(_tmpl$2);
+/* prettier-ignore */
+const extraSpaces = _$getNextElement(_tmpl$3);
+const trailingExpr = (() => {
+  var _el$ = _$getNextElement(_tmpl$), _el$1 = _el$.firstChild;
+  _$insert(_el$, name, null);
+  return _el$;
+})();
+const leadingExpr = (() => {
+  var _el$2 = _$getNextElement(_tmpl$2), _el$3 = _el$2.firstChild;
+  _$insert(_el$2, greeting, _el$3);
+  return _el$2;
+})();
+/* prettier-ignore */
+const multiExpr = (() => {
+  var _el$4 = _$getNextElement(_tmpl$4), _el$5 = _el$4.firstChild;
+  _$insert(_el$4, greeting, _el$5);
+  _$insert(_el$4, name, null);
+  return _el$4;
+})();
+/* prettier-ignore */
+const multiExprSpaced = (() => {
+  var _el$6 = _$getNextElement(_tmpl$5), _el$7 = _el$6.firstChild, _el$8 = _el$7.nextSibling, _el$9 = _el$8.nextSibling, _el$10 = _el$9.nextSibling;
+  _$insert(_el$6, greeting, _el$8);
+  _$insert(_el$6, name, _el$10);
+  return _el$6;
+})();
+/* prettier-ignore */
+const multiExprTogether = (() => {
+  var _el$11 = _$getNextElement(_tmpl$6), _el$12 = _el$11.firstChild, _el$13 = _el$12.nextSibling;
+  _$insert(_el$11, greeting, _el$13);
+  _$insert(_el$11, name, _el$13);
+  return _el$11;
+})();
+/* prettier-ignore */
+const multiLine = _$getNextElement(_tmpl$7);
+/* prettier-ignore */
+const multiLineTrailingSpace = _$getNextElement(_tmpl$3);
+/* prettier-ignore */
+const multiLineNoTrailingSpace = _$getNextElement(_tmpl$3);
+/* prettier-ignore */
+const escape = _$getNextElement(_tmpl$8);
+/* prettier-ignore */
+const escape2 = _$createComponent(Comp, { children: "\xA0<Hi>\xA0" });
+/* prettier-ignore */
+const escape3 = "\xA0<Hi>\xA0";
+const injection = _$getNextElement(_tmpl$9);
+let value = "World";
+const evaluated = (() => {
+  var _el$14 = _$getNextElement(_tmpl$), _el$15 = _el$14.firstChild;
+  _$insert(_el$14, value + "!", null);
+  return _el$14;
+})();
+let number = 4 + 5;
+const evaluatedNonString = (() => {
+  var _el$16 = _$getNextElement(_tmpl$10), _el$17 = _el$16.firstChild;
+  _$insert(_el$16, number, null);
+  return _el$16;
+})();
+const newLineLiteral = (() => {
+  var _el$18 = _$getNextElement(_tmpl$11), _el$19 = _el$18.firstChild;
+  _$insert(_el$18, s, _el$19);
+  return _el$18;
+})();
+const trailingSpace = (() => {
+  var _el$20 = _$getNextElement(_tmpl$12);
+  _$insert(_el$20, expr);
+  return _el$20;
+})();
+const trailingSpaceComp = _$createComponent(Comp, { children: expr });
+const trailingSpaceFrag = expr;
+const leadingSpaceElement = (() => {
+  var _el$21 = _$getNextElement(_tmpl$4), _el$22 = _el$21.firstChild;
+  _$insert(_el$21, expr, null);
+  return _el$21;
+})();
+const leadingSpaceComponent = _$createComponent(Div, { get children() {
+  return [" ", expr];
+} });
+const leadingSpaceFragment = [" ", expr];
+const trailingSpaceElement = (() => {
+  var _el$23 = _$getNextElement(_tmpl$4), _el$24 = _el$23.firstChild;
+  _$insert(_el$23, expr, _el$24);
+  return _el$23;
+})();
+const trailingSpaceComponent = _$createComponent(Div, { get children() {
+  return [expr, " "];
+} });
+const trailingSpaceFragment = [expr, " "];
+const escapeAttribute = _$getNextElement(_tmpl$13);
+const escapeCompAttribute = _$createComponent(Div, {
+  normal: "Search…",
+  title: "Search&hellip;"
+});
+const lastElementExpression = (() => {
+  var _el$25 = _$getNextElement(_tmpl$14), _el$26 = _el$25.firstChild;
+  _$insert(_el$25, expr, null);
+  return _el$25;
+})();
@@ -0,0 +1,31 @@
+import { ssr as _$ssr } from "r-server";
+import { createComponent as _$createComponent } from "r-server";
+import { escape as _$escape } from "r-server";
+import { ssrAttribute as _$ssrAttribute } from "r-server";
+import { ssrStyle as _$ssrStyle } from "r-server";
+var _tmpl$ = "<svg width=\"400\" height=\"180\"><rect stroke-width=\"2\" x=\"50\" y=\"20\" rx=\"20\" ry=\"20\" width=\"150\" height=\"150\" style=\"fill:red;stroke:black;stroke-width:5;opacity:0.5\"></rect><linearGradient gradientTransform=\"rotate(25)\"><stop offset=\"0%\"></stop></linearGradient></svg>",
+  _tmpl$2 = [
+  "<svg width=\"400\" height=\"180\"><rect class=\"",
+  "\"",
+  "",
+  "",
+  " rx=\"20\" ry=\"20\" width=\"150\" height=\"150\" style=\"",
+  "\"></rect></svg>"
+],
+  _tmpl$3 = "<svg width=\"400\" height=\"180\"><rect></rect></svg>",
+  _tmpl$4 = "<rect x=\"50\" y=\"20\" width=\"150\" height=\"150\"></rect>",
+  _tmpl$5 = ["<svg viewBox=\"0 0 160 40\" xmlns=\"http://www.w3.org/2000/svg\"><a", "><text x=\"10\" y=\"25\">MDN Web Docs</text></a></svg>"],
+  _tmpl$6 = ["<svg viewBox=\"0 0 160 40\" xmlns=\"http://www.w3.org/2000/svg\"><text x=\"10\" y=\"25\">", "</text></svg>"];
+const template = _$ssr(_tmpl$);
+const template2 = _$ssr(_tmpl$2, _$escape(state.name, true), _$ssrAttribute("stroke-width", _$escape(state.width, true), false), _$ssrAttribute("x", _$escape(state.x, true), false), _$ssrAttribute("y", _$escape(state.y, true), false), _$ssrStyle({
+  fill: "red",
+  stroke: "black",
+  "stroke-width": props.stroke,
+  opacity: .5
+}));
+const template3 = _$ssr(_tmpl$3);
+const template4 = _$ssr(_tmpl$4);
+const template5 = _$ssr(_tmpl$4);
+const template6 = _$createComponent(Component, { children: _$ssr(_tmpl$4) });
+const template7 = _$ssr(_tmpl$5, _$ssrAttribute("xlink:href", _$escape(url, true), false));
+const template8 = _$ssr(_tmpl$6, _$escape(text));
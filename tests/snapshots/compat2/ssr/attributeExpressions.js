@@ -0,0 +1,189 @@
+import { ssr as _$ssr } from "r-server";
+import { ssrClassList as _$ssrClassList } from "r-server";
+import { ssrStyle as _$ssrStyle } from "r-server";
+import { ssrAttribute as _$ssrAttribute } from "r-server";
+import { escape as _$escape } from "r-server";
+var _tmpl$ = [
+  "<div id=\"main\" class=\"",
+  "\" style=\"",
+  "\"><h1 class=\"base\"",
+  " foo disabled",
+  " style=\"",
+  "\" class=\"",
+  "\"><a href=\"/\" class=\"",
+  "\">Welcome</a></h1></div>"
+],
+  _tmpl$2 = [
+  "<div><div>",
+  "</div><div>",
+  "</div><div><div/></div></div>"
+],
+  _tmpl$3 = [
+  "<div foo",
+  " style=\"",
+  "\"",
+  ">",
+  "</div>"
+],
+  _tmpl$4 = [
+  "<div class=\"hi\" class=\"",
+  "\" class=\"",
+  "\"></div>"
+],
+  _tmpl$5 = "<div class=\"a\" class=\"b\"></div>",
+  _tmpl$6 = ["<div style=\"", "\">Hi</div>"],
+  _tmpl$7 = [
+  "<div style=\"",
+  "\" class=\"",
+  "\"></div>"
+],
+  _tmpl$8 = "<div></div>",
+  _tmpl$9 = "<div onclick=\"console.log('hi')\"></div>",
+  _tmpl$10 = "<input type=\"checkbox\" checked>",
+  _tmpl$11 = ["<input type=\"checkbox\"", ">"],
+  _tmpl$12 = "<div class=\"`a\">`$`</div>",
+  _tmpl$13 = ["<button class=\"static\" class=\"", "\" type=\"button\">Write</button>"],
+  _tmpl$14 = ["<button class=\"", "\">Hi</button>"],
+  _tmpl$15 = ["<div class=\"", "\" class=\"flex flex-col\"></div>"],
+  _tmpl$16 = [
+  "<div><input",
+  "",
+  "",
+  " readonly=\"\"><input",
+  "",
+  "",
+  "",
+  "></div>"
+],
+  _tmpl$17 = ["<div style=\"", "\"></div>"],
+  _tmpl$18 = "<div data=\"&quot;hi&quot;\" data2=\"&quot;\"></div>",
+  _tmpl$19 = [
+  "<div",
+  ">",
+  "</div>"
+],
+  _tmpl$20 = "<a something></a>",
+  _tmpl$21 = ["<div>", "<a something></a></div>"],
+  _tmpl$22 = ["<div start=\"Hi\"", ">Hi</div>"],
+  _tmpl$23 = ["<label><span>Input is ", "</span><input><div></div></label>"],
+  _tmpl$24 = "<div class=\"class1 class2\n    class3 class4\n    class5 class6\" style=\"color:red;background-color:blue !important;border:1px solid black;font-size:12px\" random=\"random1 random2\n    random3 random4\"></div>",
+  _tmpl$25 = ["<button class=\"", "\"></button>"],
+  _tmpl$26 = "<input value=\"10\">",
+  _tmpl$27 = [
+  "<select",
+  "><option",
+  ">Red</option><option",
+  ">Blue</option></select>"
+],
+  _tmpl$28 = ["<div a a=\"\" a=\"\" checked a a=\"0\" a=\"\" a=\"\"", " a></div>"],
+  _tmpl$29 = ["<style>", "</style>"],
+  _tmpl$30 = ["<style", "></style>"],
+  _tmpl$31 = ["<video", "></video>"],
+  _tmpl$32 = "<video playsinline></video>",
+  _tmpl$33 = "<video></video>",
+  _tmpl$34 = "<video playsInline></video>",
+  _tmpl$35 = ["<div class=\"bg-(--bg)\" style=\"", "\"></div>"];
+import * as styles from "./styles.module.css";
+const selected = true;
+let id = "my-h1";
+let link;
+const template = _$ssr(_tmpl$, _$ssrClassList({ selected: unknown }), _$ssrStyle({ color }), _$ssrAttribute("id", _$escape(id, true), false), _$ssrAttribute("title", _$escape(welcoming(), true), false), _$ssrStyle({
+  "background-color": color(),
+  "margin-right": "40px"
+}), _$ssrClassList({
+  dynamic: dynamic(),
+  selected
+}), _$ssrClassList({ "ccc ddd": true }));
+const template2 = _$ssr(_tmpl$2, _$escape(rowId), _$escape(row.label));
+const template3 = _$ssr(_tmpl$3, _$ssrAttribute("id", _$escape(
+  /*@once*/
+  state.id,
+  true
+), false), _$ssrStyle(
+  /*@once*/
+  { "background-color": state.color }
+), _$ssrAttribute("name", _$escape(state.name, true), false), _$escape(
+  /*@once*/
+  state.content
+));
+const template4 = _$ssr(_tmpl$4, _$escape(state.class, true), _$ssrClassList({ "ccc:ddd": true }));
+const template5 = _$ssr(_tmpl$5);
+const template6 = _$ssr(_tmpl$6, _$ssrStyle(someStyle()));
+let undefVar;
+const template7 = _$ssr(_tmpl$7, _$ssrStyle({
+  "background-color": color(),
+  "margin-right": "40px",
+  ...props.style
+}), _$ssrClassList({ "other-class2": undefVar }));
+let refTarget;
+const template8 = _$ssr(_tmpl$8);
+const template9 = _$ssr(_tmpl$8);
+const template10 = _$ssr(_tmpl$8);
+const template11 = _$ssr(_tmpl$8);
+const template12 = _$ssr(_tmpl$9);
+const template13 = _$ssr(_tmpl$10);
+const template14 = _$ssr(_tmpl$11, _$ssrAttribute("checked", state.visible, true));
+const template15 = _$ssr(_tmpl$12);
+const template16 = _$ssr(_tmpl$13, _$ssrClassList({ hi: "k" }));
+const template17 = _$ssr(_tmpl$14, _$ssrClassList({
+  a: true,
+  b: true,
+  c: true
+}));
+const template18 = _$ssr(_tmpl$8);
+const template19 = _$ssr(_tmpl$15, _$ssrClassList({ "bg-red-500": true }));
+const template20 = _$ssr(_tmpl$16, _$ssrAttribute("value", _$escape(s(), true), false), _$ssrAttribute("min", _$escape(min(), true), false), _$ssrAttribute("max", _$escape(max(), true), false), _$ssrAttribute("checked", s2(), true), _$ssrAttribute("min", _$escape(min(), true), false), _$ssrAttribute("max", _$escape(max(), true), false), _$ssrAttribute("readonly", value, true));
+const template21 = _$ssr(_tmpl$17, _$ssrStyle({
+  d: "static",
+  ...rest
+}));
+const template22 = _$ssr(_tmpl$18);
+const template23 = _$ssr(_tmpl$19, _$ssrAttribute("disabled", "t" in test, true), _$escape("t" in test && "true"));
+const template24 = _$ssr(_tmpl$20);
+const template25 = _$ssr(_tmpl$21, _$escape(props.children));
+const template26 = _$ssr(_tmpl$22, _$ssrAttribute("middle", _$escape(middle, true), false));
+const template27 = _$ssr(_tmpl$22, _$ssrAttribute("middle", _$escape(middle, true), false));
+const template28 = _$ssr(_tmpl$23, _$escape(api() ? "checked" : "unchecked"));
+const template29 = _$ssr(_tmpl$19, _$ssrAttribute("attribute", _$escape(!!someValue, true), false), _$escape(!!someValue));
+const template30 = _$ssr(_tmpl$24);
+const template31 = _$ssr(_tmpl$17, _$ssrStyle({ "background-color": getStore.itemProperties.color }));
+const template32 = _$ssr(_tmpl$17, _$ssrStyle({ "background-color": undefined }));
+const template33 = [
+  _$ssr(_tmpl$25, _$escape(styles.button, true)),
+  _$ssr(_tmpl$25, _$escape(styles["foo--bar"], true)),
+  _$ssr(_tmpl$25, _$escape(styles.foo.bar, true)),
+  _$ssr(_tmpl$25, _$escape(styles[foo()], true))
+];
+const template34 = _$ssr(_tmpl$8);
+const template35 = _$ssr(_tmpl$8);
+const template36 = _$ssr(_tmpl$8);
+const template37 = _$ssr(_tmpl$8);
+const template38 = _$ssr(_tmpl$8);
+const template39 = _$ssr(_tmpl$26);
+const template40 = _$ssr(_tmpl$17, _$ssrStyle({ color: a() }));
+const template41 = _$ssr(_tmpl$27, _$ssrAttribute("value", _$escape(state.color, true), false), _$ssrAttribute("value", _$escape(Color.Red, true), false), _$ssrAttribute("value", _$escape(Color.Blue, true), false));
+const template42 = _$ssr(_tmpl$28, _$ssrAttribute("a", _$escape(void 0, true), false));
+const css = () => "&{color:red}";
+const template43 = [
+  _$ssr(_tmpl$29, _$escape(css())),
+  _$ssr(_tmpl$30, _$ssrAttribute("children", _$escape(css(), true), false)),
+  _$ssr(_tmpl$29, css()),
+  _$ssr(_tmpl$29, _$escape(css())),
+  _$ssr(_tmpl$29, _$escape(css()))
+];
+const styleProps = { children: css };
+const template44 = [
+  _$ssr(_tmpl$29, _$escape(css())),
+  _$ssr(_tmpl$30, _$ssrAttribute("children", _$escape(css(), true), false)),
+  _$ssr(_tmpl$29, css()),
+  _$ssr(_tmpl$29, _$escape(css())),
+  _$ssr(_tmpl$29, _$escape(css()))
+];
+const template45 = _$ssr(_tmpl$31, _$ssrAttribute("playsinline", value, true));
+const template46 = _$ssr(_tmpl$32);
+const template47 = _$ssr(_tmpl$33);
+const template48 = _$ssr(_tmpl$31, _$ssrAttribute("playsInline", value, true));
+const template49 = _$ssr(_tmpl$34);
+const template50 = _$ssr(_tmpl$33);
+const nope = () => undefined;
+const template51 = _$ssr(_tmpl$35, _$ssrStyle({ "--bg": nope() }));
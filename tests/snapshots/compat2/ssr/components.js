@@ -0,0 +1,163 @@
+import { ssr as _$ssr } from "r-server";
+import { createComponent as _$createComponent } from "r-server";
+import { mergeProps as _$mergeProps } from "r-server";
+import { For as _$For } from "r-server";
+import { Show as _$Show } from "r-server";
+import { escape as _$escape } from "r-server";
+var _tmpl$ = ["<div>Hello ", "</div>"],
+  _tmpl$2 = ["<div>", "</div>"],
+  _tmpl$3 = "<div>From Parent</div>",
+  _tmpl$4 = [
+  "<div>",
+  "",
+  "<div>",
+  "</div></div>"
+],
+  _tmpl$5 = "<div></div>",
+  _tmpl$6 = "<_garbage>Hi</_garbage>",
+  _tmpl$7 = [
+  "<div>",
+  " | ",
+  " | ",
+  " | ",
+  " | ",
+  " | ",
+  "</div>"
+],
+  _tmpl$8 = [
+  "<div>",
+  " | ",
+  "",
+  " | ",
+  "",
+  " | ",
+  "</div>"
+],
+  _tmpl$9 = [
+  "<div> | ",
+  " |  |  | ",
+  " | </div>"
+],
+  _tmpl$10 = "<span>1</span>",
+  _tmpl$11 = "<span>2</span>",
+  _tmpl$12 = "<span>3</span>";
+import { Show } from "somewhere";
+const Child = (props) => {
+  const [s, set] = createSignal();
+  return [_$ssr(_tmpl$, _$escape(props.name)), _$ssr(_tmpl$2, _$escape(props.children))];
+};
+const template = (props) => {
+  let childRef;
+  const { content } = props;
+  return _$ssr(_tmpl$4, _$escape(_$createComponent(Child, _$mergeProps({ name: "John" }, props, {
+    ref: childRef,
+    booleanProperty: true,
+    children: _$ssr(_tmpl$3)
+  }))), _$escape(_$createComponent(Child, _$mergeProps({ name: "Jason" }, dynamicSpread(), {
+    get ref() {
+      return props.ref;
+    },
+    children: [null, _$ssr(_tmpl$2, _$escape(content))]
+  }))), _$escape((context) => context));
+};
+const template2 = _$createComponent(Child, {
+  name: "Jake",
+  get dynamic() {
+    return state.data;
+  },
+  get stale() {
+    return state.data;
+  },
+  handleClick: clickHandler,
+  get "hyphen-ated"() {
+    return state.data;
+  },
+  ref: (el) => e = el
+});
+const template3 = _$createComponent(Child, { get children() {
+  return [
+    _$ssr(_tmpl$5),
+    _$ssr(_tmpl$5),
+    _$ssr(_tmpl$5),
+    "After"
+  ];
+} });
+const [s, set] = createSignal();
+const template4 = _$createComponent(Child, {
+  ref: set,
+  children: _$ssr(_tmpl$5)
+});
+const template5 = _$createComponent(Child, {
+  get dynamic() {
+    return state.dynamic;
+  },
+  children: state.dynamic
+});
+// builtIns
+const template6 = _$createComponent(_$For, {
+  get each() {
+    return state.list;
+  },
+  get fallback() {
+    return _$createComponent(Loading, {});
+  },
+  children: (item) => _$createComponent(_$Show, {
+    get when() {
+      return state.condition;
+    },
+    children: item
+  })
+});
+const template7 = _$createComponent(Child, { children: [_$ssr(_tmpl$5), state.dynamic] });
+const template8 = _$createComponent(Child, { children: [(item) => item, (item) => item] });
+const template9 = _$ssr(_tmpl$6);
+const template10 = _$ssr(_tmpl$7, _$escape(_$createComponent(Link, { children: "new" })), _$escape(_$createComponent(Link, { children: "comments" })), _$escape(_$createComponent(Link, { children: "show" })), _$escape(_$createComponent(Link, { children: "ask" })), _$escape(_$createComponent(Link, { children: "jobs" })), _$escape(_$createComponent(Link, { children: "submit" })));
+const template11 = _$ssr(_tmpl$8, _$escape(_$createComponent(Link, { children: "new" })), _$escape(_$createComponent(Link, { children: "comments" })), _$escape(_$createComponent(Link, { children: "show" })), _$escape(_$createComponent(Link, { children: "ask" })), _$escape(_$createComponent(Link, { children: "jobs" })), _$escape(_$createComponent(Link, { children: "submit" })));
+const template12 = _$ssr(_tmpl$9, _$escape(_$createComponent(Link, { children: "comments" })), _$escape(_$createComponent(Link, { children: "show" })));
+class Template13 {
+  render() {
+    _$createComponent(Component, {
+      get prop() {
+        return this.something;
+      },
+      onClick: () => this.shouldStay,
+      children: _$createComponent(Nested, {
+        get prop() {
+          return this.data;
+        },
+        children: this.content
+      })
+    });
+  }
+}
+const Template14 = _$createComponent(Component, { children: data() });
+const Template15 = _$createComponent(Component, props);
+const Template16 = _$createComponent(Component, _$mergeProps({ something }, props));
+const Template17 = _$createComponent(Pre, { get children() {
+  return [
+    _$ssr(_tmpl$10),
+    " ",
+    _$ssr(_tmpl$11),
+    " ",
+    _$ssr(_tmpl$12)
+  ];
+} });
+const Template18 = _$createComponent(Pre, { children: [
+  _$ssr(_tmpl$10),
+  _$ssr(_tmpl$11),
+  _$ssr(_tmpl$12)
+] });
+const Template19 = _$createComponent(Component, s.dynamic());
+const Template20 = _$createComponent(Component, { get class() {
+  return prop.red ? "red" : "green";
+} });
+const template21 = _$createComponent(Component, { get [key()]() {
+  return props.value;
+} });
+const template22 = _$createComponent(Component, { passObject: { ...a } });
+const template23 = _$createComponent(Component, {
+  disabled: "t" in test,
+  children: "t" in test && "true"
+});
+const template24 = _$createComponent(Component, { children: state.dynamic });
+const template25 = _$createComponent(Component, { children: _$ssr(_tmpl$5) });
@@ -0,0 +1,69 @@
+import { ssr as _$ssr } from "r-server";
+import { createComponent as _$createComponent } from "r-server";
+import { memo as _$memo } from "r-server";
+import { escape as _$escape } from "r-server";
+var _tmpl$ = ["<div>", "</div>"],
+  _tmpl$2 = "<div>Output</div>";
+const template1 = _$ssr(_tmpl$, _$escape(simple));
+const template2 = _$ssr(_tmpl$, _$escape(state.dynamic));
+const template3 = _$ssr(_tmpl$, _$escape(simple ? good : bad));
+const template4 = _$ssr(_tmpl$, _$escape(simple ? good() : bad));
+const template5 = _$ssr(_tmpl$, _$escape(state.dynamic ? good() : bad));
+const template6 = _$ssr(_tmpl$, _$escape(state.dynamic && good()));
+const template7 = _$ssr(_tmpl$, _$escape(state.count > 5 ? state.dynamic ? best : good() : bad));
+const template8 = _$ssr(_tmpl$, _$escape(state.dynamic && state.something && good()));
+const template9 = _$ssr(_tmpl$, _$escape(state.dynamic && good() || bad));
+const template10 = _$ssr(_tmpl$, _$escape(state.a ? "a" : state.b ? "b" : state.c ? "c" : "fallback"));
+const template11 = _$ssr(_tmpl$, _$escape(state.a ? a() : state.b ? b() : state.c ? "c" : "fallback"));
+const template12 = _$createComponent(Comp, { get render() {
+  return state.dynamic ? good() : bad;
+} });
+// no dynamic predicate
+const template13 = _$createComponent(Comp, { get render() {
+  return state.dynamic ? good : bad;
+} });
+const template14 = _$createComponent(Comp, { get render() {
+  return state.dynamic && good();
+} });
+// no dynamic predicate
+const template15 = _$createComponent(Comp, { get render() {
+  return state.dynamic && good;
+} });
+const template16 = _$createComponent(Comp, { get render() {
+  return state.dynamic || good();
+} });
+const template17 = _$createComponent(Comp, { get render() {
+  return state.dynamic ? _$createComponent(Comp, {}) : _$createComponent(Comp, {});
+} });
+const template18 = _$createComponent(Comp, { children: state.dynamic ? _$createComponent(Comp, {}) : _$createComponent(Comp, {}) });
+const template19 = _$ssr(_tmpl$, state.dynamic ? _$createComponent(Comp, {}) : _$createComponent(Comp, {}));
+const template20 = _$ssr(_tmpl$, _$escape(state.dynamic ? _$createComponent(Comp, {}) : _$createComponent(Comp, {})));
+const template21 = _$createComponent(Comp, { get render() {
+  return state?.dynamic ? "a" : "b";
+} });
+const template22 = _$createComponent(Comp, { children: state?.dynamic ? "a" : "b" });
+const template23 = _$ssr(_tmpl$, state?.dynamic ? "a" : "b");
+const template24 = _$ssr(_tmpl$, _$escape(state?.dynamic ? "a" : "b"));
+const template25 = _$createComponent(Comp, { get render() {
+  return state.dynamic ?? _$createComponent(Comp, {});
+} });
+const template26 = _$createComponent(Comp, { children: state.dynamic ?? _$createComponent(Comp, {}) });
+const template27 = _$ssr(_tmpl$, state.dynamic ?? _$createComponent(Comp, {}));
+const template28 = _$ssr(_tmpl$, _$escape(state.dynamic ?? _$createComponent(Comp, {})));
+const template29 = _$ssr(_tmpl$, _$escape((thing() && thing1()) ?? thing2() ?? thing3()));
+const template30 = _$ssr(_tmpl$, _$escape(thing() || thing1() || thing2()));
+const template31 = _$createComponent(Comp, { get value() {
+  return count() ? count() ? count() : count() : count();
+} });
+const template32 = _$ssr(_tmpl$, _$escape(something?.()));
+const template33 = _$createComponent(Comp, { children: something?.() });
+const template34 = simple ? good : bad;
+const template35 = _$memo(() => simple ? good() : bad);
+const template36 = _$memo(() => state.dynamic ? good() : bad);
+const template37 = _$memo(() => state.dynamic && good());
+const template38 = _$memo(() => state.count > 5 ? state.dynamic ? best : good() : bad);
+const template39 = _$memo(() => state.dynamic && state.something && good());
+const template40 = _$memo(() => state.dynamic && good() || bad);
+const template41 = _$memo(() => state.a ? "a" : state.b ? "b" : state.c ? "c" : "fallback");
+const template42 = _$memo(() => state.a ? a() : state.b ? b() : state.c ? "c" : "fallback");
+const template43 = _$memo(() => obj1.prop ? obj2.prop ? _$ssr(_tmpl$2) : [] : []);
@@ -0,0 +1,67 @@
+import { ssr as _$ssr } from "r-server";
+import { createComponent as _$createComponent } from "r-server";
+import { mergeProps as _$mergeProps } from "r-server";
+import { ssrAttribute as _$ssrAttribute } from "r-server";
+import { escape as _$escape } from "r-server";
+var _tmpl$ = "<div></div>",
+  _tmpl$2 = ["<module", "></module>"],
+  _tmpl$3 = ["<module", ">Hello</module>"],
+  _tmpl$4 = [
+  "<module",
+  ">",
+  "</module>"
+],
+  _tmpl$5 = "<module></module>",
+  _tmpl$6 = "<module>Hello</module>",
+  _tmpl$7 = ["<module>", "</module>"],
+  _tmpl$8 = ["<module>Hi ", "</module>"],
+  _tmpl$9 = ["<module>Hi", "</module>"],
+  _tmpl$10 = "<div>Test 1</div>",
+  _tmpl$11 = ["<div>", "</div>"];
+const children = _$ssr(_tmpl$);
+const dynamic = { children };
+const template = _$createComponent(Module, { children });
+const template2 = _$ssr(_tmpl$2, _$ssrAttribute("children", _$escape(children, true), false));
+const template3 = _$ssr(_tmpl$3, _$ssrAttribute("children", _$escape(children, true), false));
+const template4 = _$ssr(_tmpl$4, _$ssrAttribute("children", _$escape(children, true), false), _$escape(_$createComponent(Hello, {})));
+const template5 = _$ssr(_tmpl$2, _$ssrAttribute("children", _$escape(dynamic.children, true), false));
+const template6 = _$createComponent(Module, { get children() {
+  return dynamic.children;
+} });
+const template7 = _$ssr(_tmpl$5);
+const template8 = _$ssr(_tmpl$6);
+const template9 = _$ssr(_tmpl$7, _$escape(dynamic.children));
+const template10 = _$createComponent(Module, _$mergeProps(dynamic, { children: "Hello" }));
+const template11 = _$ssr(_tmpl$2, _$ssrAttribute("children", _$escape(
+  /*@once*/
+  state.children,
+  true
+), false));
+const template12 = _$createComponent(Module, { get children() {
+  return state.children;
+} });
+const template13 = _$ssr(_tmpl$7, _$escape(children));
+const template14 = _$createComponent(Module, { children });
+const template15 = _$ssr(_tmpl$7, _$escape(dynamic.children));
+const template16 = _$createComponent(Module, { children: dynamic.children });
+const template18 = _$ssr(_tmpl$8, _$escape(children));
+const template19 = _$createComponent(Module, { children: ["Hi ", children] });
+const template20 = _$ssr(_tmpl$7, _$escape(children()));
+const template21 = _$createComponent(Module, { children: children() });
+const template22 = _$ssr(_tmpl$7, _$escape(state.children()));
+const template23 = _$createComponent(Module, { children: state.children() });
+const template24 = _$ssr(_tmpl$9, _$escape(dynamic.children));
+const tiles = [];
+tiles.push(_$ssr(_tmpl$10));
+const template25 = _$ssr(_tmpl$11, _$escape(tiles));
+const comma = _$ssr(_tmpl$11, _$escape((expression(), "static")));
+const double = _$ssr(_tmpl$11, _$escape(children()()));
+const template26 = _$ssr(_tmpl$11, _$escape(() => children));
+const template27 = _$ssr(_tmpl$11, _$escape(() => {
+  statement;
+  return children;
+}));
+const template28 = _$ssr(_tmpl$11, _$escape((() => {
+  statement;
+  return children;
+})()));
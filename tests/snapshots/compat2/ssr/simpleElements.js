@@ -0,0 +1,11 @@
+import { ssr as _$ssr } from "r-server";
+var _tmpl$ = "<div id=\"main\"><style>div { color: red; }</style><h1>Welcome</h1><label for=\"entry\">Edit:</label><input id=\"entry\" type=\"text\"></div>",
+  _tmpl$2 = "<div><span><a></a></span><span></span></div>",
+  _tmpl$3 = "<div><div><table><tbody></tbody></table></div><div></div></div>",
+  _tmpl$4 = "<div><div><footer><div></div></footer></div><div><button><span>0</span></button></div></div>",
+  _tmpl$5 = "<div><noscript>No JS!!<style>div { color: red; }</style></noscript></div>";
+const template = _$ssr(_tmpl$);
+const template2 = _$ssr(_tmpl$2);
+const template3 = _$ssr(_tmpl$3);
+const template4 = _$ssr(_tmpl$4);
+const template5 = _$ssr(_tmpl$5);